@@ -66,5 +66,20 @@ benchmarks! {
         assert_eq!(T::Currency::reserved_balance(&caller), 0u32.into());
     }
 
+    // 3. 测试 unbond_early
+    unbond_early {
+        let role = CollateralRole::IpfsProvider;
+        let caller = setup_funded_account::<T>("caller", 0, role);
+        let pledge_amount = T::MinIpfsProviderCollateral::get() + 100u32.into();
+
+        // 前置状态：质押后不推进区块，仍在锁定期内
+        Pallet::<T>::internal_pledge(&caller, role, pledge_amount)?;
+
+    }: _(RawOrigin::Signed(caller.clone()), role)
+    verify {
+        assert!(!CollateralData::<T>::contains_key(&caller, role));
+        assert_eq!(T::Currency::reserved_balance(&caller), 0u32.into());
+    }
+
     impl_benchmark_test_suite!(Collaterals, crate::mock::new_test_ext(), crate::mock::Test);
 }
\ No newline at end of file
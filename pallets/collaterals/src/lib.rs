@@ -7,19 +7,23 @@ pub mod benchmarking;
 
 // 权重定义
 pub mod weights;
+pub mod migrations;
+
+// 存储版本，存储结构变更时递增并在 migrations 中添加对应的迁移
+const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::{
         pallet_prelude::*,
-        traits::{Currency, ReservableCurrency, Get, ExistenceRequirement, Imbalance, BalanceStatus},
+        traits::{Currency, ReservableCurrency, Get, Contains, ExistenceRequirement, Imbalance, BalanceStatus},
         transactional,
     };
     use frame_system::pallet_prelude::*;
     use sp_runtime::{
         traits::{Zero, CheckedAdd, CheckedSub, SaturatedConversion, Bounded, AccountIdConversion, Saturating},
-        DispatchError, ArithmeticError,
+        DispatchError, ArithmeticError, Perbill,
     };
     use scale_info::TypeInfo;
     use core::convert::TryInto;
@@ -28,10 +32,11 @@ pub mod pallet {
     pub trait WeightInfo {
         fn unbond() -> Weight;
         fn pledge() -> Weight;
+        fn unbond_early() -> Weight;
     }
 
     /// 货币类型的别名
-    type BalanceOf<T> =
+    pub type BalanceOf<T> =
         <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
     /// 惩罚类型，用于决定资金分配比例
@@ -47,6 +52,15 @@ pub mod pallet {
         IpfsProviderHeavy,
     }
 
+    /// 违规严重程度，与角色一起决定惩罚类型和罚没比例
+    #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    pub enum ViolationSeverity {
+        /// 轻度违规：罚没 30% 质押金
+        Light,
+        /// 重度违规：罚没全部质押金
+        Heavy,
+    }
+
     /// 质押角色枚举
     #[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
     pub enum CollateralRole {
@@ -64,6 +78,7 @@ pub mod pallet {
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
@@ -81,6 +96,20 @@ pub mod pallet {
         #[pallet::constant]
         type MinGovernancePledge: Get<BalanceOf<Self>>;
 
+        /// 各角色单个账户累计质押金额的上限，None 表示不限制
+        /// 超出上限的部分不会被质押（发出 CollateralCapped），已达上限时拒绝质押
+        #[pallet::constant]
+        type MaxMarketOperatorCollateral: Get<Option<BalanceOf<Self>>>;
+        #[pallet::constant]
+        type MaxIpfsProviderCollateral: Get<Option<BalanceOf<Self>>>;
+        #[pallet::constant]
+        type MaxGovernancePledge: Get<Option<BalanceOf<Self>>>;
+
+        /// 质押生效期（区块数）：质押满该区块数后才计入治理投票权重，
+        /// 防止投票前临时质押、锁定期一过立即解押来获取权重
+        #[pallet::constant]
+        type PledgeActivationDelay: Get<BlockNumberFor<Self>>;
+
         /// 专门用于处理惩罚（Slash）和奖励（Reward）的账户ID
         #[pallet::constant]
         type IncentivePoolAccount: Get<Self::AccountId>;
@@ -94,6 +123,18 @@ pub mod pallet {
         #[pallet::constant]
         type CompensationPoolAccount: Get<Self::AccountId>;
 
+        /// 提前解押的罚金比例，按当前质押金额计算
+        #[pallet::constant]
+        type EarlyUnbondPenalty: Get<Perbill>;
+        /// 提前解押罚金使用的惩罚类型，决定罚金的分配方式
+        #[pallet::constant]
+        type EarlyUnbondSlashType: Get<SlashType>;
+        /// 允许提前解押的角色（如 MarketOperator 不允许）
+        type EarlyUnbondAllowedRoles: Contains<CollateralRole>;
+
+        /// 罚没后通知质押所属的业务模块（如 DataCreator 质押通知 dataassets）
+        type SlashNotificationHandler: pallet_shared_traits::SlashNotificationHandler<Self::AccountId, CollateralRole, BalanceOf<Self>>;
+
         /// Weight information
         type WeightInfo: WeightInfo;
     }
@@ -109,13 +150,22 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// 各角色当前持有质押的账户数，质押记录创建时加一、移除时减一
+    #[pallet::storage]
+    #[pallet::getter(fn pledger_count)]
+    pub type PledgerCount<T: Config> = StorageMap<_, Blake2_128Concat, CollateralRole, u32, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// 质押成功
         Pledged { who: T::AccountId, role: CollateralRole, amount: BalanceOf<T> },
+        /// 质押金额超过角色上限，只质押了上限内的部分
+        CollateralCapped { who: T::AccountId, role: CollateralRole, requested: BalanceOf<T>, accepted: BalanceOf<T> },
         /// 解除质押成功
         Unbonded { who: T::AccountId, role: CollateralRole, amount: BalanceOf<T> },
+        /// 锁定期内提前解押：amount 为释放的金额，penalty 为被罚没的金额
+        EarlyUnbond { who: T::AccountId, role: CollateralRole, amount: BalanceOf<T>, penalty: BalanceOf<T> },
         /// 质押被惩罚并分配
         SlashedAndDistributed { 
             who: T::AccountId, 
@@ -138,6 +188,20 @@ pub mod pallet {
         CollateralNotReadyForRelease,
         /// 角色不支持此操作
         UnsupportedRole,
+        /// 该角色的质押金额已达上限
+        CollateralCapExceeded,
+        /// 该角色不允许提前解押
+        EarlyUnbondNotAllowed,
+        /// 锁定期已过，请使用 unbond 无罚金解押
+        LockPeriodElapsed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
     }
 
     #[pallet::call]
@@ -156,6 +220,14 @@ pub mod pallet {
             let who = ensure_signed(origin)?;
             Self::internal_unbond(&who, role)
         }
+
+        /// 锁定期内提前解押，按 EarlyUnbondPenalty 罚没后释放剩余质押
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::unbond_early())]
+        pub fn unbond_early(origin: OriginFor<T>, role: CollateralRole) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            Self::internal_unbond_early(&who, role)
+        }
     }
 
     /// 辅助函数
@@ -164,17 +236,28 @@ pub mod pallet {
         pub fn internal_pledge(who: &T::AccountId, role: CollateralRole, amount: BalanceOf<T>) -> DispatchResult {
             ensure!(!amount.is_zero(), Error::<T>::AmountIsZero);
             Self::ensure_min_collateral(&role, amount)?;
+            let requested = amount;
+            let amount = Self::cap_to_role_maximum(who, &role, amount)?;
             
             T::Currency::reserve(who, amount)?;
 
+            if !CollateralData::<T>::contains_key(who, &role) {
+                PledgerCount::<T>::mutate(&role, |count| *count = count.saturating_add(1));
+            }
+            let min_amount = Self::min_collateral(&role);
             CollateralData::<T>::try_mutate(who, &role, |info| -> DispatchResult {
+                // 罚没后低于最小金额的质押，补足时重新计算起始区块，生效期和锁定期都从补足时开始
+                let reaches_minimum = info.amount < min_amount;
                 info.amount = info.amount.checked_add(&amount).ok_or(ArithmeticError::Overflow)?;
-                if info.start_block.is_zero() {
+                if info.start_block.is_zero() || (reaches_minimum && info.amount >= min_amount) {
                     info.start_block = frame_system::Pallet::<T>::block_number();
                 }
                 Ok(())
             })?;
 
+            if amount < requested {
+                Self::deposit_event(Event::CollateralCapped { who: who.clone(), role, requested, accepted: amount });
+            }
             Self::deposit_event(Event::Pledged { who: who.clone(), role, amount });
             Ok(())
         }
@@ -190,7 +273,7 @@ pub mod pallet {
             T::Currency::unreserve(who, releasable);
             
             if remaining.is_zero() {
-                CollateralData::<T>::remove(who, &role);// 全部释放，移除存储项
+                Self::remove_pledge(who, role);// 全部释放，移除存储项
             } else {
                 CollateralData::<T>::mutate(who, &role, |info| info.amount = remaining);
             }
@@ -199,22 +282,135 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 检查最小质押要求
-        fn ensure_min_collateral(role: &CollateralRole, amount: BalanceOf<T>) -> DispatchResult {
-            let min_amount = match role {
+        /// 提前解押：罚金通过 slash_and_distribute 按 EarlyUnbondSlashType 分配，剩余部分全部释放
+        pub fn internal_unbond_early(who: &T::AccountId, role: CollateralRole) -> DispatchResult {
+            ensure!(T::EarlyUnbondAllowedRoles::contains(&role), Error::<T>::EarlyUnbondNotAllowed);
+
+            let collateral_info = CollateralData::<T>::get(who, &role);
+            ensure!(!collateral_info.amount.is_zero(), Error::<T>::CollateralNotFound);
+            // 已满足释放条件时不应再收罚金
+            ensure!(Self::get_releasable_amount(&role, &collateral_info).is_err(), Error::<T>::LockPeriodElapsed);
+
+            let penalty = T::EarlyUnbondPenalty::get() * collateral_info.amount;
+            let penalty = if penalty.is_zero() {
+                penalty
+            } else {
+                Self::slash_and_distribute(who, role, penalty, T::EarlyUnbondSlashType::get())?
+            };
+
+            let remaining = CollateralData::<T>::get(who, &role).amount;
+            T::Currency::unreserve(who, remaining);
+            Self::remove_pledge(who, role);
+
+            Self::deposit_event(Event::EarlyUnbond { who: who.clone(), role, amount: remaining, penalty });
+            Ok(())
+        }
+
+        /// 质押记账不变量检查（try-runtime 中每块执行）
+        ///
+        /// 1. 每个账户所有角色的质押金额之和不超过其实际 reserved 余额
+        ///    （其他模块也会 reserve 同一币种，因此只要求不超过）
+        /// 2. 质押起始区块不晚于当前区块
+        /// 3. PledgerCount 与各角色的质押记录数一致
+        #[cfg(feature = "try-runtime")]
+        pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+            use sp_std::collections::btree_map::BTreeMap;
+
+            let now = frame_system::Pallet::<T>::block_number();
+            let mut totals: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+            let mut pledgers: BTreeMap<u8, u32> = BTreeMap::new();
+            for (who, role, info) in CollateralData::<T>::iter() {
+                ensure!(info.start_block <= now, "CollateralData 起始区块晚于当前区块");
+                let total = totals.entry(who).or_insert_with(Zero::zero);
+                *total = total.saturating_add(info.amount);
+                *pledgers.entry(role as u8).or_default() += 1;
+            }
+
+            for role in [
+                CollateralRole::DataCreator,
+                CollateralRole::MarketOperator,
+                CollateralRole::IpfsProvider,
+                CollateralRole::GovernancePledge,
+            ] {
+                ensure!(
+                    PledgerCount::<T>::get(role) == pledgers.get(&(role as u8)).copied().unwrap_or(0),
+                    "PledgerCount 与质押记录数不一致"
+                );
+            }
+
+            for (who, total) in totals {
+                ensure!(
+                    total <= T::Currency::reserved_balance(&who),
+                    "CollateralData 质押总额超过账户实际 reserved 余额"
+                );
+            }
+
+            Ok(())
+        }
+
+        /// 移除质押记录并更新该角色的质押账户数
+        fn remove_pledge(who: &T::AccountId, role: CollateralRole) {
+            if CollateralData::<T>::contains_key(who, &role) {
+                CollateralData::<T>::remove(who, &role);
+                PledgerCount::<T>::mutate(&role, |count| *count = count.saturating_sub(1));
+            }
+        }
+
+        /// `who` 在 `role` 角色下的质押是否已生效（质押不低于角色最小金额且已满 PledgeActivationDelay）
+        pub fn is_pledge_active(who: &T::AccountId, role: CollateralRole) -> bool {
+            let info = CollateralData::<T>::get(who, &role);
+            if info.amount.is_zero() || info.amount < Self::min_collateral(&role) {
+                return false;
+            }
+            let activation_block = info.start_block.saturating_add(T::PledgeActivationDelay::get());
+            frame_system::Pallet::<T>::block_number() >= activation_block
+        }
+
+        /// 角色的最小质押金额，零表示不限制
+        pub fn min_collateral(role: &CollateralRole) -> BalanceOf<T> {
+            match role {
                 CollateralRole::MarketOperator => T::MinMarketOperatorCollateral::get(),
                 CollateralRole::IpfsProvider => T::MinIpfsProviderCollateral::get(),
                 CollateralRole::GovernancePledge => T::MinGovernancePledge::get(),
                 // 数据创建者的基础质押在业务 Pallet 中处理
-                _ => BalanceOf::<T>::zero(), 
-            };
-            
+                _ => BalanceOf::<T>::zero(),
+            }
+        }
+
+        /// 检查最小质押要求
+        fn ensure_min_collateral(role: &CollateralRole, amount: BalanceOf<T>) -> DispatchResult {
+            let min_amount = Self::min_collateral(role);
             if !min_amount.is_zero() {
                 ensure!(amount >= min_amount, Error::<T>::InsufficientCollateralAmount);
             }
             Ok(())
         }
 
+        /// 角色的单账户质押上限，None 表示不限制
+        pub fn max_collateral(role: &CollateralRole) -> Option<BalanceOf<T>> {
+            match role {
+                CollateralRole::MarketOperator => T::MaxMarketOperatorCollateral::get(),
+                CollateralRole::IpfsProvider => T::MaxIpfsProviderCollateral::get(),
+                CollateralRole::GovernancePledge => T::MaxGovernancePledge::get(),
+                // 数据创建者的质押由业务 Pallet 按数据大小计算，不设上限
+                _ => None,
+            }
+        }
+
+        /// 按角色上限截断本次质押金额，已达上限时返回 CollateralCapExceeded
+        fn cap_to_role_maximum(
+            who: &T::AccountId,
+            role: &CollateralRole,
+            amount: BalanceOf<T>,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let Some(max_amount) = Self::max_collateral(role) else {
+                return Ok(amount);
+            };
+            let room = max_amount.saturating_sub(CollateralData::<T>::get(who, role).amount);
+            ensure!(!room.is_zero(), Error::<T>::CollateralCapExceeded);
+            Ok(amount.min(room))
+        }
+
         /// 计算可释放金额和剩余金额
         fn get_releasable_amount(
             role: &CollateralRole,
@@ -256,6 +452,33 @@ pub mod pallet {
             }
         }
         
+        /// 按 (角色, 严重程度) 确定惩罚类型和罚没比例
+        ///
+        /// 轻度违规统一为 LightViolation，罚没 30%；
+        /// 重度违规罚没全部质押金，市场运营者与 IPFS 服务提供者使用各自的分配方式
+        pub fn slash_policy(role: CollateralRole, severity: ViolationSeverity) -> (SlashType, Perbill) {
+            match (role, severity) {
+                (_, ViolationSeverity::Light) => (SlashType::LightViolation, Perbill::from_percent(30)),
+                (CollateralRole::MarketOperator, ViolationSeverity::Heavy) => (SlashType::MarketOperatorHeavy, Perbill::one()),
+                (CollateralRole::IpfsProvider, ViolationSeverity::Heavy) => (SlashType::IpfsProviderHeavy, Perbill::one()),
+                (_, ViolationSeverity::Heavy) => (SlashType::HeavyViolation, Perbill::one()),
+            }
+        }
+
+        /// 按违规严重程度惩罚质押，调用方不需要自己选择 SlashType 和罚没金额
+        /// 返回实际罚没的金额
+        pub fn slash_for_violation(
+            who: &T::AccountId,
+            role: CollateralRole,
+            severity: ViolationSeverity,
+        ) -> Result<BalanceOf<T>, DispatchError> {
+            let bonded = CollateralData::<T>::get(who, &role).amount;
+            ensure!(!bonded.is_zero(), Error::<T>::CollateralNotFound);
+
+            let (slash_type, fraction) = Self::slash_policy(role, severity);
+            Self::slash_and_distribute(who, role, fraction * bonded, slash_type)
+        }
+
         /// 执行惩罚和资金分配
         #[transactional]
         pub fn slash_and_distribute(
@@ -303,6 +526,7 @@ pub mod pallet {
 
             // 4. 执行资金划拨 (Repatriate)
             // 直接从 who 的 reserved 转移到各个池子账户的 free 余额中
+            // 不经过本模块自有的中转账户，因此无需预先为模块账户注资
             
             if !burn_amount.is_zero() {
                 T::Currency::repatriate_reserved(who, &T::DestructionAccount::get(), burn_amount, BalanceStatus::Free)?;
@@ -321,12 +545,12 @@ pub mod pallet {
             }
 
             // 5. 更新存储
-            CollateralData::<T>::mutate(who, &role, |info| {
-                info.amount = info.amount.saturating_sub(actual_slash);
-                if info.amount.is_zero() {
-                    CollateralData::<T>::remove(who, &role);
-                }
-            });
+            let remaining = CollateralData::<T>::get(who, &role).amount.saturating_sub(actual_slash);
+            if remaining.is_zero() {
+                Self::remove_pledge(who, role);
+            } else {
+                CollateralData::<T>::mutate(who, &role, |info| info.amount = remaining);
+            }
 
             // 6. 触发事件
             Self::deposit_event(Event::SlashedAndDistributed { 
@@ -337,7 +561,35 @@ pub mod pallet {
                 incentive_amount: final_incentive_amount,
             });
 
+            // 7. 通知业务模块，保持其状态与实际质押一致
+            T::SlashNotificationHandler::on_collateral_slashed(who, role, actual_slash);
+
             Ok(actual_slash)
         }
     }
-}
\ No newline at end of file
+    impl<T: Config> pallet_shared_traits::CollateralManager<T::AccountId, CollateralRole, BalanceOf<T>> for Pallet<T> {
+        fn internal_pledge(who: &T::AccountId, role: CollateralRole, amount: BalanceOf<T>) -> DispatchResult {
+            Pallet::<T>::internal_pledge(who, role, amount)
+        }
+
+        fn internal_unbond(who: &T::AccountId, role: CollateralRole) -> DispatchResult {
+            Pallet::<T>::internal_unbond(who, role)
+        }
+
+        fn pledged_amount(who: &T::AccountId, role: CollateralRole) -> BalanceOf<T> {
+            CollateralData::<T>::get(who, &role).amount
+        }
+    }
+
+    impl pallet_shared_traits::CollateralRoleKind for CollateralRole {
+        fn is_data_creator(&self) -> bool {
+            matches!(self, CollateralRole::DataCreator)
+        }
+    }
+
+    impl<T: Config> pallet_shared_traits::PledgeStatusProvider<T::AccountId> for Pallet<T> {
+        fn is_governance_pledge_active(who: &T::AccountId) -> bool {
+            Pallet::<T>::is_pledge_active(who, CollateralRole::GovernancePledge)
+        }
+    }
+}
@@ -0,0 +1,38 @@
+/// 质押模块的存储迁移
+///
+/// 每个版本一个子模块，内部迁移不检查版本，由 VersionedMigration 包装后
+/// 只在链上版本匹配时执行并更新版本号。新增的迁移需要加入 runtime 的 Migrations 元组。
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+
+pub mod v1 {
+    use super::*;
+
+    /// 按现有质押记录回填各角色的 PledgerCount
+    pub struct InnerInitPledgerCount<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerInitPledgerCount<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut entries: u64 = 0;
+            let _ = PledgerCount::<T>::clear(u32::MAX, None);
+            for (_who, role, _info) in CollateralData::<T>::iter() {
+                entries += 1;
+                PledgerCount::<T>::mutate(role, |count| *count = count.saturating_add(1));
+            }
+            <T as frame_system::Config>::DbWeight::get().reads_writes(entries, entries)
+        }
+    }
+
+    pub type InitPledgerCount<T> = VersionedMigration<
+        0,
+        1,
+        InnerInitPledgerCount<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
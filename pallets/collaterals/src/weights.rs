@@ -51,6 +51,20 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 	}
 	/// Storage: `Collaterals::CollateralData` (r:1 w:1)
 	/// Proof: `Collaterals::CollateralData` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:4 w:4)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn unbond_early() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `254`
+		//  Estimated: `6196`
+		// Minimum execution time: 41_530_000 picoseconds.
+		Weight::from_parts(44_102_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(5))
+	}
+	/// Storage: `Collaterals::CollateralData` (r:1 w:1)
+	/// Proof: `Collaterals::CollateralData` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn unbond() -> Weight {
@@ -0,0 +1,161 @@
+/// Data Availability Attestation
+///
+/// The third collateral phase (`TimeAndAvailability`) only releases once the asset's
+/// data is confirmed fetchable:
+/// - The off-chain worker looks `AvailabilityCheckLead` blocks ahead in `ReleaseQueue`
+///   and sends a HEAD request for every due availability phase to `IpfsGatewayUrl ++ CID`
+/// - 2xx is attested as available, 404/410 as unavailable
+/// - Timeouts, connection errors and other status codes mean the gateway is down:
+///   nothing is submitted and the phase is simply retried a day later
+/// - `check_release_condition` accepts only a positive attestation younger than
+///   `AvailabilityAttestationTtl`
+
+use super::*;
+use alloc::{string::String, vec::Vec};
+use frame_support::traits::Get;
+use frame_system::{
+    offchain::{SendSignedTransaction, Signer},
+    pallet_prelude::BlockNumberFor,
+};
+use sp_runtime::{
+    offchain::{http, Duration},
+    traits::Saturating,
+};
+use crate::types::*;
+
+/// Key type of the off-chain worker's attestation key (insert it with `author_insertKey`)
+pub const KEY_TYPE: sp_core::crypto::KeyTypeId = sp_core::crypto::KeyTypeId(*b"dast");
+
+/// sr25519 key used to sign availability attestations
+pub mod crypto {
+    use super::KEY_TYPE;
+    use sp_runtime::{
+        app_crypto::{app_crypto, sr25519},
+        MultiSignature, MultiSigner,
+    };
+
+    app_crypto!(sr25519, KEY_TYPE);
+
+    /// Configured as `AuthorityId` in the runtime
+    pub struct AttesterAuthId;
+
+    impl frame_system::offchain::AppCrypto<MultiSigner, MultiSignature> for AttesterAuthId {
+        type RuntimeAppPublic = Public;
+        type GenericSignature = sp_core::sr25519::Signature;
+        type GenericPublic = sp_core::sr25519::Public;
+    }
+}
+
+/// Outcome of one gateway request
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum GatewayCheck {
+    /// The gateway served the CID
+    Available,
+    /// The gateway answered that the CID is not found
+    Missing,
+    /// Timeout, connection error or unexpected status; the check is deferred
+    Unreachable,
+}
+
+impl GatewayCheck {
+    /// Map an HTTP status code to a check outcome
+    pub fn from_status(code: u16) -> Self {
+        match code {
+            200..=299 => GatewayCheck::Available,
+            404 | 410 => GatewayCheck::Missing,
+            _ => GatewayCheck::Unreachable,
+        }
+    }
+}
+
+impl<T: Config> Pallet<T> {
+    /// Whether the asset has a positive attestation no older than `AvailabilityAttestationTtl`
+    pub fn has_recent_availability(asset_id: &[u8; 32]) -> bool {
+        let now = frame_system::Pallet::<T>::block_number();
+        Self::availability_attestation(asset_id).is_some_and(|attestation| {
+            attestation.available
+                && now.saturating_sub(attestation.block) <= T::AvailabilityAttestationTtl::get()
+        })
+    }
+
+    /// Assets whose availability phase is queued `AvailabilityCheckLead` blocks after `now`
+    /// and that still have metadata to check
+    pub fn availability_checks_due(now: BlockNumberFor<T>) -> Vec<([u8; 32], Vec<u8>)> {
+        let due_block = now.saturating_add(T::AvailabilityCheckLead::get());
+        ReleaseQueue::<T>::get(due_block)
+            .into_iter()
+            .filter(|(asset_id, phase_index)| {
+                AssetCollateral::<T>::get(asset_id)
+                    .and_then(|info| info.release_schedule.get(*phase_index as usize).cloned())
+                    .is_some_and(|phase| {
+                        !phase.is_released && phase.condition == ReleaseCondition::TimeAndAvailability
+                    })
+            })
+            .filter_map(|(asset_id, _)| {
+                let asset = Self::get_asset(&asset_id)?;
+                // 没有 CID 的资产无法检查，阶段保持推迟
+                (!asset.metadata_cid.is_empty()).then(|| (asset_id, asset.metadata_cid))
+            })
+            .collect()
+    }
+
+    /// Off-chain worker entry: check every due asset and submit one signed attestation per answer
+    pub(crate) fn run_availability_checks(now: BlockNumberFor<T>) {
+        let due = Self::availability_checks_due(now);
+        if due.is_empty() {
+            return;
+        }
+
+        let signer = Signer::<T, T::AuthorityId>::any_account();
+        if !signer.can_sign() {
+            log::debug!("没有可用的可用性证明密钥，跳过 {} 个网关检查", due.len());
+            return;
+        }
+
+        for (asset_id, cid) in due {
+            let available = match Self::check_gateway(&cid) {
+                GatewayCheck::Available => true,
+                GatewayCheck::Missing => false,
+                GatewayCheck::Unreachable => {
+                    // 网关不可用不代表数据丢失：不提交证明，释放阶段到期后自动推迟
+                    log::warn!("IPFS 网关不可用，推迟可用性检查：asset_id={:?}", asset_id);
+                    continue;
+                }
+            };
+
+            match signer.send_signed_transaction(|_| Call::submit_availability_attestation { asset_id, available }) {
+                Some((_, Ok(()))) => {}
+                Some((account, Err(()))) => {
+                    log::warn!("可用性证明提交失败：asset_id={:?}, account={:?}", asset_id, account.id);
+                }
+                None => log::warn!("没有可用于提交可用性证明的本地账户"),
+            }
+        }
+    }
+
+    /// HEAD request for `IpfsGatewayUrl ++ cid`, bounded by `GatewayTimeoutMillis`
+    fn check_gateway(cid: &[u8]) -> GatewayCheck {
+        // 无法拼出网关地址时不能断定数据丢失，与网关不可用一样不提交证明
+        let Ok(cid) = core::str::from_utf8(cid) else {
+            log::warn!("CID 不是有效的 UTF-8，跳过网关检查：cid={:?}", cid);
+            return GatewayCheck::Unreachable;
+        };
+        let mut url = String::from(T::IpfsGatewayUrl::get());
+        url.push_str(cid);
+
+        let deadline = sp_io::offchain::timestamp()
+            .add(Duration::from_millis(T::GatewayTimeoutMillis::get()));
+        let Ok(pending) = http::Request::get(&url)
+            .method(http::Method::Other("HEAD"))
+            .deadline(deadline)
+            .send()
+        else {
+            return GatewayCheck::Unreachable;
+        };
+
+        match pending.try_wait(deadline) {
+            Ok(Ok(response)) => GatewayCheck::from_status(response.code),
+            _ => GatewayCheck::Unreachable,
+        }
+    }
+}
@@ -6,7 +6,7 @@ use frame_benchmarking::v2::*;
 use frame_support::traits::{ Currency, Get };
 use frame_system::RawOrigin;
 use sp_core::H256;
-use sp_runtime::traits::{ Saturating, SaturatedConversion };
+use sp_runtime::traits::{ Saturating, SaturatedConversion, Zero };
 use sp_std::vec;
 
 // 为基准测试创建账户并提供资金
@@ -104,6 +104,8 @@ mod benchmarks {
         let collateral = T::BaseCollateral::get()
             .saturating_add(T::CollateralPerMB::get());
         T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+        // 新所有者需要承接仍在锁定的质押金
+        T::Currency::make_free_balance_be(&new_owner, collateral * 10u32.into());
 
         assert!(DataAssets::<T>::register_asset(
             RawOrigin::Signed(owner.clone()).into(),
@@ -276,6 +278,33 @@ mod benchmarks {
         slash_asset_collateral(RawOrigin::Root, asset_id, slash_percentage);
     }
 
+    #[benchmark]
+    fn report_size_mismatch() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 100u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        // 补足差额的路径：实际大小为声明的 1024 倍
+        #[extrinsic_call]
+        report_size_mismatch(RawOrigin::Root, asset_id, 1024 * 1024);
+
+        assert_eq!(ClaimedDataSize::<T>::get(&asset_id), Some(1024 * 1024));
+    }
+
     #[benchmark]
     fn authorize_operator() {
         // 设置
@@ -307,6 +336,7 @@ mod benchmarks {
             RawOrigin::Signed(owner.clone()),
             asset_id,
             market,
+            None,
         );
     }
 
@@ -339,11 +369,291 @@ mod benchmarks {
         assert!(DataAssets::<T>::authorize_market(
             RawOrigin::Signed(owner.clone()).into(),
             asset_id,
-            market,
+            market.clone(),
+            None,
         ).is_ok());
 
         #[extrinsic_call]
-        revoke_authorization(RawOrigin::Signed(owner.clone()), asset_id);
+        revoke_authorization(RawOrigin::Signed(owner.clone()), asset_id, market);
+    }
+
+    #[benchmark]
+    fn revoke_all_authorizations(n: Linear<1, 10>) {
+        // 设置
+        let owner = create_funded_account::<T>("owner", 0);
+
+        let raw_data_hash = H256::repeat_byte(0x01);
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        // 授权给 n 个市场
+        for i in 0..n.min(T::MaxOperatorsPerAsset::get()) {
+            let market = create_funded_account::<T>("market", i + 1);
+            assert!(DataAssets::<T>::authorize_market(
+                RawOrigin::Signed(owner.clone()).into(),
+                asset_id,
+                market,
+                None,
+            ).is_ok());
+        }
+
+        #[extrinsic_call]
+        revoke_all_authorizations(RawOrigin::Signed(owner.clone()), asset_id);
+    }
+
+    #[benchmark]
+    fn transfer_certificate() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let holder = create_funded_account::<T>("holder", 1);
+        let new_holder = create_funded_account::<T>("new_holder", 2);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        assert!(DataAssets::<T>::issue_certificate(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            holder.clone(),
+            1u8,
+            None,
+        ).is_ok());
+        let certificate_id = crate::types::RightToken::generate_certificate_id(&asset_id, timestamp, &owner, 0);
+
+        #[extrinsic_call]
+        transfer_certificate(RawOrigin::Signed(holder), asset_id, certificate_id, new_holder.clone());
+
+        assert_eq!(DataAssets::<T>::get_certificate(&asset_id, &certificate_id).unwrap().owner, new_holder);
+    }
+
+    #[benchmark]
+    fn exercise_certificate() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let holder = create_funded_account::<T>("holder", 1);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        assert!(DataAssets::<T>::issue_certificate(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            holder.clone(),
+            1u8,
+            None,
+        ).is_ok());
+        let certificate_id = crate::types::RightToken::generate_certificate_id(&asset_id, timestamp, &owner, 0);
+
+        // 最坏情况：设置了行权次数上限，需要读写额度
+        assert!(DataAssets::<T>::set_usage_quota(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            certificate_id,
+            Some(10),
+        ).is_ok());
+
+        #[extrinsic_call]
+        exercise_certificate(RawOrigin::Signed(holder), asset_id, certificate_id);
+
+        assert_eq!(DownloadCount::<T>::get(&asset_id), 1);
+        assert_eq!(CertificateUsageQuota::<T>::get(&asset_id, &certificate_id), Some(9));
+    }
+
+    #[benchmark]
+    fn extend_certificate() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let market = create_funded_account::<T>("market", 1);
+        let holder = create_funded_account::<T>("holder", 2);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        assert!(DataAssets::<T>::issue_certificate(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            holder,
+            1u8,
+            None,
+        ).is_ok());
+        let certificate_id = crate::types::RightToken::generate_certificate_id(&asset_id, timestamp, &owner, 0);
+
+        // 最坏情况：由被授权的市场延期，需要额外读取授权记录
+        assert!(DataAssets::<T>::authorize_market(
+            RawOrigin::Signed(owner).into(),
+            asset_id,
+            market.clone(),
+            None,
+        ).is_ok());
+        let new_valid_until = timestamp.saturating_add(T::MaxCertificateValidity::get());
+
+        #[extrinsic_call]
+        extend_certificate(RawOrigin::Signed(market), asset_id, certificate_id, new_valid_until);
+
+        assert_eq!(
+            DataAssets::<T>::get_certificate(&asset_id, &certificate_id).unwrap().valid_until,
+            Some(new_valid_until)
+        );
+    }
+
+    #[benchmark]
+    fn recalculate_collateral() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 100u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        // 补足差额的路径：按 1MB 计算的质押金高于注册时锁定的金额
+        ClaimedDataSize::<T>::insert(&asset_id, 1024 * 1024);
+
+        #[extrinsic_call]
+        recalculate_collateral(RawOrigin::Root, asset_id);
+
+        let (required, _, _) = DataAssets::<T>::calculate_collateral(1024 * 1024);
+        assert_eq!(AssetCollateral::<T>::get(&asset_id).unwrap().total_amount, required);
+    }
+
+    #[benchmark]
+    fn set_registration_exempt() {
+        let who = create_funded_account::<T>("who", 0);
+
+        #[extrinsic_call]
+        set_registration_exempt(RawOrigin::Root, who.clone(), true);
+
+        assert!(DataAssets::<T>::is_registration_exempt(&who));
+    }
+
+    #[benchmark]
+    fn set_usage_quota() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let holder = create_funded_account::<T>("holder", 1);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        assert!(DataAssets::<T>::issue_certificate(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            holder,
+            1u8,
+            None,
+        ).is_ok());
+        let certificate_id = crate::types::RightToken::generate_certificate_id(&asset_id, timestamp, &owner, 0);
+
+        #[extrinsic_call]
+        set_usage_quota(RawOrigin::Signed(owner), asset_id, certificate_id, Some(10));
+
+        assert_eq!(CertificateUsageQuota::<T>::get(&asset_id, &certificate_id), Some(10));
+    }
+
+    #[benchmark]
+    fn reclaim_collateral_record() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        // 构造已全部释放的质押记录
+        AssetCollateral::<T>::mutate(&asset_id, |info| {
+            let info = info.as_mut().unwrap();
+            for phase in info.release_schedule.iter_mut() {
+                phase.is_released = true;
+            }
+            info.released_amount = info.total_amount;
+            info.reserved_amount = Zero::zero();
+            info.status = crate::types::CollateralStatus::FullyReleased;
+        });
+
+        #[extrinsic_call]
+        reclaim_collateral_record(RawOrigin::Signed(owner), asset_id);
+
+        assert!(AssetCollateral::<T>::get(&asset_id).is_none());
     }
 
     #[benchmark]
@@ -403,5 +713,381 @@ mod benchmarks {
         assert!(frame_system::Pallet::<T>::events().len() > 0);
     }
 
-    impl_benchmark_test_suite!(DataAssets, crate::tests::new_test_ext(), crate::tests::Test);
+    #[benchmark]
+    fn issue_certificates_batch(n: Linear<1, 50>) {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        // 向 n 个持有者发行使用权证
+        let certificates: Vec<_> = (0..n.min(T::MaxBatchSize::get()))
+            .map(|i| (frame_benchmarking::account::<T::AccountId>("holder", i, 0), 1u8, None))
+            .collect();
+
+        #[extrinsic_call]
+        issue_certificates_batch(RawOrigin::Signed(owner.clone()), asset_id, certificates);
+
+        assert!(CertificateRoots::<T>::contains_key(&asset_id));
+    }
+
+    #[benchmark]
+    fn revoke_all_certificates(n: Linear<1, 50>) {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024 * 1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        // 先发行 n 个权证，一次调用全部撤销
+        let certificates: Vec<_> = (0..n.min(T::MaxBatchSize::get()))
+            .map(|i| (frame_benchmarking::account::<T::AccountId>("holder", i, 0), 1u8, None))
+            .collect();
+        assert!(DataAssets::<T>::issue_certificates_batch(
+            RawOrigin::Signed(owner.clone()).into(),
+            asset_id,
+            certificates,
+        ).is_ok());
+
+        #[extrinsic_call]
+        revoke_all_certificates(RawOrigin::Signed(owner.clone()), asset_id);
+
+        assert_eq!(CertificateCounts::<T>::get(&asset_id), 0);
+    }
+
+    #[benchmark]
+    fn release_orphaned_collateral() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        // 直接删除资产树中的记录，留下孤儿质押
+        let child_info = sp_core::storage::ChildInfo::new_default(b":asset_trie:");
+        frame_support::storage::child::kill(&child_info, &[b"assets/".as_slice(), &asset_id].concat());
+
+        #[extrinsic_call]
+        release_orphaned_collateral(RawOrigin::Root, asset_id);
+
+        assert!(AssetCollateral::<T>::get(&asset_id).is_none());
+    }
+
+    #[benchmark]
+    fn set_revenue_shares(n: Linear<1, 16>) {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        let n = n.min(T::MaxRevenueShareRecipients::get());
+        let shares: Vec<_> = (0..n)
+            .map(|i| (account::<T::AccountId>("contributor", i, 0), sp_runtime::Perbill::from_rational(1u32, n + 1)))
+            .collect();
+
+        #[extrinsic_call]
+        set_revenue_shares(RawOrigin::Signed(owner), asset_id, shares);
+
+        assert_eq!(RevenueShares::<T>::get(&asset_id).unwrap().len() as u32, n);
+    }
+
+    #[benchmark]
+    fn retire_asset() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+
+        #[extrinsic_call]
+        retire_asset(RawOrigin::Signed(owner), asset_id);
+
+        assert!(DataAssets::<T>::get_asset(&asset_id).is_none());
+        assert!(AssetCollateral::<T>::get(&asset_id).is_none());
+    }
+
+    #[benchmark]
+    fn cache_asset_root(n: Linear<1, 1000>) {
+        let owner = create_funded_account::<T>("owner", 0);
+        for i in 0..n {
+            let raw_data_hash = H256::from_low_u64_be(i as u64);
+            let mut asset = crate::types::DataAsset::minimal(
+                owner.clone(), b"Test Asset".to_vec(), b"Test Description".to_vec(), raw_data_hash, i as u64,
+            );
+            asset.asset_id = crate::types::DataAsset::generate_asset_id(&owner, i as u64, &raw_data_hash);
+            asset.token_id = i;
+            assert!(DataAssets::<T>::insert_asset(&asset.asset_id, &asset).is_ok());
+        }
+        let child_info = sp_core::storage::ChildInfo::new_default(b":asset_trie:");
+        frame_support::storage::child::put(&child_info, b"_metadata/next_token_id", &n);
+
+        #[block]
+        {
+            DataAssets::<T>::cache_asset_root();
+        }
+
+        assert_eq!(CachedAssetRoot::<T>::get(), Some(DataAssets::<T>::compute_asset_root()));
+    }
+
+    #[benchmark]
+    fn set_availability_attester() {
+        let who = create_funded_account::<T>("who", 0);
+
+        #[extrinsic_call]
+        set_availability_attester(RawOrigin::Root, who.clone(), true);
+
+        assert!(DataAssets::<T>::is_availability_attester(&who));
+    }
+
+    #[benchmark]
+    fn submit_availability_attestation() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let attester = create_funded_account::<T>("attester", 1);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        AvailabilityAttesters::<T>::insert(&attester, true);
+
+        #[extrinsic_call]
+        submit_availability_attestation(RawOrigin::Signed(attester), asset_id, true);
+
+        assert!(DataAssets::<T>::has_recent_availability(&asset_id));
+    }
+
+    #[benchmark]
+    fn set_transfer_policy() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        // 审批人数取上限，重复检查的开销最大
+        let approvers: Vec<T::AccountId> = (0..crate::types::MAX_TRANSFER_APPROVERS)
+            .map(|i| account::<T::AccountId>("approver", i, 0))
+            .collect();
+        let policy = crate::types::TransferPolicy::Threshold {
+            approvers: approvers.try_into().unwrap(),
+            threshold: crate::types::MAX_TRANSFER_APPROVERS,
+        };
+
+        #[extrinsic_call]
+        set_transfer_policy(RawOrigin::Signed(owner), asset_id, policy.clone());
+
+        assert_eq!(TransferPolicies::<T>::get(&asset_id), Some(policy));
+    }
+
+    #[benchmark]
+    fn approve_transfer() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let new_owner = create_funded_account::<T>("new_owner", 1);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        let approvers: Vec<T::AccountId> = (0..crate::types::MAX_TRANSFER_APPROVERS)
+            .map(|i| account::<T::AccountId>("approver", i, 0))
+            .collect();
+        TransferPolicies::<T>::insert(&asset_id, crate::types::TransferPolicy::Threshold {
+            approvers: approvers.clone().try_into().unwrap(),
+            threshold: crate::types::MAX_TRANSFER_APPROVERS,
+        });
+        // 最后一个审批人审批，已有审批列表最长
+        let (last, others) = approvers.split_last().unwrap();
+        PendingTransfers::<T>::insert(&asset_id, crate::types::PendingTransfer {
+            new_owner: new_owner.clone(),
+            approvals: others.to_vec().try_into().unwrap(),
+            asset_nonce: 0,
+        });
+
+        #[extrinsic_call]
+        approve_transfer(RawOrigin::Signed(last.clone()), asset_id, new_owner);
+
+        assert_eq!(
+            PendingTransfers::<T>::get(&asset_id).unwrap().approvals.len() as u32,
+            crate::types::MAX_TRANSFER_APPROVERS
+        );
+    }
+
+    #[benchmark]
+    fn execute_transfer() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let new_owner = create_funded_account::<T>("new_owner", 1);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        // 新所有者需要承接仍在锁定的质押金
+        T::Currency::make_free_balance_be(&new_owner, collateral * 10u32.into());
+        let approvers: Vec<T::AccountId> = (0..crate::types::MAX_TRANSFER_APPROVERS)
+            .map(|i| account::<T::AccountId>("approver", i, 0))
+            .collect();
+        TransferPolicies::<T>::insert(&asset_id, crate::types::TransferPolicy::Threshold {
+            approvers: approvers.clone().try_into().unwrap(),
+            threshold: crate::types::MAX_TRANSFER_APPROVERS,
+        });
+        PendingTransfers::<T>::insert(&asset_id, crate::types::PendingTransfer {
+            new_owner: new_owner.clone(),
+            approvals: approvers.try_into().unwrap(),
+            asset_nonce: 0,
+        });
+
+        #[extrinsic_call]
+        execute_transfer(RawOrigin::Signed(owner), asset_id);
+
+        assert_eq!(DataAssets::<T>::get_asset(&asset_id).unwrap().owner, new_owner);
+        assert!(TransferPolicies::<T>::get(&asset_id).is_none());
+    }
+
+    #[benchmark]
+    fn approve_policy_change() {
+        let owner = create_funded_account::<T>("owner", 0);
+        let raw_data_hash = H256::repeat_byte(0x01);
+
+        let collateral = T::BaseCollateral::get()
+            .saturating_add(T::CollateralPerMB::get());
+        T::Currency::make_free_balance_be(&owner, collateral * 10u32.into());
+
+        assert!(DataAssets::<T>::register_asset(
+            RawOrigin::Signed(owner.clone()).into(),
+            b"Test Asset".to_vec(),
+            b"Test Description".to_vec(),
+            raw_data_hash,
+            1024,
+        ).is_ok());
+
+        let timestamp = <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>();
+        let asset_id = crate::types::DataAsset::generate_asset_id(&owner, timestamp, &raw_data_hash);
+        let approvers: Vec<T::AccountId> = (0..crate::types::MAX_TRANSFER_APPROVERS)
+            .map(|i| account::<T::AccountId>("approver", i, 0))
+            .collect();
+        let current = crate::types::TransferPolicy::Threshold {
+            approvers: approvers.clone().try_into().unwrap(),
+            threshold: crate::types::MAX_TRANSFER_APPROVERS,
+        };
+        TransferPolicies::<T>::insert(&asset_id, current);
+        // 最后一个审批人审批后变更生效，已有审批列表最长
+        let (last, others) = approvers.split_last().unwrap();
+        let policy = crate::types::TransferPolicy::SingleOwner;
+        PendingPolicyChanges::<T>::insert(&asset_id, crate::types::PendingPolicyChange {
+            policy: policy.clone(),
+            approvals: others.to_vec().try_into().unwrap(),
+        });
+
+        #[extrinsic_call]
+        approve_policy_change(RawOrigin::Signed(last.clone()), asset_id, policy);
+
+        assert!(TransferPolicies::<T>::get(&asset_id).is_none());
+        assert!(PendingPolicyChanges::<T>::get(&asset_id).is_none());
+    }
+
+    impl_benchmark_test_suite!(DataAssets, crate::mock::new_test_ext(), crate::mock::Test);
 }
\ No newline at end of file
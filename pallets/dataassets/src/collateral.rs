@@ -15,32 +15,31 @@ use frame_support::{
     pallet_prelude::DispatchResult,
 };
 use frame_system::pallet_prelude::BlockNumberFor;
-use sp_runtime::traits::{Zero, Saturating, SaturatedConversion, CheckedDiv};
+use sp_runtime::traits::{One, Zero, Saturating, SaturatedConversion, CheckedDiv};
 use frame_support::weights::Weight;
 use crate::types::*;
 use alloc::vec;
 
+/// How many consecutive blocks `enqueue_release` tries when a block's queue is full
+const RELEASE_QUEUE_MAX_PROBE: u32 = 100;
+
 /// Type alias for Balance
 pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 impl<T: Config> Pallet<T> {
     /// Calculate required collateral based on data size
     /// 
-    /// Formula: BaseCollateral + (data_size_mb * CollateralPerMB)
+    /// Formula: BaseCollateral + variable_collateral(data_size_bytes)
     /// Maximum: MaxCollateral
     /// 
     /// # Arguments
     /// * `data_size_bytes` - Size of data in bytes
     /// 
     /// # Returns
-    /// * Calculated collateral amount (capped at MaxCollateral), capped flag
-    pub fn calculate_collateral(data_size_bytes: u64) -> (BalanceOf<T>, bool) {
-        // Convert bytes to MB (minimum 1 MB)
-        let data_size_mb = ((data_size_bytes as u128) / (1024 * 1024)).max(1);
-        
-        // Calculate variable collateral based on data size
-        let variable_collateral = T::CollateralPerMB::get()
-            .saturating_mul(data_size_mb.saturated_into());
+    /// * Calculated collateral amount (capped at MaxCollateral), capped flag,
+    ///   and the uncapped `BaseCollateral + variable_collateral` amount
+    pub fn calculate_collateral(data_size_bytes: u64) -> (BalanceOf<T>, bool, BalanceOf<T>) {
+        let variable_collateral = Self::variable_collateral(data_size_bytes);
             
         // Total collateral = base + variable
         let total_uncapped = T::BaseCollateral::get()
@@ -52,7 +51,25 @@ impl<T: Config> Pallet<T> {
         // 是否超过MaxCollateral
         let is_over_capped = total_uncapped > max_collateral;
 
-        (total_capped, is_over_capped)
+        (total_capped, is_over_capped, total_uncapped)
+    }
+    
+    /// Size-dependent part of the collateral
+    ///
+    /// Billed pro rata per byte instead of per whole MB:
+    /// `ceil(max(data_size_bytes, MinBillableSize) * CollateralPerMB / 1MB)`.
+    /// Sizes below `MinBillableSize` are billed as `MinBillableSize`, and any
+    /// fractional amount is rounded up so a non-empty asset never pays zero.
+    pub(crate) fn variable_collateral(data_size_bytes: u64) -> BalanceOf<T> {
+        const BYTES_PER_MB: u128 = 1024 * 1024;
+
+        let billable_bytes = data_size_bytes.max(T::MinBillableSize::get()) as u128;
+        let per_mb: u128 = T::CollateralPerMB::get().saturated_into();
+        let scaled = per_mb.saturating_mul(billable_bytes);
+        // 向上取整
+        let variable = scaled / BYTES_PER_MB + if scaled % BYTES_PER_MB == 0 { 0 } else { 1 };
+
+        variable.saturated_into()
     }
     
     /// Create a phased release schedule for collateral
@@ -156,7 +173,11 @@ impl<T: Config> Pallet<T> {
             status: CollateralStatus::FullyLocked,
         };
         
+        CollateralByDepositor::<T>::insert(who, asset_id, ());
+
+        Self::enqueue_release_phases(asset_id, &collateral_info.release_schedule);
         AssetCollateral::<T>::insert(asset_id, collateral_info);
+        Self::note_collateral_locked(collateral_amount);
         
         // Emit event
         Self::deposit_event(Event::CollateralLocked {
@@ -168,7 +189,44 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
     
-    /// Process collateral releases for all assets (called in on_initialize)
+    /// Queue every phase of a release schedule in `ReleaseQueue` at its unlock block
+    pub(crate) fn enqueue_release_phases(
+        asset_id: &[u8; 32],
+        schedule: &[ReleasePhase<BlockNumberFor<T>, BalanceOf<T>>],
+    ) {
+        for (index, phase) in schedule.iter().enumerate() {
+            Self::enqueue_release(asset_id, index as u8, phase.unlock_block);
+        }
+    }
+
+    /// Queue one release phase at `due_block`
+    ///
+    /// If the queue of that block is already full (`MaxReleasesPerBlock`),
+    /// the phase is pushed to the next block that still has room. When none of
+    /// the next `RELEASE_QUEUE_MAX_PROBE` blocks has room, the phase goes to
+    /// `ReleaseOverflow` instead, so it is never dropped.
+    pub(crate) fn enqueue_release(asset_id: &[u8; 32], phase_index: u8, due_block: BlockNumberFor<T>) {
+        let mut block = due_block;
+        for _ in 0..RELEASE_QUEUE_MAX_PROBE {
+            let queued = ReleaseQueue::<T>::mutate(block, |queue| {
+                queue.try_push((*asset_id, phase_index)).is_ok()
+            });
+            if queued {
+                return;
+            }
+            block = block.saturating_add(One::one());
+        }
+        log::warn!("质押释放队列已满，转入溢出列表：asset_id={:?}, phase={}", asset_id, phase_index);
+        ReleaseOverflow::<T>::insert((*asset_id, phase_index), due_block);
+    }
+
+    /// Process the collateral phases due at `current_block` (called in on_initialize)
+    ///
+    /// Only entries queued in `ReleaseQueue` for this block are visited, so the
+    /// cost is O(due phases) instead of O(all assets). A phase whose release
+    /// condition is not met yet is re-queued one day later. Afterwards up to
+    /// `MaxReleasesPerBlock` entries of `ReleaseOverflow` are checked and the
+    /// due ones are processed as well.
     /// 
     /// # Arguments
     /// * `current_block` - Current block number
@@ -176,70 +234,99 @@ impl<T: Config> Pallet<T> {
     /// # Returns
     /// * Weight consumed by this operation
     pub fn process_collateral_releases(current_block: BlockNumberFor<T>) -> Weight {
-        let mut weight = T::DbWeight::get().reads(1);
-        let mut releases_processed = 0u32;
-        
-        // Iterate through all collateral entries
-        // Note: In production, consider using a more efficient approach
-        // such as a priority queue or scheduled tasks
-        for (asset_id, mut collateral_info) in AssetCollateral::<T>::iter() {
-            weight = weight.saturating_add(T::DbWeight::get().reads(1));
-            
-            let mut updated = false;
-            
-            // Check each release phase
-            for phase in collateral_info.release_schedule.iter_mut() {
-                // Skip if already released or not yet unlocked
-                if phase.is_released || current_block < phase.unlock_block {
-                    continue;
-                }
-                
-                // Check if release conditions are met
-                if Self::check_release_condition(&asset_id, &phase.condition) {
-                    // Attempt to unreserve (release) the collateral
-                    let unreserved = T::Currency::unreserve(&collateral_info.depositor, phase.amount);
-                    
-                    if unreserved == phase.amount {
-                        // Successfully released
-                        phase.is_released = true;
-                        collateral_info.released_amount = 
-                            collateral_info.released_amount.saturating_add(phase.amount);
-                        collateral_info.reserved_amount = 
-                            collateral_info.reserved_amount.saturating_sub(phase.amount);
-                        updated = true;
-                        releases_processed = releases_processed.saturating_add(1);
-                        
-                        // Emit event
-                        Self::deposit_event(Event::CollateralReleased {
-                            asset_id,
-                            amount: phase.amount,
-                            phase: phase.percentage,
-                        });
-                        
-                        weight = weight.saturating_add(T::DbWeight::get().writes(1));
-                    }
-                }
-            }
-            
-            // Update collateral status if changes were made
-            if updated {
-                if collateral_info.reserved_amount.is_zero() {
-                    collateral_info.status = CollateralStatus::FullyReleased;
-                } else {
-                    collateral_info.status = CollateralStatus::PartiallyReleased;
-                }
-                AssetCollateral::<T>::insert(asset_id, collateral_info);
-                weight = weight.saturating_add(T::DbWeight::get().writes(1));
-            }
-            
-            // 限制100个操作防止区块过载，应该根据实际权重调整
-            if releases_processed >= 100 {
-                break;
+        let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+        for (asset_id, phase_index) in ReleaseQueue::<T>::take(current_block) {
+            weight = weight.saturating_add(Self::process_release_phase(asset_id, phase_index, current_block));
+        }
+
+        let overflow: Vec<(([u8; 32], u8), BlockNumberFor<T>)> = ReleaseOverflow::<T>::iter()
+            .take(T::MaxReleasesPerBlock::get() as usize)
+            .collect();
+        weight = weight.saturating_add(T::DbWeight::get().reads(overflow.len() as u64 + 1));
+        for ((asset_id, phase_index), due_block) in overflow {
+            if due_block > current_block {
+                continue;
             }
+            ReleaseOverflow::<T>::remove((asset_id, phase_index));
+            weight = weight
+                .saturating_add(T::DbWeight::get().writes(1))
+                .saturating_add(Self::process_release_phase(asset_id, phase_index, current_block));
         }
         
         weight
     }
+
+    /// Release one due phase, or re-queue it one day later when its condition is not met
+    fn process_release_phase(asset_id: [u8; 32], phase_index: u8, current_block: BlockNumberFor<T>) -> Weight {
+        let weight = T::DbWeight::get().reads(1);
+
+        // 记录已被回收（或资产已不存在）时直接跳过
+        let Some(mut collateral_info) = AssetCollateral::<T>::get(&asset_id) else {
+            return weight;
+        };
+
+        // 已全部释放的记录直接回收
+        if Self::is_collateral_finished(&collateral_info) {
+            Self::remove_collateral_record(&asset_id, &collateral_info.depositor);
+            return weight.saturating_add(T::DbWeight::get().writes(1));
+        }
+
+        let depositor = collateral_info.depositor.clone();
+        let Some(phase) = collateral_info.release_schedule.get_mut(phase_index as usize) else {
+            return weight;
+        };
+        if phase.is_released {
+            return weight;
+        }
+
+        // 释放条件未满足，一天后重试；质押已全部罚没或释放时没有可解锁的金额，不再等待条件
+        let nothing_left = collateral_info.reserved_amount.is_zero();
+        if !nothing_left && !Self::check_release_condition(&asset_id, &phase.condition) {
+            Self::enqueue_release(&asset_id, phase_index, current_block.saturating_add(Self::blocks_in_days(1)));
+            return weight.saturating_add(T::DbWeight::get().writes(1));
+        }
+
+        // 罚没后剩余的质押可能少于计划金额：最多解锁本记录仍锁定的部分，避免解锁质押人其他资产的质押；
+        // unreserve 返回未能解锁的部分。不足时仍标记为已释放，否则该阶段永远无法完成
+        let scheduled = phase.amount;
+        let requested = scheduled.min(collateral_info.reserved_amount);
+        let remaining = T::Currency::unreserve(&depositor, requested);
+        let released = requested.saturating_sub(remaining);
+
+        phase.is_released = true;
+        let percentage = phase.percentage;
+        collateral_info.released_amount = collateral_info.released_amount.saturating_add(released);
+        collateral_info.reserved_amount = collateral_info.reserved_amount.saturating_sub(released);
+        Self::note_collateral_unlocked(released);
+        collateral_info.status = if collateral_info.reserved_amount.is_zero() {
+            CollateralStatus::FullyReleased
+        } else {
+            CollateralStatus::PartiallyReleased
+        };
+
+        if released < scheduled {
+            Self::deposit_event(Event::PartialCollateralRelease {
+                asset_id,
+                scheduled,
+                released,
+                phase: percentage,
+            });
+        } else {
+            Self::deposit_event(Event::CollateralReleased {
+                asset_id,
+                amount: released,
+                phase: percentage,
+            });
+        }
+
+        if Self::is_collateral_finished(&collateral_info) {
+            Self::remove_collateral_record(&asset_id, &depositor);
+        } else {
+            AssetCollateral::<T>::insert(asset_id, collateral_info);
+        }
+        weight.saturating_add(T::DbWeight::get().writes(2))
+    }
     
     /// Check if release condition is satisfied
     /// 
@@ -274,16 +361,9 @@ impl<T: Config> Pallet<T> {
                 }
             }
             ReleaseCondition::TimeAndAvailability => {
-                // Check if IPFS data is continuously available
-                // This should be verified by off-chain workers
-                // For now, we assume availability if asset exists
-                if let Some(_asset) = Self::get_asset(asset_id) {
-                    // TODO: 应该检查 IPFS 数据是否可访问
-                    // This will require off-chain worker integration
-                    true
-                } else {
-                    false
-                }
+                // Require a recent positive gateway attestation from the off-chain worker;
+                // a missing, stale or negative attestation defers the phase (never slashes)
+                Self::get_asset(asset_id).is_some() && Self::has_recent_availability(asset_id)
             }
         }
     }
@@ -326,6 +406,7 @@ impl<T: Config> Pallet<T> {
         // Update collateral info
         collateral_info.reserved_amount = collateral_info.reserved_amount.saturating_sub(actual_slashed);
         collateral_info.status = CollateralStatus::Slashed(actual_slashed);
+        Self::note_collateral_unlocked(actual_slashed);
         
         AssetCollateral::<T>::insert(asset_id, collateral_info);
         
@@ -339,6 +420,186 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
     
+    /// Raise an asset's collateral to what `actual_size_bytes` requires
+    ///
+    /// The depositor reserves the difference, which is released together with
+    /// the last pending phase (see `add_collateral_top_up`). If the depositor
+    /// cannot cover it, all remaining collateral is slashed instead.
+    ///
+    /// # Returns
+    /// * `(top_up, slashed)` - amount additionally reserved, and whether the collateral was slashed
+    pub(crate) fn recollateralize(
+        asset_id: &[u8; 32],
+        actual_size_bytes: u64,
+    ) -> Result<(BalanceOf<T>, bool), sp_runtime::DispatchError> {
+        let mut collateral_info = AssetCollateral::<T>::get(asset_id)
+            .ok_or(Error::<T>::CollateralNotFound)?;
+
+        let (required, _, _) = Self::calculate_collateral(actual_size_bytes);
+        let top_up = required.saturating_sub(Self::effective_collateral(&collateral_info));
+        if top_up.is_zero() {
+            return Ok((top_up, false));
+        }
+
+        match Self::add_collateral_top_up(asset_id, &mut collateral_info, top_up) {
+            Ok(()) => {}
+            Err(e) if e == Error::<T>::InsufficientBalance.into() => {
+                Self::slash_collateral(asset_id, 100)?;
+                return Ok((Zero::zero(), true));
+            }
+            Err(e) => return Err(e),
+        }
+        AssetCollateral::<T>::insert(asset_id, collateral_info);
+
+        Ok((top_up, false))
+    }
+
+    /// Reserve `top_up` from the depositor and schedule its release
+    ///
+    /// The amount is added to the last pending phase of the registration
+    /// schedule, so it unlocks together with that phase. When every such phase
+    /// has already been released, a new `TimeAndAvailability` phase due in
+    /// 90 days is added for it. `TimeOnly` phases are never used: they record
+    /// excess released by `recalculate_collateral`.
+    ///
+    /// Fails with `ReleaseScheduleFull` or `InsufficientBalance` before anything
+    /// is reserved or queued; the caller stores the updated `info`.
+    pub(crate) fn add_collateral_top_up(
+        asset_id: &[u8; 32],
+        info: &mut CollateralInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        top_up: BalanceOf<T>,
+    ) -> DispatchResult {
+        let pending = info.release_schedule
+            .iter()
+            .rposition(|phase| !phase.is_released && phase.condition != ReleaseCondition::TimeOnly);
+        ensure!(
+            pending.is_some() || !info.release_schedule.is_full(),
+            Error::<T>::ReleaseScheduleFull
+        );
+
+        T::Currency::reserve(&info.depositor, top_up)
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
+        info.total_amount = info.total_amount.saturating_add(top_up);
+        info.reserved_amount = info.reserved_amount.saturating_add(top_up);
+        Self::note_collateral_locked(top_up);
+
+        match pending {
+            Some(index) => {
+                let phase = &mut info.release_schedule[index];
+                phase.amount = phase.amount.saturating_add(top_up);
+            }
+            None => {
+                let unlock_block = frame_system::Pallet::<T>::block_number()
+                    .saturating_add(Self::blocks_in_days(90));
+                info.release_schedule
+                    .try_push(ReleasePhase {
+                        percentage: 0,
+                        amount: top_up,
+                        unlock_block,
+                        condition: ReleaseCondition::TimeAndAvailability,
+                        is_released: false,
+                    })
+                    .map_err(|_| Error::<T>::ReleaseScheduleFull)?;
+                let phase_index = (info.release_schedule.len() - 1) as u8;
+                Self::enqueue_release(asset_id, phase_index, unlock_block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Collateral an asset is currently required to hold
+    ///
+    /// `total_amount` minus the excess already scheduled for release by
+    /// `recalculate_collateral`. Those releases are the only `TimeOnly` phases,
+    /// the registration schedule never uses that condition.
+    pub(crate) fn effective_collateral(info: &CollateralInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) -> BalanceOf<T> {
+        let excess_released = info.release_schedule
+            .iter()
+            .filter(|phase| phase.condition == ReleaseCondition::TimeOnly)
+            .fold(Zero::zero(), |total: BalanceOf<T>, phase| total.saturating_add(phase.amount));
+        info.total_amount.saturating_sub(excess_released)
+    }
+
+    /// Bring an asset's collateral in line with the current collateral parameters
+    ///
+    /// The requirement is recomputed from the declared data size. A higher
+    /// requirement is reserved from the depositor right away and released with
+    /// the last pending phase (see `add_collateral_top_up`); a lower one adds a
+    /// `TimeOnly` phase that releases the excess in the next block.
+    ///
+    /// # Returns
+    /// * `(previous, required, top_up, scheduled_release)`
+    pub(crate) fn recalculate_asset_collateral(
+        asset_id: &[u8; 32],
+    ) -> Result<(BalanceOf<T>, BalanceOf<T>, BalanceOf<T>, BalanceOf<T>), sp_runtime::DispatchError> {
+        let data_size = ClaimedDataSize::<T>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+        let mut collateral_info = AssetCollateral::<T>::get(asset_id)
+            .ok_or(Error::<T>::CollateralNotFound)?;
+
+        let previous = Self::effective_collateral(&collateral_info);
+        let (required, _, _) = Self::calculate_collateral(data_size);
+
+        let top_up = required.saturating_sub(previous);
+        if !top_up.is_zero() {
+            Self::add_collateral_top_up(asset_id, &mut collateral_info, top_up)?;
+        }
+
+        // 已罚没或已释放的部分无法再释放，超出部分以仍锁定的金额为上限
+        let release = previous.saturating_sub(required).min(collateral_info.reserved_amount);
+        if !release.is_zero() {
+            let unlock_block = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+            collateral_info.release_schedule
+                .try_push(ReleasePhase {
+                    percentage: 0,
+                    amount: release,
+                    unlock_block,
+                    condition: ReleaseCondition::TimeOnly,
+                    is_released: false,
+                })
+                .map_err(|_| Error::<T>::ReleaseScheduleFull)?;
+            let phase_index = (collateral_info.release_schedule.len() - 1) as u8;
+            Self::enqueue_release(asset_id, phase_index, unlock_block);
+        }
+
+        AssetCollateral::<T>::insert(asset_id, collateral_info);
+        Ok((previous, required, top_up, release))
+    }
+
+    /// Move the collateral obligation of an asset to its new owner
+    ///
+    /// The new owner reserves the still-locked amount, the previous depositor's
+    /// reserve is released, and the remaining release schedule continues unchanged.
+    /// Must be called before any other transfer writes, so a failure leaves no state behind.
+    pub(crate) fn transfer_collateral(asset_id: &[u8; 32], new_depositor: &T::AccountId) -> DispatchResult {
+        let Some(mut collateral_info) = AssetCollateral::<T>::get(asset_id) else {
+            return Ok(());
+        };
+        let old_depositor = collateral_info.depositor.clone();
+        if old_depositor == *new_depositor {
+            return Ok(());
+        }
+
+        let amount = collateral_info.reserved_amount;
+        T::Currency::reserve(new_depositor, amount)
+            .map_err(|_| Error::<T>::InsufficientBalance)?;
+        T::Currency::unreserve(&old_depositor, amount);
+
+        CollateralByDepositor::<T>::insert(new_depositor, asset_id, ());
+        CollateralByDepositor::<T>::remove(&old_depositor, asset_id);
+
+        collateral_info.depositor = new_depositor.clone();
+        AssetCollateral::<T>::insert(asset_id, collateral_info);
+
+        Self::deposit_event(Event::CollateralTransferred {
+            asset_id: *asset_id,
+            from: old_depositor,
+            to: new_depositor.clone(),
+            amount,
+        });
+
+        Ok(())
+    }
+
     /// Calculate blocks in hours based on block time
     fn blocks_in_hours(hours: u32) -> BlockNumberFor<T> {
         // MILLI_SECS_PER_BLOCK is defined in your runtime (e.g., 18000ms = 18s)
@@ -352,8 +613,153 @@ impl<T: Config> Pallet<T> {
         Self::blocks_in_hours(days.saturating_mul(24))
     }
     
+    /// Whether a collateral record has nothing left to track:
+    /// status is `FullyReleased` and every release phase has been released
+    pub fn is_collateral_finished(info: &CollateralInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>) -> bool {
+        info.status == CollateralStatus::FullyReleased
+            && info.release_schedule.iter().all(|phase| phase.is_released)
+    }
+
+    /// Remove a finished collateral record and emit `CollateralRecordReclaimed`
+    pub(crate) fn remove_collateral_record(asset_id: &[u8; 32], depositor: &T::AccountId) {
+        AssetCollateral::<T>::remove(asset_id);
+        CollateralByDepositor::<T>::remove(depositor, asset_id);
+        Self::deposit_event(Event::CollateralRecordReclaimed {
+            asset_id: *asset_id,
+            depositor: depositor.clone(),
+        });
+    }
+
+    /// Add newly reserved collateral to `TotalCollateralLocked`
+    fn note_collateral_locked(amount: BalanceOf<T>) {
+        TotalCollateralLocked::<T>::mutate(|total| *total = total.saturating_add(amount));
+    }
+
+    /// Remove released, slashed or settled collateral from `TotalCollateralLocked`
+    fn note_collateral_unlocked(amount: BalanceOf<T>) {
+        TotalCollateralLocked::<T>::mutate(|total| *total = total.saturating_sub(amount));
+    }
+
     /// Get collateral info for an asset
     pub fn get_collateral_info(asset_id: &[u8; 32]) -> Option<CollateralInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
         AssetCollateral::<T>::get(asset_id)
     }
+
+    /// Total collateral still reserved across all assets deposited by `who`
+    ///
+    /// Walks the whole depositor index; intended for runtime API / off-chain use only.
+    pub fn total_locked_collateral(who: &T::AccountId) -> BalanceOf<T> {
+        CollateralByDepositor::<T>::iter_key_prefix(who)
+            .filter_map(|asset_id| AssetCollateral::<T>::get(asset_id))
+            .fold(Zero::zero(), |total: BalanceOf<T>, info| total.saturating_add(info.reserved_amount))
+    }
+
+    /// One page of the assets `who` holds collateral records for, in storage key order
+    ///
+    /// Starts after `start_after` (exclusive) and returns at most
+    /// `min(limit, MaxAssetsPerDepositor)` asset ids; pass the last id of a page to get the next.
+    pub fn collateral_assets_of(who: &T::AccountId, start_after: Option<[u8; 32]>, limit: u32) -> Vec<[u8; 32]> {
+        let limit = limit.min(T::MaxAssetsPerDepositor::get()) as usize;
+        let iter = match start_after {
+            Some(last) => CollateralByDepositor::<T>::iter_key_prefix_from(
+                who,
+                CollateralByDepositor::<T>::hashed_key_for(who, last),
+            ),
+            None => CollateralByDepositor::<T>::iter_key_prefix(who),
+        };
+        iter.take(limit).collect()
+    }
+
+    /// Like `collateral_assets_of`, paired with each asset's still-reserved collateral
+    pub fn locked_collateral_page(
+        who: &T::AccountId,
+        start_after: Option<[u8; 32]>,
+        limit: u32,
+    ) -> Vec<([u8; 32], BalanceOf<T>)> {
+        Self::collateral_assets_of(who, start_after, limit)
+            .into_iter()
+            .filter_map(|asset_id| AssetCollateral::<T>::get(asset_id).map(|info| (asset_id, info.reserved_amount)))
+            .collect()
+    }
+
+    /// Collateral records whose `asset_id` no longer resolves via `get_asset`
+    ///
+    /// Funds reserved by these records are locked against a non-existent asset
+    /// until governance calls `release_orphaned_collateral`
+    pub fn find_orphaned_collateral() -> Vec<[u8; 32]> {
+        AssetCollateral::<T>::iter_keys()
+            .filter(|asset_id| Self::get_asset(asset_id).is_none())
+            .collect()
+    }
+
+    /// Unreserve whatever an orphaned record still holds and remove the record
+    ///
+    /// Release queue entries of the asset are left in place; they are skipped
+    /// once the record is gone. Returns the depositor and the amount returned.
+    pub(crate) fn release_orphaned_collateral_record(
+        asset_id: &[u8; 32],
+    ) -> Result<(T::AccountId, BalanceOf<T>), sp_runtime::DispatchError> {
+        ensure!(AssetCollateral::<T>::contains_key(asset_id), Error::<T>::CollateralNotFound);
+        ensure!(Self::get_asset(asset_id).is_none(), Error::<T>::CollateralNotOrphaned);
+
+        ClaimedDataSize::<T>::remove(asset_id);
+        Self::settle_collateral_record(asset_id).ok_or_else(|| Error::<T>::CollateralNotFound.into())
+    }
+
+    /// Unreserve everything a collateral record still holds, bypassing the release schedule,
+    /// and remove the record. Returns the depositor and the amount actually unreserved,
+    /// or `None` when the asset has no collateral record.
+    pub(crate) fn settle_collateral_record(asset_id: &[u8; 32]) -> Option<(T::AccountId, BalanceOf<T>)> {
+        let info = AssetCollateral::<T>::take(asset_id)?;
+        let remaining = T::Currency::unreserve(&info.depositor, info.reserved_amount);
+        let released = info.reserved_amount.saturating_sub(remaining);
+        Self::note_collateral_unlocked(info.reserved_amount);
+
+        Self::remove_from_depositor_index(asset_id, &info.depositor);
+        Some((info.depositor, released))
+    }
+
+    /// Check collateral accounting invariants (run by try-runtime and tests)
+    ///
+    /// 1. `reserved_amount + released_amount <= total_amount` for every asset
+    ///    (slashed collateral leaves both reserved and released, so equality is not required)
+    /// 2. The sum of `reserved_amount` per depositor never exceeds the depositor's
+    ///    actual reserved balance (other pallets may reserve on top of it)
+    /// 3. Every collateral record belongs to an asset that still exists
+    /// 4. `TotalCollateralLocked` equals the sum of all `reserved_amount`
+    #[cfg(any(feature = "try-runtime", test))]
+    pub(crate) fn do_try_state_collateral() -> Result<(), sp_runtime::TryRuntimeError> {
+        use alloc::collections::BTreeMap;
+
+        let mut reserved_by_depositor: BTreeMap<T::AccountId, BalanceOf<T>> = BTreeMap::new();
+        let mut total_reserved = BalanceOf::<T>::zero();
+        for (_asset_id, info) in AssetCollateral::<T>::iter() {
+            ensure!(
+                info.reserved_amount.saturating_add(info.released_amount) <= info.total_amount,
+                "AssetCollateral reserved + released exceeds total_amount"
+            );
+            let total = reserved_by_depositor.entry(info.depositor).or_insert_with(Zero::zero);
+            *total = total.saturating_add(info.reserved_amount);
+            total_reserved = total_reserved.saturating_add(info.reserved_amount);
+        }
+
+        ensure!(
+            TotalCollateralLocked::<T>::get() == total_reserved,
+            "TotalCollateralLocked does not match the sum of AssetCollateral reserved_amount"
+        );
+
+        ensure!(
+            Self::find_orphaned_collateral().is_empty(),
+            "AssetCollateral holds records for assets that no longer exist"
+        );
+
+        for (depositor, reserved) in reserved_by_depositor {
+            ensure!(
+                reserved <= T::Currency::reserved_balance(&depositor),
+                "AssetCollateral reserved_amount exceeds depositor's reserved balance"
+            );
+        }
+
+        Ok(())
+    }
 }
\ No newline at end of file
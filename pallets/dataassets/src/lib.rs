@@ -21,10 +21,15 @@ pub use pallet::*;
 pub mod types;
 pub mod digest_item;
 pub mod collateral;
+pub mod availability;
+pub mod migrations;
 
 pub mod weights;
 pub use weights::WeightInfo;
 
+#[cfg(test)]
+mod mock;
+
 #[cfg(test)]
 mod tests;
 
@@ -35,17 +40,25 @@ pub use collateral::BalanceOf;
 
 // 需要和 runtime/src/lib.rs 中的对应值保持一致
 pub const MILLI_SECS_PER_BLOCK: u64 = 6000;
+/// 权证到期队列的时间桶宽度（毫秒），valid_until 落在同一分钟内的权证进入同一个桶
+pub const EXPIRY_BUCKET_MILLIS: u64 = 60_000;
+/// 到期桶已满时最多向后顺延的桶数
+const EXPIRY_QUEUE_MAX_PROBE: u64 = 100;
+
+// 存储版本，存储结构变更时递增并在 migrations 中添加对应的迁移
+const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(3);
 
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, transactional};
     use frame_system::pallet_prelude::*;
     use sp_core::H256;
     use frame_support::storage::child;
-    use sp_runtime::traits::{SaturatedConversion, Saturating};
-    use frame_support::traits::{Currency, ReservableCurrency};
-    use pallet_shared_traits::IncentiveHandler;
+    use sp_runtime::traits::{Hash as HashT, SaturatedConversion, Saturating, Zero};
+    use frame_support::traits::{Currency, ExistenceRequirement, ReservableCurrency};
+    use pallet_shared_traits::{IncentiveHandler, StorageProofProvider};
+    use sp_runtime::{PerThing, Perbill};
 
     use crate::types::*;
 
@@ -63,13 +76,39 @@ pub mod pallet {
         fn slash_collateral() -> Weight;
         fn authorize_operator() -> Weight;
         fn revoke_authorization() -> Weight;
+        fn revoke_all_authorizations(n: u32) -> Weight;
+        fn transfer_certificate() -> Weight;
+        fn reclaim_collateral_record() -> Weight;
+        fn issue_certificates_batch(n: u32) -> Weight;
+        fn revoke_all_certificates(n: u32) -> Weight;
+        fn report_size_mismatch() -> Weight;
+        fn exercise_certificate() -> Weight;
+        fn set_usage_quota() -> Weight;
+        fn extend_certificate() -> Weight;
+        fn recalculate_collateral() -> Weight;
+        fn set_registration_exempt() -> Weight;
+        fn release_orphaned_collateral() -> Weight;
+        fn set_revenue_shares(n: u32) -> Weight;
+        fn retire_asset() -> Weight;
+        fn cache_asset_root(n: u32) -> Weight;
+        fn set_availability_attester() -> Weight;
+        fn submit_availability_attestation() -> Weight;
+        fn set_transfer_policy() -> Weight;
+        fn approve_transfer() -> Weight;
+        fn execute_transfer() -> Weight;
+        fn approve_policy_change() -> Weight;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_timestamp::Config {
+    pub trait Config:
+        frame_system::Config
+        + pallet_timestamp::Config
+        + frame_system::offchain::CreateSignedTransaction<Call<Self>>
+    {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
         /// Currency type for handling collateral
@@ -87,14 +126,138 @@ pub mod pallet {
         #[pallet::constant]
         type MaxCollateral: Get<BalanceOf<Self>>;
 
+        /// Minimum billable data size in bytes (e.g., 1 KB)
+        /// Smaller assets are charged as if they were this size
+        #[pallet::constant]
+        type MinBillableSize: Get<u64>;
+
         #[pallet::constant]
         type MaxNameLength: Get<u32>;
         
         #[pallet::constant]
         type MaxDescriptionLength: Get<u32>;
 
+        /// Maximum number of collateral release phases processed in one block
+        #[pallet::constant]
+        type MaxReleasesPerBlock: Get<u32>;
+
+        /// Maximum number of markets a single asset can be authorized to at the same time
+        #[pallet::constant]
+        type MaxOperatorsPerAsset: Get<u32>;
+
+        /// Maximum number of certificates issued by a single batch call
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Maximum number of depositor asset ids returned by one collateral query page
+        /// or listed in a `CreatorCollateralSlashed` event; does not limit registration
+        #[pallet::constant]
+        type MaxAssetsPerDepositor: Get<u32>;
+
+        /// Maximum number of certificates indexed per issuer (and per proxying market)
+        #[pallet::constant]
+        type MaxCertificatesPerIssuer: Get<u32>;
+
+        /// Maximum number of live certificates per asset, bounding the certificate child trie
+        #[pallet::constant]
+        type MaxCertificatesPerAsset: Get<u32>;
+
+        /// Minimum certificate lifetime in milliseconds: `valid_until` must be at least now + this
+        #[pallet::constant]
+        type MinCertificateValidity: Get<u64>;
+
+        /// Maximum certificate lifetime in milliseconds: `valid_until` must be at most now + this
+        #[pallet::constant]
+        type MaxCertificateValidity: Get<u64>;
+
+        /// Whether certificates without `valid_until` (perpetual) may be issued
+        #[pallet::constant]
+        type AllowPerpetualCertificates: Get<bool>;
+
+        /// Maximum number of due certificates marked expired in one block's on_initialize sweep
+        #[pallet::constant]
+        type MaxExpirationsPerBlock: Get<u32>;
+
+        /// Maximum number of certificates queued in one `ExpiryQueue` bucket; overflow spills into later buckets
+        #[pallet::constant]
+        type MaxExpirationsPerBucket: Get<u32>;
+
+        /// Whether `transfer_asset` requires every certificate to be revoked first
+        #[pallet::constant]
+        type RequireCertificatesRevokedOnTransfer: Get<bool>;
+
+        /// Flat fee charged on every ownership transfer (zero disables it)
+        #[pallet::constant]
+        type TransferFee: Get<BalanceOf<Self>>;
+
+        /// Fee charged on ownership transfers as a share of the asset's base_price
+        #[pallet::constant]
+        type TransferFeeRatio: Get<Perbill>;
+
+        /// Account receiving transfer fees (the ecosystem incentive pool)
+        #[pallet::constant]
+        type IncentivePoolAccount: Get<Self::AccountId>;
+
+        /// Fee prepaid by the issuer for every certificate, covering its exercise (zero disables it)
+        #[pallet::constant]
+        type CertificateIssuanceFee: Get<BalanceOf<Self>>;
+
+        /// Account escrowing prepaid issuance fees until the certificate is exercised or revoked
+        #[pallet::constant]
+        type ExerciseFeePoolAccount: Get<Self::AccountId>;
+
+        /// Maximum number of lineage entries kept per asset; the oldest entries are pruned first
+        #[pallet::constant]
+        type MaxLineageDepth: Get<u32>;
+
+        /// Minimum number of blocks between two `register_asset` calls from the same account (zero disables the limit)
+        #[pallet::constant]
+        type MinBlocksBetweenRegistrations: Get<BlockNumberFor<Self>>;
+
+        /// Long-term share of the asset's base_price paid by the market on every market transfer,
+        /// split across the asset's revenue-share recipients (zero disables it)
+        #[pallet::constant]
+        type LongTermShareRatio: Get<Perbill>;
+
+        /// Maximum number of revenue-share recipients per asset besides the owner
+        #[pallet::constant]
+        type MaxRevenueShareRecipients: Get<u32>;
+
+        /// Maximum number of live assets indexed under the same `raw_data_hash`;
+        /// assets registered once the index is full are still registered but not indexed
+        #[pallet::constant]
+        type MaxAssetsPerContentHash: Get<u32>;
+
+        /// Compute the asset trie root in `on_initialize` (metered) and publish it as a
+        /// digest item in `on_finalize`; the published root reflects the trie at block start
+        #[pallet::constant]
+        type PublishAssetRootDigest: Get<bool>;
+
+        /// Key the off-chain worker signs availability attestations with
+        type AuthorityId: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>;
+
+        /// IPFS HTTP gateway queried by the off-chain worker; the asset's CID is appended
+        /// (e.g. `http://127.0.0.1:8080/ipfs/`)
+        type IpfsGatewayUrl: Get<&'static str>;
+
+        /// Timeout of a single gateway request, in milliseconds
+        #[pallet::constant]
+        type GatewayTimeoutMillis: Get<u64>;
+
+        /// How many blocks before an availability-gated phase unlocks the off-chain worker
+        /// checks the gateway; must be shorter than the one-day retry of a deferred phase
+        #[pallet::constant]
+        type AvailabilityCheckLead: Get<BlockNumberFor<Self>>;
+
+        /// Attestations older than this many blocks are ignored when releasing collateral
+        #[pallet::constant]
+        type AvailabilityAttestationTtl: Get<BlockNumberFor<Self>>;
+
         /// Incentive handler trait
         type IncentiveHandler: IncentiveHandler<Self::AccountId, [u8; 32], BalanceOf<Self>>;
+
+        /// Storage proof queries used by asset_health (`()` when no storage pallet is present)
+        type StorageProofProvider: StorageProofProvider<[u8; 32], BlockNumberFor<Self>>;
         type WeightInfo: WeightInfo;
     }
 
@@ -108,36 +271,395 @@ pub mod pallet {
         CollateralInfo<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
     >;
 
+    // 质押人 -> 资产ID -> ()，记录其仍有质押记录的资产，用于汇总查询（不限制数量）
+    #[pallet::storage]
+    pub type CollateralByDepositor<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        [u8; 32],
+        (),
+        OptionQuery
+    >;
+
+    // 发行者（元证持有者） -> 以其名义发行且仍然有效的权证 (asset_id, token_id)
+    // 权证被撤销、删除或过期时移除，过期权证延期重新生效时重新记入
+    #[pallet::storage]
+    #[pallet::getter(fn certificates_by_issuer)]
+    pub type CertificatesByIssuer<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<([u8; 32], u32), T::MaxCertificatesPerIssuer>,
+        ValueQuery
+    >;
+
+    // 代理发行的市场 -> 其代为发行的权证 (asset_id, token_id)，所有者自己发行的不记录
+    #[pallet::storage]
+    #[pallet::getter(fn certificates_by_proxy)]
+    pub type CertificatesByProxy<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<([u8; 32], u32), T::MaxCertificatesPerIssuer>,
+        ValueQuery
+    >;
+
+    // (asset_id, certificate_id) -> 代理发行该权证的市场，删除权证时据此清理 CertificatesByProxy
+    #[pallet::storage]
+    pub type CertificateProxy<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        Blake2_128Concat,
+        [u8; 32],
+        T::AccountId,
+        OptionQuery
+    >;
+
     #[pallet::storage]
     #[pallet::getter(fn asset_approvals)]
-    pub type AssetApprovals<T: Config> = StorageMap<
+    pub type AssetApprovals<T: Config> = StorageDoubleMap<
         _,
         Blake2_128Concat,
         [u8; 32], // asset_id
+        Blake2_128Concat,
         T::AccountId, // authorized operator (market)
+        ApprovalInfo<BlockNumberFor<T>>,
+        OptionQuery
+    >;
+
+    /// Number of authorized operators per asset (bounded by MaxOperatorsPerAsset)
+    #[pallet::storage]
+    #[pallet::getter(fn approval_count)]
+    pub type ApprovalCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        u32,
+        ValueQuery
+    >;
+
+    /// 质押释放队列：到期区块 -> (asset_id, 释放阶段下标)
+    #[pallet::storage]
+    #[pallet::getter(fn release_queue)]
+    pub type ReleaseQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<([u8; 32], u8), T::MaxReleasesPerBlock>,
+        ValueQuery
+    >;
+
+    /// 连续多个区块的释放队列都已满、未能排入的阶段：(asset_id, 释放阶段下标) -> 原到期区块
+    /// process_collateral_releases 每个区块处理其中已到期的，最多 MaxReleasesPerBlock 个
+    #[pallet::storage]
+    #[pallet::getter(fn release_overflow)]
+    pub type ReleaseOverflow<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        ([u8; 32], u8),
+        BlockNumberFor<T>,
+        OptionQuery
+    >;
+
+    /// 每个资产当前的权证数量，发行时递增、删除时递减，用于限制权证子树大小
+    #[pallet::storage]
+    pub type CertificateCounts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        u32,
+        ValueQuery
+    >;
+
+    /// 注册时声明的数据大小（字节），质押金按此计算；治理可通过 report_size_mismatch 更正
+    #[pallet::storage]
+    #[pallet::getter(fn claimed_data_size)]
+    pub type ClaimedDataSize<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        u64,
+        OptionQuery
+    >;
+
+    /// 每个资产被行权（数据下载/访问）的累计次数
+    #[pallet::storage]
+    #[pallet::getter(fn download_count)]
+    pub type DownloadCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        u64,
+        ValueQuery
+    >;
+
+    /// 权证发行时预付的行权费：(付款的发行者, 金额)
+    /// 首次行权时删除（费用已用于行权），未行权就撤销时从行权费池退还给发行者
+    #[pallet::storage]
+    #[pallet::getter(fn issuance_fee_escrow)]
+    pub type IssuanceFeeEscrow<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        Blake2_128Concat,
+        [u8; 32], // certificate_id
+        (T::AccountId, BalanceOf<T>),
         OptionQuery
     >;
 
+    /// 权证剩余可行权次数，由资产所有者设置；没有记录表示不限次数
+    #[pallet::storage]
+    #[pallet::getter(fn usage_quota)]
+    pub type CertificateUsageQuota<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        Blake2_128Concat,
+        [u8; 32], // certificate_id
+        u32,
+        OptionQuery
+    >;
+
+    /// 权证到期队列：时间桶（valid_until / EXPIRY_BUCKET_MILLIS） -> (asset_id, certificate_id)
+    /// 发行或延期时写入，on_initialize 按时间顺序分批处理
+    #[pallet::storage]
+    #[pallet::getter(fn expiry_queue)]
+    pub type ExpiryQueue<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        BoundedVec<([u8; 32], [u8; 32]), T::MaxExpirationsPerBucket>,
+        ValueQuery
+    >;
+
+    /// 下一个待处理的到期时间桶，之前的桶均已处理完；第一次入队时初始化为当前时间桶
+    #[pallet::storage]
+    #[pallet::getter(fn next_expiry_bucket)]
+    pub type NextExpiryBucket<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// 每个资产的权证子树根，权证写入/删除时统一重算
+    #[pallet::storage]
+    pub type CertificateRoots<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        H256,
+        OptionQuery
+    >;
+
+    /// 资产的所有权变更记录（溯源/审计），按时间顺序，超过 MaxLineageDepth 时丢弃最早的记录
+    #[pallet::storage]
+    #[pallet::getter(fn asset_lineage)]
+    pub type AssetLineage<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        BoundedVec<LineageEntry<T::AccountId, BlockNumberFor<T>>, T::MaxLineageDepth>,
+        ValueQuery
+    >;
+
+    /// 资产的长期分成接收者及比例（合计不超过 100%，剩余部分归资产所有者），未设置时全部归所有者
+    #[pallet::storage]
+    #[pallet::getter(fn revenue_shares)]
+    pub type RevenueShares<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        BoundedVec<(T::AccountId, Perbill), T::MaxRevenueShareRecipients>,
+        OptionQuery
+    >;
+
+    /// 原始数据哈希 -> 以该内容注册的资产ID，注册时追加、注销时移除，可用于发现重复内容的注册
+    /// 只保留最早注册的 MaxAssetsPerContentHash 个资产，已满时新资产照常注册但不记入索引
+    #[pallet::storage]
+    pub type AssetIdByContentHash<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        BoundedVec<[u8; 32], T::MaxAssetsPerContentHash>,
+        ValueQuery
+    >;
+
+    /// 资产的转移审批策略，未设置时为 SingleOwner（所有者单独转移）
+    #[pallet::storage]
+    #[pallet::getter(fn transfer_policy_of)]
+    pub type TransferPolicies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        TransferPolicy<T::AccountId>,
+        OptionQuery
+    >;
+
+    /// 门限策略下等待审批的转移，记录接收者和已审批的账户
+    #[pallet::storage]
+    #[pallet::getter(fn pending_transfer)]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        PendingTransfer<T::AccountId>,
+        OptionQuery
+    >;
+
+    /// 门限策略下所有者提出、等待当前审批人同意的策略变更
+    #[pallet::storage]
+    #[pallet::getter(fn pending_policy_change)]
+    pub type PendingPolicyChanges<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        PendingPolicyChange<T::AccountId>,
+        OptionQuery
+    >;
+
+    /// 当前资产数量，注册时加一、注销时减一
+    #[pallet::storage]
+    #[pallet::getter(fn total_assets)]
+    pub type TotalAssets<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 当前权证数量，发行时加一、撤销或删除时减一（已过期但未删除的权证仍计入）
+    #[pallet::storage]
+    #[pallet::getter(fn total_certificates)]
+    pub type TotalCertificates<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// 所有资产质押记录中仍锁定的质押金之和
+    #[pallet::storage]
+    #[pallet::getter(fn total_collateral_locked)]
+    pub type TotalCollateralLocked<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// on_initialize 计算的资产树根，on_finalize 取出后写入区块 digest（只在区块内存在）
+    #[pallet::storage]
+    #[pallet::getter(fn cached_asset_root)]
+    pub type CachedAssetRoot<T: Config> = StorageValue<_, H256, OptionQuery>;
+
+    /// 可以提交可用性证明的账户，即运行链下工作机的签名账户（治理维护）
+    #[pallet::storage]
+    #[pallet::getter(fn is_availability_attester)]
+    pub type AvailabilityAttesters<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
+    /// 资产数据在 IPFS 网关上的最近一次可用性证明，由链下工作机签名提交
+    #[pallet::storage]
+    #[pallet::getter(fn availability_attestation)]
+    pub type AvailabilityAttestations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        AvailabilityAttestation<T::AccountId, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// 账户最近一次注册资产所在的区块，用于注册频率限制
+    #[pallet::storage]
+    #[pallet::getter(fn last_registration)]
+    pub type LastRegistration<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery
+    >;
+
+    /// 不受注册频率限制的账户（治理维护）
+    #[pallet::storage]
+    #[pallet::getter(fn is_registration_exempt)]
+    pub type RegistrationRateLimitExempt<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
+        /// Topics: `event_topic(asset_id)`, `event_topic(owner)`
         AssetRegistered { asset_id: [u8; 32], token_id: u32, owner: T::AccountId, collateral: BalanceOf<T> },
+        /// Topics: `event_topic(asset_id)`, `event_topic(issuer)`, `event_topic(holder)`
         CertificateIssued { asset_id: [u8; 32], certificate_id: [u8; 32], issuer: T::AccountId, holder: T::AccountId },
-        AssetTransferred { asset_id: [u8; 32], from: T::AccountId, to: T::AccountId },
+        /// Topics: `event_topic(asset_id)`, `event_topic(from)`, `event_topic(to)`
+        AssetTransferred { asset_id: [u8; 32], from: T::AccountId, to: T::AccountId, fee: BalanceOf<T> },
         CertificateRevoked { asset_id: [u8; 32], certificate_id: [u8; 32] },
+        /// 批量撤销资产的权证，remaining 为本次之后仍未撤销的数量（大于 0 时需再次调用）
+        AllCertificatesRevoked { asset_id: [u8; 32], owner: T::AccountId, count: u32, remaining: u32 },
         AssetRootUpdated { root: H256 },
         /// Collateral locked for asset
         CollateralLocked { asset_id: [u8; 32], depositor: T::AccountId, amount: BalanceOf<T> },
         /// Collateral released (phase completed)
         CollateralReleased { asset_id: [u8; 32], amount: BalanceOf<T>, phase: u8 },
+        /// Less collateral than scheduled was left (e.g. after a slash); the phase is
+        /// marked released with whatever was still reserved
+        PartialCollateralRelease { asset_id: [u8; 32], scheduled: BalanceOf<T>, released: BalanceOf<T>, phase: u8 },
+        /// Fully released collateral record removed from storage
+        CollateralRecordReclaimed { asset_id: [u8; 32], depositor: T::AccountId },
+        /// Collateral obligation moved to the asset's new owner
+        CollateralTransferred { asset_id: [u8; 32], from: T::AccountId, to: T::AccountId, amount: BalanceOf<T> },
         /// Collateral slashed due to violation
         CollateralSlashed { asset_id: [u8; 32], amount: BalanceOf<T>, percentage: u8 },
+        /// Declared data size was under-reported: the depositor topped up the missing
+        /// collateral, or had the remaining collateral slashed when the top-up failed
+        DataSizeMisreported { asset_id: [u8; 32], claimed_size: u64, actual_size: u64, top_up: BalanceOf<T>, slashed: bool },
+        /// Collateral recomputed under the current parameters: the depositor reserved
+        /// `top_up`, or `scheduled_release` was queued to be released in the next block
+        CollateralRecalculated {
+            asset_id: [u8; 32], previous: BalanceOf<T>, required: BalanceOf<T>, top_up: BalanceOf<T>, scheduled_release: BalanceOf<T> },
+        /// Warning: the depositor's DataCreator collateral in pallet-collaterals was slashed;
+        /// `assets` are (up to `MaxAssetsPerDepositor` of) the assets it still has collateral records for
+        CreatorCollateralSlashed { depositor: T::AccountId, amount: BalanceOf<T>, assets: BoundedVec<[u8; 32], T::MaxAssetsPerDepositor> },
         CollateralOverCappedHint {
             asset_id: [u8; 32], depositor: T::AccountId, total_uncapped: BalanceOf<T>, capped_amount: BalanceOf<T>, max_collateral: BalanceOf<T> },
         /// Asset authorized to a market/operator
-        AssetAuthorized { asset_id: [u8; 32], owner: T::AccountId, operator: T::AccountId },
+        AssetAuthorized { asset_id: [u8; 32], owner: T::AccountId, operator: T::AccountId, valid_until: Option<BlockNumberFor<T>> },
         /// Authorization revoked
-        AuthorizationRevoked { asset_id: [u8; 32], owner: T::AccountId },
+        AuthorizationRevoked { asset_id: [u8; 32], owner: T::AccountId, operator: T::AccountId },
+        /// All authorizations of an asset revoked
+        AllAuthorizationsRevoked { asset_id: [u8; 32], owner: T::AccountId, count: u32 },
+        /// Expired authorization cleared
+        AuthorizationExpiredCleared { asset_id: [u8; 32], operator: T::AccountId },
+        /// Certificate transferred to a new holder
+        CertificateTransferred { asset_id: [u8; 32], certificate_id: [u8; 32], from: T::AccountId, to: T::AccountId },
+        /// 权证被行权一次，remaining 为剩余可行权次数（None 表示不限次数）
+        CertificateExercised { asset_id: [u8; 32], certificate_id: [u8; 32], holder: T::AccountId, remaining: Option<u32> },
+        /// 资产所有者设置或取消了权证的行权次数上限
+        UsageQuotaSet { asset_id: [u8; 32], certificate_id: [u8; 32], quota: Option<u32> },
+        /// 到期扫描将权证标记为 Expired
+        CertificateExpired { asset_id: [u8; 32], certificate_id: [u8; 32] },
+        /// 权证有效期被延长（已过期的权证重新生效）
+        CertificateExtended { asset_id: [u8; 32], certificate_id: [u8; 32], valid_until: u64 },
+        /// 未行权的权证被撤销，预付的行权费退还给发行者
+        IssuanceFeeRefunded { asset_id: [u8; 32], certificate_id: [u8; 32], issuer: T::AccountId, amount: BalanceOf<T> },
+        /// 治理将账户加入或移出注册频率限制的豁免名单
+        RegistrationExemptionSet { who: T::AccountId, exempt: bool },
+        /// Collateral whose asset no longer exists was returned to the depositor by governance
+        OrphanedCollateralReleased { asset_id: [u8; 32], depositor: T::AccountId, amount: BalanceOf<T> },
+        /// 资产所有者设置了长期分成接收者（为空表示全部归所有者）
+        RevenueSharesSet { asset_id: [u8; 32], shares: Vec<(T::AccountId, Perbill)> },
+        /// 市场转移资产时支付的长期分成，amount 为按比例分给各接收者与所有者的总额
+        LongTermSharePaid { asset_id: [u8; 32], payer: T::AccountId, amount: BalanceOf<T> },
+        /// Asset permanently retired by its owner; `collateral_returned` was unreserved to the depositor
+        AssetRetired { asset_id: [u8; 32], owner: T::AccountId, collateral_returned: BalanceOf<T> },
+        /// Account added to or removed from the availability attesters
+        AvailabilityAttesterSet { who: T::AccountId, enabled: bool },
+        /// Gateway check result recorded for an asset; `available = false` keeps the availability phase deferred
+        AvailabilityAttested { asset_id: [u8; 32], attester: T::AccountId, available: bool },
+        /// 资产所有者设置了转移审批策略，之前的审批记录作废
+        TransferPolicySet { asset_id: [u8; 32], policy: TransferPolicy<T::AccountId> },
+        /// 审批人同意将资产转移给 new_owner，approvals 为当前已审批的人数
+        TransferApproved { asset_id: [u8; 32], approver: T::AccountId, new_owner: T::AccountId, approvals: u32 },
+        /// 门限策略下所有者提出策略变更，达到当前门限的审批后生效
+        TransferPolicyChangeProposed { asset_id: [u8; 32], policy: TransferPolicy<T::AccountId> },
+        /// 审批人同意策略变更，approvals 为当前已审批的人数
+        TransferPolicyChangeApproved { asset_id: [u8; 32], approver: T::AccountId, approvals: u32 },
     }
 
     #[pallet::error]
@@ -158,34 +680,109 @@ pub mod pallet {
 
         NotAuthorized,
         AlreadyAuthorized,
+        /// 市场授权已过期
+        AuthorizationExpired,
+        /// 授权尚未过期，不能清理
+        AuthorizationNotExpired,
+        /// 授权的市场数量已达上限
+        TooManyOperators,
+        /// 元证 token_id 已分配完
+        TokenIdOverflow,
+        /// 权证不是有效状态
+        CertificateNotActive,
+        /// 该权证类型必须设置未来的有效期
+        ValidityRequired,
+        /// 批量发行的数量超过上限
+        BatchTooLarge,
+        /// 质押金尚未全部释放，不能回收记录
+        CollateralNotFullyReleased,
+        /// 发行者（或代理市场）的权证索引已满
+        TooManyIssuedCertificates,
+        /// 该资产的权证数量已达上限
+        CertificateLimitReached,
+        /// 有效期不在允许范围内，或不允许发行永久权证
+        InvalidValidityPeriod,
+        /// 资产仍有未撤销的权证，需先调用 revoke_all_certificates
+        OutstandingCertificates,
+        /// 实际大小不大于声明的数据大小
+        SizeNotUnderReported,
+        /// 该权证类型不能行权（只有 Usage/Access 权证可以）
+        RightNotExercisable,
+        /// 权证的行权次数已用完
+        UsageQuotaExhausted,
+        /// 质押释放计划已满，不能再追加释放阶段
+        ReleaseScheduleFull,
+        /// 距离该账户上次注册资产的区块数不足 MinBlocksBetweenRegistrations
+        RegistrationRateLimited,
+        /// 资产仍然存在，质押记录不是孤儿记录
+        CollateralNotOrphaned,
+        /// 长期分成比例合计超过 100%，或包含零比例、重复的接收者
+        InvalidRevenueShares,
+        /// 长期分成接收者数量超过 MaxRevenueShareRecipients
+        TooManyRevenueShareRecipients,
+        /// 市场转移的接收者是零账户或不存在的账户
+        InvalidRecipient,
+        /// 资产仍授权给市场，需先撤销授权
+        AssetStillApproved,
+        /// 调用者不在可用性证明提交者名单中
+        NotAvailabilityAttester,
+        /// 转移审批人数未达到策略要求的门限，或审批的接收者不是本次转移的接收者
+        ThresholdNotMet,
+        /// 门限为零或超过审批人数，或审批人重复
+        InvalidTransferPolicy,
+        /// 调用者不是该资产转移策略中的审批人
+        NotTransferApprover,
+        /// 该审批人已经审批过本次转移
+        AlreadyApprovedTransfer,
+        /// 没有等待执行的转移
+        NoPendingTransfer,
+        /// 已有等待审批的转移，且接收者不同
+        TransferTargetMismatch,
+        /// 没有等待审批的策略变更
+        NoPendingPolicyChange,
+        /// 审批的策略与等待审批的策略变更不一致
+        PolicyChangeMismatch,
     }
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        // fn on_initialize(n: BlockNumberFor<T>) -> Weight {
-        //     // Process collateral releases
-        //     let release_weight = Self::process_collateral_releases(n);
-            
-        //     release_weight
-        // }
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            // 只处理本区块到期的质押释放阶段
+            let mut weight = Self::process_collateral_releases(n)
+                .saturating_add(Self::process_certificate_expirations());
+
+            // 全资产树的根计算开销与资产数量成正比，放在这里计入区块权重
+            if T::PublishAssetRootDigest::get() {
+                weight = weight.saturating_add(Self::cache_asset_root());
+            }
+            weight
+        }
         
         fn on_finalize(_n: BlockNumberFor<T>) {
-            // 计算asset root,这是全资产状态树计算
-            // let root = Self::compute_asset_root();
-            
-            // 创建digest item并添加到区块头的digest中
-            // let digest_item = crate::digest_item::create_asset_root_digest(root);
-            // frame_system::Pallet::<T>::deposit_log(digest_item);
-            
-            // 事件
-            // Self::deposit_event(Event::AssetRootUpdated { root });
+            // 只读取 on_initialize 缓存的根写入 digest，不在这里做未计量的根计算
+            if let Some(root) = CachedAssetRoot::<T>::take() {
+                let digest_item = crate::digest_item::create_asset_root_digest(root);
+                frame_system::Pallet::<T>::deposit_log(digest_item);
+            }
+        }
+
+        /// 在可用性释放阶段到期前检查 IPFS 网关并提交证明
+        fn offchain_worker(n: BlockNumberFor<T>) {
+            Self::run_availability_checks(n);
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state_collateral()
         }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
+        /// 注册资产。质押金先于资产写入锁定，后续任一步骤失败都会整体回滚（包括 reserve）
         #[pallet::call_index(0)]
         #[pallet::weight(<T as Config>::WeightInfo::register_asset())]
+        #[transactional]
         pub fn register_asset(
             origin: OriginFor<T>,
             name: Vec<u8>,
@@ -203,22 +800,17 @@ pub mod pallet {
                 description.len() <= T::MaxDescriptionLength::get() as usize,
                 Error::<T>::DescriptionTooLong
             );
+            Self::ensure_registration_allowed(&who)?;
             
             let timestamp = Self::current_timestamp();
             let asset_id = DataAsset::generate_asset_id(&who, timestamp, &raw_data_hash);
             // Check if asset already exists
             ensure!(Self::get_asset(&asset_id).is_none(), Error::<T>::InvalidInput);
             // Get collateral amount for event
-            let (collateral_amount, is_over_capped) = Self::calculate_collateral(data_size_bytes);
+            let (collateral_amount, is_over_capped, total_uncapped) = Self::calculate_collateral(data_size_bytes);
             if is_over_capped {
                 // 获取上限值，用于事件中展示“原计算值vs上限值”
                 let max_collateral = T::MaxCollateral::get();
-                // 重新计算“未封顶的原始金额”（用于提示用户“原本需要多少，实际锁定多少”）
-                let data_size_mb = ((data_size_bytes as u128) / (1024 * 1024)).max(1);
-                let variable_collateral = T::CollateralPerMB::get()
-                    .saturating_mul(data_size_mb.saturated_into());
-                let total_uncapped = T::BaseCollateral::get()
-                    .saturating_add(variable_collateral);
                 
                 // 发射超限提示事件
                 Self::deposit_event(Event::CollateralOverCappedHint {
@@ -231,7 +823,8 @@ pub mod pallet {
             }
             // Lock collateral BEFORE creating asset
             Self::lock_collateral(&asset_id, &who, collateral_amount)?;
-            let token_id = Self::get_and_increment_token_id();
+            ClaimedDataSize::<T>::insert(&asset_id, data_size_bytes);
+            let token_id = Self::get_and_increment_token_id()?;
             
             // 使用 minimal 构造函数
             let mut asset = DataAsset::minimal(who.clone(), name, description, raw_data_hash, timestamp,);
@@ -240,16 +833,24 @@ pub mod pallet {
             
             Self::insert_asset(&asset_id, &asset)?;
             Self::set_token_mapping(token_id, asset_id);
+            // 索引只用于查询，已满时不影响注册
+            let _ = AssetIdByContentHash::<T>::try_append(raw_data_hash, asset_id);
+            TotalAssets::<T>::mutate(|total| *total = total.saturating_add(1));
+            LastRegistration::<T>::insert(&who, frame_system::Pallet::<T>::block_number());
             // 一个元证一棵子树真实情况下可能有性能问题
             // 之后改成一棵子树存元证一棵子树存权证
             // Self::initialize_certificate_trie(&asset_id);
             
             // 首次创建奖励发放(捕捉错误，不阻断业务)
-            if let Err(_) = T::IncentiveHandler::distribute_first_create_reward(&who, &asset_id) {
-                log::error!("首次创建奖励发放失败：asset_id={:?}", asset_id);
+            if let Err(e) = T::IncentiveHandler::distribute_first_create_reward(&who, &asset_id) {
+                log::error!("首次创建奖励发放失败：asset_id={:?}, error={:?}", asset_id, e);
             }
 
-            Self::deposit_event(Event::AssetRegistered { asset_id, token_id, owner: who, collateral: collateral_amount });
+            let topics = [Self::event_topic(&asset_id), Self::event_topic(&who)];
+            Self::deposit_indexed_event(
+                Event::AssetRegistered { asset_id, token_id, owner: who, collateral: collateral_amount },
+                &topics,
+            );
             Ok(())
         }
 
@@ -268,36 +869,23 @@ pub mod pallet {
             let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
 
             let is_owner = asset.owner == who;
-            let is_approved = Self::asset_approvals(&asset_id).map_or(false, |operator| operator == who);
+            let is_approved = Self::is_approved_operator(&asset_id, &who);
             
             ensure!(is_owner || is_approved, Error::<T>::NotAuthorized);
             ensure!(asset.is_active(), Error::<T>::AssetNotActive);
             
-            // 转换 u8 到 RightType
-            let right_type_enum = match right_type {
-                1 => RightType::Usage,
-                2 => RightType::Access,
-                _ => return Err(Error::<T>::InvalidRightType.into()),
-            };
-            
-            let token_id = Self::get_next_certificate_id(&asset_id);
             let current_time = Self::current_timestamp();
-            
-            // 使用 minimal 构造函数，没有修改issuer，市场只是代理
-            let mut certificate = RightToken::minimal(
-                token_id,
-                right_type_enum,
-                holder.clone(), // 权证的购买者
-                asset.owner.clone(), // 元证持有者作为权证的发行者
-                asset_id,
-                current_time,
-                valid_until
-            );
-            // certificate.token_id = RightToken::generate_token_id(asset.token_id, certificate_id);
+            let certificate = Self::build_certificate(&asset_id, &asset, is_owner, holder.clone(), right_type, valid_until, current_time)?;
+            Self::index_issued_certificate(&asset_id, &certificate, (!is_owner).then_some(&who))?;
+            Self::charge_issuance_fee(&asset_id, &certificate.certificate_id, &who)?;
 
             Self::insert_certificate(&asset_id, &certificate)?;
+            Self::enqueue_expiry(&asset_id, &certificate);
+            if !is_owner {
+                Self::reward_issuance_operator(&who);
+            }
             
-            Self::deposit_event(Event::CertificateIssued { asset_id, certificate_id: certificate.certificate_id, issuer: asset.owner.clone(), holder });
+            Self::deposit_certificate_issued(asset_id, certificate.certificate_id, asset.owner.clone(), holder);
             Ok(())
         }
 
@@ -309,25 +897,7 @@ pub mod pallet {
             new_owner: T::AccountId,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            
-            let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
-            ensure!(asset.owner == who, Error::<T>::NotOwner); // 在未被授权的时候，只有资产所有者才能转移资产
-            ensure!(!asset.is_locked(), Error::<T>::AssetLocked); // 锁定的资产不能转移
-            
-            let old_owner = asset.owner.clone();
-            asset.owner = new_owner.clone();
-            asset.nonce += 1;
-            asset.transaction_count += 1;
-            asset.updated_at = Self::current_timestamp();
-            // ！！！！没有修改资产状态
-            Self::insert_asset(&asset_id, &asset)?;
-            // 如果所有者自己转移资产，清除该资产上所有未完成的市场授权。确保授权记录不会残留。
-            // 但是这样会导致市场方无法继续操作资产，必须重新授权。
-            AssetApprovals::<T>::remove(asset_id);
-
-            T::IncentiveHandler::register_asset_trade(&asset_id);
-            Self::deposit_event(Event::AssetTransferred { asset_id, from: old_owner, to: new_owner });
-            Ok(())
+            Self::do_owner_transfer(&who, &asset_id, new_owner)
         }
 
         #[pallet::call_index(3)]
@@ -345,6 +915,7 @@ pub mod pallet {
             
             ensure!(asset.owner == who || cert.owner == who, Error::<T>::NotOwner);
             
+            Self::refund_issuance_fee(&asset_id, &certificate_id)?;
             Self::remove_certificate(&asset_id, &certificate_id)?;
             
             Self::deposit_event(Event::CertificateRevoked { asset_id, certificate_id });
@@ -355,7 +926,7 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::lock_asset())]
         pub fn lock_asset(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            // let caller = Self::account_to_h160(&who);
+            // let caller = pallet_shared_traits::account_to_h160(&who);
             
             let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.owner == who, Error::<T>::NotOwner);
@@ -372,7 +943,7 @@ pub mod pallet {
         #[pallet::weight(<T as Config>::WeightInfo::unlock_asset())]
         pub fn unlock_asset(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
             let who = ensure_signed(origin)?;
-            // let caller = Self::account_to_h160(&who);
+            // let caller = pallet_shared_traits::account_to_h160(&who);
             
             let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.owner == who, Error::<T>::NotOwner);
@@ -397,29 +968,45 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 授权资产给市场账户（或其他账户）
+        /// 授权资产给市场账户（或其他账户），同一资产可以同时授权给多个市场
         #[pallet::call_index(7)] // 索引号递增，不重复
         #[pallet::weight(<T as Config>::WeightInfo::authorize_operator())]
         pub fn authorize_market(
             origin: OriginFor<T>,
             asset_id: [u8; 32],
             market_account: T::AccountId,
+            valid_until: Option<BlockNumberFor<T>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
+            let current_block = frame_system::Pallet::<T>::block_number();
+
+            // 过期区块必须在当前区块之后
+            if let Some(until) = valid_until {
+                ensure!(until > current_block, Error::<T>::InvalidInput);
+            }
             
             // 验证资产存在且属于调用者
             let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.owner == who, Error::<T>::NotOwner);
             ensure!(!asset.is_locked(), Error::<T>::AssetLocked); // 锁定资产不允许改变授权状态
-            ensure!(!asset.is_approved(), Error::<T>::AlreadyAuthorized); // 已被授权的资产不能再次授权
-            
-            // 防止重复授权给同一账户
-            if let Some(current_operator) = Self::asset_approvals(&asset_id) {
-                ensure!(current_operator != market_account, Error::<T>::AlreadyAuthorized);
+
+            // 防止重复授权给同一账户；已过期的旧授权直接被覆盖，不占用新的名额
+            match Self::asset_approvals(&asset_id, &market_account) {
+                Some(approval) => {
+                    ensure!(approval.is_expired(current_block), Error::<T>::AlreadyAuthorized);
+                }
+                None => {
+                    let count = Self::approval_count(&asset_id);
+                    ensure!(count < T::MaxOperatorsPerAsset::get(), Error::<T>::TooManyOperators);
+                    ApprovalCount::<T>::insert(&asset_id, count.saturating_add(1));
+                }
             }
 
             // 存储授权信息
-            AssetApprovals::<T>::insert(&asset_id, &market_account);
+            AssetApprovals::<T>::insert(&asset_id, &market_account, ApprovalInfo {
+                approved_at: current_block,
+                valid_until,
+            });
             
             // 修改资产状态
             asset.status = AssetStatus::Approved;
@@ -430,38 +1017,44 @@ pub mod pallet {
             Self::deposit_event(Event::AssetAuthorized { 
                 asset_id, 
                 owner: who, 
-                operator: market_account 
+                operator: market_account,
+                valid_until,
             });
             
             Ok(())
         }
 
-        /// 撤销对市场的授权
+        /// 撤销对某个市场的授权，最后一个授权被撤销后资产回到私有状态
         #[pallet::call_index(8)]
         #[pallet::weight(<T as Config>::WeightInfo::revoke_authorization())]
         pub fn revoke_authorization(
             origin: OriginFor<T>,
             asset_id: [u8; 32],
-        ) -> DispatchResult {
+            market_account: T::AccountId,
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
             
             let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             ensure!(asset.owner == who, Error::<T>::NotOwner);
             
-            if AssetApprovals::<T>::contains_key(&asset_id) {
-                AssetApprovals::<T>::remove(&asset_id);
+            // 该市场本就没有授权时不产生任何状态变更，退还手续费
+            if !Self::remove_approval(&asset_id, &market_account) {
+                return Ok(Pays::No.into());
+            }
 
+            if Self::approval_count(&asset_id) == 0 {
                 asset.status = AssetStatus::Private;
                 asset.updated_at = Self::current_timestamp(); // 同步更新时间戳
                 Self::insert_asset(&asset_id, &asset)?; // 保存修改后的资产
-
-                Self::deposit_event(Event::AuthorizationRevoked { 
-                    asset_id, 
-                    owner: who 
-                });
             }
+
+            Self::deposit_event(Event::AuthorizationRevoked { 
+                asset_id, 
+                owner: who,
+                operator: market_account,
+            });
             
-            Ok(())
+            Ok(().into())
         }
 
         /// 市场账户（被授权方）转移资产，还有一个transfer_by_market_internal供链扩展调用
@@ -477,12 +1070,16 @@ pub mod pallet {
             // 1. 获取资产
             let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
             
-            // 2. 验证是否被授权
-            let approved_account = Self::asset_approvals(&asset_id).ok_or(Error::<T>::NotAuthorized)?;
-            ensure!(approved_account == market, Error::<T>::NotAuthorized);
+            // 2. 验证是否被授权且授权未过期
+            Self::ensure_approved_operator(&asset_id, &market)?;
+            Self::ensure_valid_recipient(&new_owner)?;
             
-            // 3. 检查资产状态
+            // 3. 检查资产状态；门限策略下市场转移同样需要审批人对该接收者的审批
             ensure!(!asset.is_locked(), Error::<T>::AssetLocked);
+            Self::ensure_transfer_threshold(&asset_id, &asset, &new_owner)?;
+            let fee = Self::charge_transfer_fee(&market, &asset)?;
+            Self::pay_long_term_share(&market, &asset_id, &asset)?;
+            Self::transfer_collateral(&asset_id, &new_owner)?;
             
             let old_owner = asset.owner.clone();
             
@@ -496,23 +1093,718 @@ pub mod pallet {
             // 5. 更新资产树
             Self::insert_asset(&asset_id, &asset)?;
             
-            // 6. 转移后通常清除授权（ERC721标准行为，防止前任市场继续控制）
-            AssetApprovals::<T>::remove(&asset_id);
+            // 6. 转移后清除所有市场的授权（ERC721标准行为，防止前任市场继续控制）
+            Self::clear_approvals(&asset_id);
+            Self::clear_transfer_policy(&asset_id);
             
             T::IncentiveHandler::register_asset_trade(&asset_id);
 
             // 7. 发出事件
-            Self::deposit_event(Event::AssetTransferred { 
-                asset_id, 
-                from: old_owner, 
-                to: new_owner 
-            });
+            Self::record_transfer_lineage(&asset_id, &old_owner, &new_owner);
+            Self::deposit_asset_transferred(asset_id, old_owner, new_owner, fee);
             
             Ok(())
         }
+
+        /// 清理已过期的市场授权（任何人都可以调用），没有剩余授权时资产回到私有状态
+        #[pallet::call_index(10)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_authorization())]
+        pub fn clear_expired_authorization(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            market_account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            let approval = Self::asset_approvals(&asset_id, &market_account)
+                .ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(
+                approval.is_expired(frame_system::Pallet::<T>::block_number()),
+                Error::<T>::AuthorizationNotExpired
+            );
+
+            Self::remove_approval(&asset_id, &market_account);
+            if asset.is_approved() && Self::approval_count(&asset_id) == 0 {
+                asset.status = AssetStatus::Private;
+                asset.updated_at = Self::current_timestamp();
+                Self::insert_asset(&asset_id, &asset)?;
+            }
+
+            Self::deposit_event(Event::AuthorizationExpiredCleared {
+                asset_id,
+                operator: market_account,
+            });
+            Ok(())
+        }
+
+        /// 撤销资产上所有市场的授权，资产回到私有状态
+        #[pallet::call_index(11)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_all_authorizations(T::MaxOperatorsPerAsset::get()))]
+        pub fn revoke_all_authorizations(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+
+            let count = Self::clear_approvals(&asset_id);
+            if asset.is_approved() {
+                asset.status = AssetStatus::Private;
+                asset.updated_at = Self::current_timestamp();
+                Self::insert_asset(&asset_id, &asset)?;
+            }
+
+            Self::deposit_event(Event::AllAuthorizationsRevoked { asset_id, owner: who, count });
+            Ok(())
+        }
+
+        /// 权证持有者将未过期的权证转让给他人
+        #[pallet::call_index(12)]
+        #[pallet::weight(<T as Config>::WeightInfo::transfer_certificate())]
+        pub fn transfer_certificate(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            certificate_id: [u8; 32],
+            new_holder: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut cert = Self::get_certificate(&asset_id, &certificate_id)
+                .ok_or(Error::<T>::CertificateNotFound)?;
+            ensure!(cert.owner == who, Error::<T>::NotOwner);
+            ensure!(cert.is_valid(Self::current_timestamp()), Error::<T>::CertificateNotActive);
+
+            cert.owner = new_holder.clone();
+            cert.nonce = cert.nonce.saturating_add(1);
+            Self::insert_certificate(&asset_id, &cert)?;
+
+            Self::deposit_event(Event::CertificateTransferred { asset_id, certificate_id, from: who, to: new_holder });
+            Ok(())
+        }
+
+        /// 手动回收已全部释放的质押记录（正常情况下在释放扫描中自动回收），任何人都可以调用
+        #[pallet::call_index(13)]
+        #[pallet::weight(<T as Config>::WeightInfo::reclaim_collateral_record())]
+        pub fn reclaim_collateral_record(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let info = AssetCollateral::<T>::get(&asset_id).ok_or(Error::<T>::CollateralNotFound)?;
+            ensure!(Self::is_collateral_finished(&info), Error::<T>::CollateralNotFullyReleased);
+
+            Self::remove_collateral_record(&asset_id, &info.depositor);
+            Ok(())
+        }
+        /// 批量发行权证：只做一次授权检查，全部写入后只重新计算一次权证根
+        /// 任意一个权证发行失败时整批回滚
+        #[pallet::call_index(14)]
+        #[pallet::weight(<T as Config>::WeightInfo::issue_certificates_batch(
+            (certificates.len() as u32).min(T::MaxBatchSize::get())
+        ))]
+        #[transactional]
+        pub fn issue_certificates_batch(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            certificates: Vec<(T::AccountId, u8, Option<u64>)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(!certificates.is_empty(), Error::<T>::InvalidInput);
+            ensure!(certificates.len() as u32 <= T::MaxBatchSize::get(), Error::<T>::BatchTooLarge);
+
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            let is_owner = asset.owner == who;
+            ensure!(is_owner || Self::is_approved_operator(&asset_id, &who), Error::<T>::NotAuthorized);
+            ensure!(asset.is_active(), Error::<T>::AssetNotActive);
+
+            let current_time = Self::current_timestamp();
+            for (holder, right_type, valid_until) in certificates {
+                let certificate = Self::build_certificate(&asset_id, &asset, is_owner, holder.clone(), right_type, valid_until, current_time)?;
+                Self::index_issued_certificate(&asset_id, &certificate, (!is_owner).then_some(&who))?;
+                Self::charge_issuance_fee(&asset_id, &certificate.certificate_id, &who)?;
+                Self::put_certificate(&asset_id, &certificate);
+                Self::enqueue_expiry(&asset_id, &certificate);
+                if !is_owner {
+                    Self::reward_issuance_operator(&who);
+                }
+                Self::deposit_certificate_issued(asset_id, certificate.certificate_id, asset.owner.clone(), holder);
+            }
+
+            Self::update_certificate_root(&asset_id);
+            Ok(())
+        }
+
+        /// 治理上报资产实际数据大小大于注册时声明的大小（仅限 sudo/governance）
+        /// 按实际大小重新计算质押金，由质押人补足差额；补足失败时罚没剩余全部质押金
+        #[pallet::call_index(16)]
+        #[pallet::weight(<T as Config>::WeightInfo::report_size_mismatch())]
+        pub fn report_size_mismatch(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            actual_size_bytes: u64,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let claimed_size = ClaimedDataSize::<T>::get(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(actual_size_bytes > claimed_size, Error::<T>::SizeNotUnderReported);
+
+            let (top_up, slashed) = Self::recollateralize(&asset_id, actual_size_bytes)?;
+            ClaimedDataSize::<T>::insert(&asset_id, actual_size_bytes);
+
+            Self::deposit_event(Event::DataSizeMisreported {
+                asset_id,
+                claimed_size,
+                actual_size: actual_size_bytes,
+                top_up,
+                slashed,
+            });
+            Ok(())
+        }
+
+        /// 撤销资产的所有权证（通常在转移资产前调用），仅限资产所有者
+        /// 每次最多撤销 MaxBatchSize 个，全部删除后只重新计算一次权证根；还有剩余时需再次调用
+        #[pallet::call_index(15)]
+        #[pallet::weight(<T as Config>::WeightInfo::revoke_all_certificates(T::MaxBatchSize::get()))]
+        pub fn revoke_all_certificates(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+
+            let certificate_ids = Self::certificate_page(&asset_id, T::MaxBatchSize::get());
+            let child_info = Self::certificate_trie_info();
+            for certificate_id in certificate_ids.iter() {
+                Self::refund_issuance_fee(&asset_id, certificate_id)?;
+                if let Some(cert) = Self::get_certificate(&asset_id, certificate_id) {
+                    Self::forget_certificate(&asset_id, &cert);
+                }
+                let mut storage_key = asset_id.to_vec();
+                storage_key.extend_from_slice(certificate_id);
+                child::kill(&child_info, &storage_key);
+                Self::deposit_event(Event::CertificateRevoked { asset_id, certificate_id: *certificate_id });
+            }
+
+            let count = certificate_ids.len() as u32;
+            for certificate_id in certificate_ids.iter() {
+                CertificateUsageQuota::<T>::remove(&asset_id, certificate_id);
+            }
+            let remaining = CertificateCounts::<T>::mutate_exists(&asset_id, |stored| {
+                *stored = stored.map(|c| c.saturating_sub(count)).filter(|c| *c > 0);
+                stored.unwrap_or(0)
+            });
+            TotalCertificates::<T>::mutate(|total| *total = total.saturating_sub(count.into()));
+            if count > 0 {
+                Self::update_certificate_root(&asset_id);
+            }
+
+            Self::deposit_event(Event::AllCertificatesRevoked { asset_id, owner: who, count, remaining });
+            Ok(Some(<T as Config>::WeightInfo::revoke_all_certificates(count)).into())
+        }
+
+        /// 权证持有者行使 Usage/Access 权证，记录一次数据使用：资产下载次数和权证 nonce 加一
+        /// 设置了行权次数上限的权证每次消耗一次额度，用完后不能再行权
+        #[pallet::call_index(17)]
+        #[pallet::weight(<T as Config>::WeightInfo::exercise_certificate())]
+        pub fn exercise_certificate(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            certificate_id: [u8; 32],
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let mut cert = Self::get_certificate(&asset_id, &certificate_id)
+                .ok_or(Error::<T>::CertificateNotFound)?;
+            ensure!(cert.owner == who, Error::<T>::NotOwner);
+            ensure!(cert.is_valid(Self::current_timestamp()), Error::<T>::CertificateNotActive);
+            ensure!(cert.right_type.exercisable(), Error::<T>::RightNotExercisable);
+
+            let remaining = CertificateUsageQuota::<T>::try_mutate(
+                &asset_id,
+                &certificate_id,
+                |quota| -> Result<Option<u32>, DispatchError> {
+                    match quota {
+                        Some(left) => {
+                            ensure!(*left > 0, Error::<T>::UsageQuotaExhausted);
+                            *left -= 1;
+                            Ok(Some(*left))
+                        }
+                        None => Ok(None),
+                    }
+                },
+            )?;
+
+            DownloadCount::<T>::mutate(&asset_id, |count| *count = count.saturating_add(1));
+            // 预付的行权费已用于本次行权，之后撤销不再退还
+            IssuanceFeeEscrow::<T>::remove(&asset_id, &certificate_id);
+            cert.nonce = cert.nonce.saturating_add(1);
+            Self::insert_certificate(&asset_id, &cert)?;
+
+            Self::deposit_event(Event::CertificateExercised { asset_id, certificate_id, holder: who, remaining });
+            Ok(())
+        }
+
+        /// 资产所有者设置权证的剩余行权次数，quota 为 None 时取消限制
+        #[pallet::call_index(18)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_usage_quota())]
+        pub fn set_usage_quota(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            certificate_id: [u8; 32],
+            quota: Option<u32>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+            let cert = Self::get_certificate(&asset_id, &certificate_id)
+                .ok_or(Error::<T>::CertificateNotFound)?;
+            ensure!(cert.right_type.exercisable(), Error::<T>::RightNotExercisable);
+
+            CertificateUsageQuota::<T>::set(&asset_id, &certificate_id, quota);
+
+            Self::deposit_event(Event::UsageQuotaSet { asset_id, certificate_id, quota });
+            Ok(())
+        }
+
+        /// 延长权证有效期，资产所有者或被授权的市场可以调用；已过期的权证重新生效，保留原权证ID与历史
+        /// 新的有效期同样必须落在 [now + MinCertificateValidity, now + MaxCertificateValidity] 内
+        #[pallet::call_index(19)]
+        #[pallet::weight(<T as Config>::WeightInfo::extend_certificate())]
+        pub fn extend_certificate(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            certificate_id: [u8; 32],
+            new_valid_until: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+
+            let is_owner = asset.owner == who;
+            ensure!(is_owner || Self::is_approved_operator(&asset_id, &who), Error::<T>::NotAuthorized);
+            ensure!(asset.is_active(), Error::<T>::AssetNotActive);
+
+            let mut cert = Self::get_certificate(&asset_id, &certificate_id)
+                .ok_or(Error::<T>::CertificateNotFound)?;
+            // 只能由所有者发行的权证类型，也只能由所有者延期
+            ensure!(is_owner || !cert.right_type.owner_only(), Error::<T>::NotAuthorized);
+
+            let current_time = Self::current_timestamp();
+            ensure!(
+                new_valid_until >= current_time.saturating_add(T::MinCertificateValidity::get())
+                    && new_valid_until <= current_time.saturating_add(T::MaxCertificateValidity::get()),
+                Error::<T>::InvalidValidityPeriod
+            );
+
+            // 过期权证已移出发行者索引，重新生效时按原代理市场记回
+            if cert.status == CertificateStatus::Expired {
+                let proxy = CertificateProxy::<T>::get(&asset_id, certificate_id);
+                Self::index_issued_certificate(&asset_id, &cert, proxy.as_ref())?;
+            }
+            cert.valid_until = Some(new_valid_until);
+            cert.status = CertificateStatus::Active;
+            cert.nonce = cert.nonce.saturating_add(1);
+            Self::insert_certificate(&asset_id, &cert)?;
+            // 旧的到期记录在扫描时会因权证未过期而被跳过
+            Self::enqueue_expiry(&asset_id, &cert);
+
+            Self::deposit_event(Event::CertificateExtended { asset_id, certificate_id, valid_until: new_valid_until });
+            Ok(())
+        }
+
+        /// 治理调整 CollateralPerMB/MaxCollateral 等参数后，按当前参数重新计算某个资产的质押金（仅限 sudo/governance）
+        /// 要求更高时由质押人补足差额，余额不足则失败；要求更低时多出的部分在下一个区块释放
+        #[pallet::call_index(20)]
+        #[pallet::weight(<T as Config>::WeightInfo::recalculate_collateral())]
+        pub fn recalculate_collateral(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let (previous, required, top_up, scheduled_release) = Self::recalculate_asset_collateral(&asset_id)?;
+
+            Self::deposit_event(Event::CollateralRecalculated {
+                asset_id,
+                previous,
+                required,
+                top_up,
+                scheduled_release,
+            });
+            Ok(())
+        }
+
+        /// 将账户加入或移出注册频率限制的豁免名单（仅限 sudo/governance）
+        #[pallet::call_index(21)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_registration_exempt())]
+        pub fn set_registration_exempt(origin: OriginFor<T>, who: T::AccountId, exempt: bool) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if exempt {
+                RegistrationRateLimitExempt::<T>::insert(&who, true);
+            } else {
+                RegistrationRateLimitExempt::<T>::remove(&who);
+            }
+
+            Self::deposit_event(Event::RegistrationExemptionSet { who, exempt });
+            Ok(())
+        }
+
+        /// 将孤儿质押（资产已不存在但仍锁定的质押金）退还给质押人并删除记录（仅限 sudo/governance）
+        /// 孤儿记录可通过 find_orphaned_collateral 查询
+        #[pallet::call_index(22)]
+        #[pallet::weight(<T as Config>::WeightInfo::release_orphaned_collateral())]
+        pub fn release_orphaned_collateral(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let (depositor, amount) = Self::release_orphaned_collateral_record(&asset_id)?;
+
+            Self::deposit_event(Event::OrphanedCollateralReleased { asset_id, depositor, amount });
+            Ok(())
+        }
+
+        /// 设置资产的长期分成接收者，仅限资产所有者；传入空列表时清除，长期分成全部归所有者
+        #[pallet::call_index(23)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_revenue_shares(
+            (shares.len() as u32).min(T::MaxRevenueShareRecipients::get())
+        ))]
+        pub fn set_revenue_shares(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            shares: Vec<(T::AccountId, Perbill)>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+
+            if shares.is_empty() {
+                RevenueShares::<T>::remove(&asset_id);
+            } else {
+                let bounded: BoundedVec<_, T::MaxRevenueShareRecipients> = shares
+                    .clone()
+                    .try_into()
+                    .map_err(|_| Error::<T>::TooManyRevenueShareRecipients)?;
+                Self::ensure_valid_revenue_shares(&bounded)?;
+                RevenueShares::<T>::insert(&asset_id, bounded);
+            }
+
+            Self::deposit_event(Event::RevenueSharesSet { asset_id, shares });
+            Ok(())
+        }
+
+        /// 永久注销资产，仅限资产所有者。资产不能处于锁定或授权状态，且不能有未撤销的权证
+        /// 剩余的质押金不再按释放计划分阶段释放，全部退还给质押人；资产及其索引一并删除
+        #[pallet::call_index(24)]
+        #[pallet::weight(<T as Config>::WeightInfo::retire_asset())]
+        pub fn retire_asset(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+            ensure!(!asset.is_locked(), Error::<T>::AssetLocked);
+            ensure!(!Self::is_asset_approved(&asset_id, &asset), Error::<T>::AssetStillApproved);
+            ensure!(CertificateCounts::<T>::get(&asset_id) == 0, Error::<T>::OutstandingCertificates);
+
+            let collateral_returned = Self::settle_collateral_record(&asset_id)
+                .map_or_else(Zero::zero, |(_, released)| released);
+            Self::remove_asset(&asset_id, asset.token_id);
+
+            Self::deposit_event(Event::AssetRetired { asset_id, owner: who, collateral_returned });
+            Ok(())
+        }
+
+        /// 将账户加入或移出可用性证明提交者名单（仅限 sudo/governance）
+        #[pallet::call_index(25)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_availability_attester())]
+        pub fn set_availability_attester(origin: OriginFor<T>, who: T::AccountId, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
+
+            if enabled {
+                AvailabilityAttesters::<T>::insert(&who, true);
+            } else {
+                AvailabilityAttesters::<T>::remove(&who);
+            }
+
+            Self::deposit_event(Event::AvailabilityAttesterSet { who, enabled });
+            Ok(())
+        }
+
+        /// 记录资产数据在 IPFS 网关上的可用性，仅限 AvailabilityAttesters（由链下工作机签名提交）
+        /// 只保留最近一次结果；证明被接受时不收手续费
+        #[pallet::call_index(26)]
+        #[pallet::weight(<T as Config>::WeightInfo::submit_availability_attestation())]
+        pub fn submit_availability_attestation(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            available: bool,
+        ) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::is_availability_attester(&who), Error::<T>::NotAvailabilityAttester);
+            ensure!(Self::get_asset(&asset_id).is_some(), Error::<T>::AssetNotFound);
+
+            AvailabilityAttestations::<T>::insert(
+                asset_id,
+                AvailabilityAttestation {
+                    available,
+                    attester: who.clone(),
+                    block: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+
+            Self::deposit_event(Event::AvailabilityAttested { asset_id, attester: who, available });
+            Ok(Pays::No.into())
+        }
+
+        /// 设置资产的转移审批策略，仅限资产所有者；SingleOwner 恢复为所有者单独转移
+        /// 门限策略下 transfer_asset 需要 threshold 个审批人先对同一接收者调用 approve_transfer
+        /// 当前已是门限策略时只记录为待审批的变更（覆盖之前未通过的提议），
+        /// 需要当前策略的 threshold 个审批人调用 approve_policy_change 后才生效
+        #[pallet::call_index(27)]
+        #[pallet::weight(<T as Config>::WeightInfo::set_transfer_policy())]
+        pub fn set_transfer_policy(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            policy: TransferPolicy<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == who, Error::<T>::NotOwner);
+
+            if let TransferPolicy::Threshold { approvers, threshold } = &policy {
+                ensure!(
+                    *threshold > 0 && *threshold as usize <= approvers.len(),
+                    Error::<T>::InvalidTransferPolicy
+                );
+                let mut seen = Vec::with_capacity(approvers.len());
+                for approver in approvers.iter() {
+                    ensure!(!seen.contains(&approver), Error::<T>::InvalidTransferPolicy);
+                    seen.push(approver);
+                }
+            }
+
+            if TransferPolicies::<T>::contains_key(&asset_id) {
+                PendingPolicyChanges::<T>::insert(
+                    &asset_id,
+                    PendingPolicyChange { policy: policy.clone(), approvals: BoundedVec::new() },
+                );
+                Self::deposit_event(Event::TransferPolicyChangeProposed { asset_id, policy });
+            } else {
+                Self::apply_transfer_policy(asset_id, policy);
+            }
+            Ok(())
+        }
+
+        /// 审批人同意将资产转移给 new_owner；同一时间只能有一个接收者在等待审批
+        #[pallet::call_index(28)]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_transfer())]
+        pub fn approve_transfer(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            new_owner: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let asset = Self::get_asset(&asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            let Some(TransferPolicy::Threshold { approvers, .. }) = TransferPolicies::<T>::get(&asset_id) else {
+                return Err(Error::<T>::NotTransferApprover.into());
+            };
+            ensure!(approvers.contains(&who), Error::<T>::NotTransferApprover);
+
+            // 资产在审批期间被转移过（nonce 变化）时，之前的审批作废
+            let mut pending = PendingTransfers::<T>::get(&asset_id)
+                .filter(|pending| pending.asset_nonce == asset.nonce)
+                .unwrap_or_else(|| PendingTransfer {
+                    new_owner: new_owner.clone(),
+                    approvals: BoundedVec::new(),
+                    asset_nonce: asset.nonce,
+                });
+            ensure!(pending.new_owner == new_owner, Error::<T>::TransferTargetMismatch);
+            ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApprovedTransfer);
+            // 审批人不会超过策略中的人数上限
+            pending.approvals.try_push(who.clone()).map_err(|_| Error::<T>::InvalidTransferPolicy)?;
+
+            let approvals = pending.approvals.len() as u32;
+            PendingTransfers::<T>::insert(&asset_id, pending);
+            Self::deposit_event(Event::TransferApproved { asset_id, approver: who, new_owner, approvals });
+            Ok(())
+        }
+
+        /// 所有者执行已获足够审批的转移，接收者为审批时的 new_owner
+        #[pallet::call_index(29)]
+        #[pallet::weight(<T as Config>::WeightInfo::execute_transfer())]
+        pub fn execute_transfer(origin: OriginFor<T>, asset_id: [u8; 32]) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let pending = PendingTransfers::<T>::get(&asset_id).ok_or(Error::<T>::NoPendingTransfer)?;
+            Self::do_owner_transfer(&who, &asset_id, pending.new_owner)
+        }
+
+        /// 当前策略的审批人同意所有者提出的策略变更，policy 必须与待审批的变更一致，
+        /// 避免所有者在审批期间替换提议；达到当前门限时变更立即生效
+        #[pallet::call_index(30)]
+        #[pallet::weight(<T as Config>::WeightInfo::approve_policy_change())]
+        pub fn approve_policy_change(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            policy: TransferPolicy<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let Some(TransferPolicy::Threshold { approvers, threshold }) = TransferPolicies::<T>::get(&asset_id) else {
+                return Err(Error::<T>::NoPendingPolicyChange.into());
+            };
+            ensure!(approvers.contains(&who), Error::<T>::NotTransferApprover);
+
+            let mut pending = PendingPolicyChanges::<T>::get(&asset_id).ok_or(Error::<T>::NoPendingPolicyChange)?;
+            ensure!(pending.policy == policy, Error::<T>::PolicyChangeMismatch);
+            ensure!(!pending.approvals.contains(&who), Error::<T>::AlreadyApprovedTransfer);
+            pending.approvals.try_push(who.clone()).map_err(|_| Error::<T>::InvalidTransferPolicy)?;
+
+            let approvals = pending.approvals.len() as u32;
+            Self::deposit_event(Event::TransferPolicyChangeApproved { asset_id, approver: who, approvals });
+            if approvals >= threshold {
+                Self::apply_transfer_policy(asset_id, pending.policy);
+            } else {
+                PendingPolicyChanges::<T>::insert(&asset_id, pending);
+            }
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
+        /// 注册频率限制：距上次注册不足 MinBlocksBetweenRegistrations 个区块时拒绝，豁免名单中的账户不受限制
+        fn ensure_registration_allowed(who: &T::AccountId) -> DispatchResult {
+            let min_gap = T::MinBlocksBetweenRegistrations::get();
+            if min_gap.is_zero() || Self::is_registration_exempt(who) {
+                return Ok(());
+            }
+            if let Some(last) = Self::last_registration(who) {
+                let now = frame_system::Pallet::<T>::block_number();
+                ensure!(now.saturating_sub(last) >= min_gap, Error::<T>::RegistrationRateLimited);
+            }
+            Ok(())
+        }
+
+        /// 检查权证类型与数量的发行约束并分配权证ID，构造待写入的权证
+        fn build_certificate(
+            asset_id: &[u8; 32],
+            asset: &DataAsset<T::AccountId>,
+            is_owner: bool,
+            holder: T::AccountId,
+            right_type: u8,
+            valid_until: Option<u64>,
+            current_time: u64,
+        ) -> Result<RightToken<T::AccountId>, DispatchError> {
+            // 转换 u8 到 RightType，并检查该类型的发行约束
+            let right_type_enum = RightType::from_u8(right_type).ok_or(Error::<T>::InvalidRightType)?;
+            ensure!(is_owner || !right_type_enum.owner_only(), Error::<T>::NotAuthorized);
+            if right_type_enum.requires_expiry() {
+                ensure!(
+                    valid_until.map_or(false, |until| until > current_time),
+                    Error::<T>::ValidityRequired
+                );
+            }
+            // 有效期必须落在 [now + Min, now + Max] 内；永久权证需配置允许
+            match valid_until {
+                Some(until) => ensure!(
+                    until >= current_time.saturating_add(T::MinCertificateValidity::get())
+                        && until <= current_time.saturating_add(T::MaxCertificateValidity::get()),
+                    Error::<T>::InvalidValidityPeriod
+                ),
+                None => ensure!(T::AllowPerpetualCertificates::get(), Error::<T>::InvalidValidityPeriod),
+            }
+
+            CertificateCounts::<T>::try_mutate(asset_id, |count| -> DispatchResult {
+                ensure!(*count < T::MaxCertificatesPerAsset::get(), Error::<T>::CertificateLimitReached);
+                *count += 1;
+                Ok(())
+            })?;
+            TotalCertificates::<T>::mutate(|total| *total = total.saturating_add(1));
+
+            let token_id = Self::get_next_certificate_id(asset_id);
+
+            // 使用 minimal 构造函数，没有修改issuer，市场只是代理
+            Ok(RightToken::minimal(
+                token_id,
+                right_type_enum,
+                holder, // 权证的购买者
+                asset.owner.clone(), // 元证持有者作为权证的发行者
+                *asset_id,
+                current_time,
+                valid_until
+            ))
+        }
+
+        /// 奖励代理发行权证的市场（捕捉错误，不阻断发行）
+        fn reward_issuance_operator(market: &T::AccountId) {
+            if let Err(e) = T::IncentiveHandler::reward_issuance_operator(market) {
+                log::error!("代理发行奖励发放失败：market={:?}, error={:?}", market, e);
+            }
+        }
+
+        /// 向发行者收取权证的预付行权费，转入行权费池并记录托管
+        fn charge_issuance_fee(asset_id: &[u8; 32], certificate_id: &[u8; 32], payer: &T::AccountId) -> DispatchResult {
+            let fee = T::CertificateIssuanceFee::get();
+            if fee.is_zero() {
+                return Ok(());
+            }
+            T::Currency::transfer(payer, &T::ExerciseFeePoolAccount::get(), fee, ExistenceRequirement::KeepAlive)
+                .map_err(|_| Error::<T>::InsufficientBalance)?;
+            IssuanceFeeEscrow::<T>::insert(asset_id, certificate_id, (payer.clone(), fee));
+            Ok(())
+        }
+
+        /// 权证从未行权时，把托管的行权费从行权费池退还给发行者
+        fn refund_issuance_fee(asset_id: &[u8; 32], certificate_id: &[u8; 32]) -> DispatchResult {
+            let Some((issuer, amount)) = IssuanceFeeEscrow::<T>::take(asset_id, certificate_id) else {
+                return Ok(());
+            };
+            T::Currency::transfer(&T::ExerciseFeePoolAccount::get(), &issuer, amount, ExistenceRequirement::AllowDeath)?;
+            Self::deposit_event(Event::IssuanceFeeRefunded {
+                asset_id: *asset_id,
+                certificate_id: *certificate_id,
+                issuer,
+                amount,
+            });
+            Ok(())
+        }
+
+        /// 将新权证记入发行者索引；由市场代理发行时同时记入该市场的代理索引
+        fn index_issued_certificate(
+            asset_id: &[u8; 32],
+            certificate: &RightToken<T::AccountId>,
+            proxy: Option<&T::AccountId>,
+        ) -> DispatchResult {
+            let entry = (*asset_id, certificate.token_id);
+            CertificatesByIssuer::<T>::try_mutate(&certificate.issuer, |issued| issued.try_push(entry))
+                .map_err(|_| Error::<T>::TooManyIssuedCertificates)?;
+            if let Some(market) = proxy {
+                CertificatesByProxy::<T>::try_mutate(market, |issued| issued.try_push(entry))
+                    .map_err(|_| Error::<T>::TooManyIssuedCertificates)?;
+                CertificateProxy::<T>::insert(asset_id, certificate.certificate_id, market);
+            }
+            Ok(())
+        }
+
+        /// 从发行者索引和代理市场索引中移除权证（权证过期时调用，保留代理记录以便延期后重新记入）
+        fn unindex_certificate(asset_id: &[u8; 32], certificate: &RightToken<T::AccountId>) {
+            let entry = (*asset_id, certificate.token_id);
+            let drop_entry = |issued: &mut Option<BoundedVec<([u8; 32], u32), T::MaxCertificatesPerIssuer>>| {
+                if let Some(list) = issued {
+                    list.retain(|e| *e != entry);
+                    if list.is_empty() {
+                        *issued = None;
+                    }
+                }
+            };
+            CertificatesByIssuer::<T>::mutate_exists(&certificate.issuer, drop_entry);
+            if let Some(market) = CertificateProxy::<T>::get(asset_id, certificate.certificate_id) {
+                CertificatesByProxy::<T>::mutate_exists(&market, drop_entry);
+            }
+        }
+
+        /// 权证被删除时清理其全部索引
+        fn forget_certificate(asset_id: &[u8; 32], certificate: &RightToken<T::AccountId>) {
+            Self::unindex_certificate(asset_id, certificate);
+            CertificateProxy::<T>::remove(asset_id, certificate.certificate_id);
+        }
+
         fn asset_trie_info() -> sp_core::storage::ChildInfo {
             sp_core::storage::ChildInfo::new_default(ASSET_TRIE_ID)
         }
@@ -543,6 +1835,15 @@ pub mod pallet {
             frame_system::Pallet::<T>::account_exists(account)
         }
 
+        /// 市场转移的接收者必须是已存在的非零账户，避免资产被转给零地址而“销毁”、质押金无人认领
+        fn ensure_valid_recipient(who: &T::AccountId) -> DispatchResult {
+            ensure!(
+                !Self::is_zero_account(who) && Self::account_exists(who),
+                Error::<T>::InvalidRecipient
+            );
+            Ok(())
+        }
+
         pub fn is_zero_account(account: &T::AccountId) -> bool {
             // 方法1：检查编码后是否全为零
             let encoded = account.encode();
@@ -555,14 +1856,15 @@ pub mod pallet {
         }
         
         // 获取自增的 token_id，最大2^32-1(42亿)
-        fn get_and_increment_token_id() -> u32 {
+        fn get_and_increment_token_id() -> Result<u32, DispatchError> {
             let child_info = Self::asset_trie_info();
             let key = [METADATA_PREFIX, b"next_token_id"].concat();
             
             let current = child::get::<u32>(&child_info, &key).unwrap_or(0);
-            let next = current.saturating_add(1);
+            // 饱和加法会导致 token_id 重复，映射被覆盖，这里直接报错
+            let next = current.checked_add(1).ok_or(Error::<T>::TokenIdOverflow)?;
             child::put(&child_info, &key, &next);
-            current
+            Ok(current)
         }
         
         fn set_token_mapping(token_id: u32, asset_id: [u8; 32]) {
@@ -573,6 +1875,45 @@ pub mod pallet {
             child::put(&child_info, &key, &asset_id);
         }
         
+        fn remove_token_mapping(token_id: u32) {
+            let child_info = Self::asset_trie_info();
+            let mut key = METADATA_PREFIX.to_vec();
+            key.extend_from_slice(b"token_mappings/");
+            key.extend_from_slice(&token_id.to_le_bytes());
+            child::kill(&child_info, &key);
+        }
+
+        /// 从资产树中删除资产及其 token_id 映射，并清理该资产的各项索引（质押记录由调用方先行结算）
+        fn remove_asset(asset_id: &[u8; 32], token_id: u32) {
+            if let Some(asset) = Self::get_asset(asset_id) {
+                AssetIdByContentHash::<T>::mutate_exists(asset.raw_data_hash, |maybe_ids| {
+                    if let Some(ids) = maybe_ids {
+                        ids.retain(|id| id != asset_id);
+                        if ids.is_empty() {
+                            *maybe_ids = None;
+                        }
+                    }
+                });
+            }
+            child::kill(&Self::asset_trie_info(), &Self::make_asset_key(asset_id));
+            Self::remove_token_mapping(token_id);
+            TotalAssets::<T>::mutate(|total| *total = total.saturating_sub(1));
+            Self::clear_approvals(asset_id);
+            ClaimedDataSize::<T>::remove(asset_id);
+            CertificateCounts::<T>::remove(asset_id);
+            CertificateRoots::<T>::remove(asset_id);
+            DownloadCount::<T>::remove(asset_id);
+            AssetLineage::<T>::remove(asset_id);
+            RevenueShares::<T>::remove(asset_id);
+            AvailabilityAttestations::<T>::remove(asset_id);
+            Self::clear_transfer_policy(asset_id);
+        }
+
+        /// 以该原始数据哈希注册且仍存在的资产ID，按注册顺序排列，至多 MaxAssetsPerContentHash 个
+        pub fn find_assets_by_hash(raw_data_hash: H256) -> Vec<[u8; 32]> {
+            AssetIdByContentHash::<T>::get(raw_data_hash).into_inner()
+        }
+
         fn get_token_mapping(token_id: u32) -> Option<[u8; 32]> {
             let child_info = Self::asset_trie_info();
             let mut key = METADATA_PREFIX.to_vec();
@@ -582,11 +1923,62 @@ pub mod pallet {
             child::get::<[u8; 32]>(&child_info, &key)
         }
         
+        /// 资产是否处于授权状态：存在未过期的授权记录；没有授权记录时以资产状态 Approved 为准
+        pub fn is_asset_approved(asset_id: &[u8; 32], asset: &DataAsset<T::AccountId>) -> bool {
+            if Self::approval_count(asset_id) == 0 {
+                return asset.is_approved();
+            }
+            let current_block = frame_system::Pallet::<T>::block_number();
+            AssetApprovals::<T>::iter_prefix_values(asset_id)
+                .any(|approval| !approval.is_expired(current_block))
+        }
+
+        /// 账户是否为该资产当前被授权（且未过期）的市场
+        pub fn is_approved_operator(asset_id: &[u8; 32], who: &T::AccountId) -> bool {
+            Self::ensure_approved_operator(asset_id, who).is_ok()
+        }
+
+        /// 检查账户是否为该资产被授权的市场，授权过期时返回 AuthorizationExpired
+        pub fn ensure_approved_operator(asset_id: &[u8; 32], who: &T::AccountId) -> DispatchResult {
+            let approval = Self::asset_approvals(asset_id, who).ok_or(Error::<T>::NotAuthorized)?;
+            ensure!(
+                !approval.is_expired(frame_system::Pallet::<T>::block_number()),
+                Error::<T>::AuthorizationExpired
+            );
+            Ok(())
+        }
+
+        /// 移除单个市场的授权，返回授权是否存在
+        fn remove_approval(asset_id: &[u8; 32], operator: &T::AccountId) -> bool {
+            if AssetApprovals::<T>::take(asset_id, operator).is_none() {
+                return false;
+            }
+            ApprovalCount::<T>::mutate_exists(asset_id, |count| {
+                *count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+            });
+            true
+        }
+
+        /// 清除资产上所有市场的授权，返回清除的授权数量
+        fn clear_approvals(asset_id: &[u8; 32]) -> u32 {
+            let count = ApprovalCount::<T>::take(asset_id);
+            let _ = AssetApprovals::<T>::clear_prefix(asset_id, T::MaxOperatorsPerAsset::get(), None);
+            count
+        }
+
         fn certificate_trie_info() -> sp_core::storage::ChildInfo {
             sp_core::storage::ChildInfo::new_default(CERTIFICATE_TRIE_PREFIX)
         }
         
+        /// 权证的所有写入（发行、转让等）都必须经过这里，以保证权证根同步更新
         fn insert_certificate(asset_id: &[u8; 32], cert: &RightToken<T::AccountId>) -> DispatchResult {
+            Self::put_certificate(asset_id, cert);
+            Self::update_certificate_root(asset_id);
+            Ok(())
+        }
+
+        /// 只写入权证而不更新权证根，调用方必须在写入结束后调用 update_certificate_root
+        fn put_certificate(asset_id: &[u8; 32], cert: &RightToken<T::AccountId>) {
             let child_info = Self::certificate_trie_info();
             
             // Key = asset_id (32 bytes) + certificate_id (32 bytes)
@@ -594,7 +1986,12 @@ pub mod pallet {
             storage_key.extend_from_slice(&cert.certificate_id[..]);
             
             child::put(&child_info, &storage_key, cert);
-            Ok(())
+        }
+
+        /// 查询权证在当前时间的有效期与状态，调用方无需自行计算时间
+        pub fn certificate_validity(asset_id: &[u8; 32], cert_id: &[u8; 32]) -> Option<CertificateValidity> {
+            Self::get_certificate(asset_id, cert_id)
+                .map(|cert| cert.validity(Self::current_timestamp()))
         }
 
         pub fn get_certificate(asset_id: &[u8; 32], cert_id: &[u8; 32]) -> Option<RightToken<T::AccountId>> {
@@ -606,15 +2003,206 @@ pub mod pallet {
             child::get::<RightToken<T::AccountId>>(&child_info, &storage_key)
         }
                 
+        /// 权证的所有删除（撤销、过期清理等）都必须经过这里，以保证权证根同步更新
         fn remove_certificate(asset_id: &[u8; 32], cert_id: &[u8; 32]) -> DispatchResult {
             let child_info = Self::certificate_trie_info();
             
             let mut storage_key = asset_id.to_vec();
             storage_key.extend_from_slice(cert_id);
             
+            if let Some(cert) = Self::get_certificate(asset_id, cert_id) {
+                Self::forget_certificate(asset_id, &cert);
+            }
             child::kill(&child_info, &storage_key);
+            CertificateCounts::<T>::mutate_exists(asset_id, |count| {
+                *count = count.map(|c| c.saturating_sub(1)).filter(|c| *c > 0);
+            });
+            TotalCertificates::<T>::mutate(|total| *total = total.saturating_sub(1));
+            CertificateUsageQuota::<T>::remove(asset_id, cert_id);
+            Self::update_certificate_root(asset_id);
             Ok(())
         }
+
+        /// 将有到期时间的权证排入 ExpiryQueue；对应的桶已满时顺延到之后第一个有空位的桶
+        fn enqueue_expiry(asset_id: &[u8; 32], cert: &RightToken<T::AccountId>) {
+            let Some(valid_until) = cert.valid_until else { return };
+            if NextExpiryBucket::<T>::get().is_none() {
+                NextExpiryBucket::<T>::put(Self::current_timestamp() / EXPIRY_BUCKET_MILLIS);
+            }
+
+            let mut bucket = valid_until / EXPIRY_BUCKET_MILLIS;
+            for _ in 0..EXPIRY_QUEUE_MAX_PROBE {
+                let queued = ExpiryQueue::<T>::mutate(bucket, |queue| {
+                    queue.try_push((*asset_id, cert.certificate_id)).is_ok()
+                });
+                if queued {
+                    return;
+                }
+                bucket = bucket.saturating_add(1);
+            }
+            log::error!("权证到期队列已满，无法排入：asset_id={:?}, certificate_id={:?}", asset_id, cert.certificate_id);
+        }
+
+        /// 按时间顺序处理已经结束的到期桶（在 on_initialize 中调用）
+        ///
+        /// 每个区块最多标记 MaxExpirationsPerBlock 个权证、最多访问同样数量的桶；
+        /// 桶未处理完时把剩余部分写回，下一个区块从该桶继续。每个受影响的资产只重算一次权证根
+        pub fn process_certificate_expirations() -> Weight {
+            let db = T::DbWeight::get();
+            let mut weight = db.reads(1);
+            let Some(mut bucket) = NextExpiryBucket::<T>::get() else { return weight };
+
+            let now = Self::current_timestamp();
+            let current_bucket = now / EXPIRY_BUCKET_MILLIS;
+            let limit = T::MaxExpirationsPerBlock::get();
+            weight = weight.saturating_add(db.reads(1));
+
+            let mut processed = 0u32;
+            let mut visited = 0u32;
+            let mut touched_assets: Vec<[u8; 32]> = Vec::new();
+            while bucket < current_bucket && processed < limit && visited < limit {
+                visited += 1;
+                let mut due = ExpiryQueue::<T>::take(bucket).into_inner();
+                weight = weight.saturating_add(db.reads_writes(1, 1));
+
+                let take = due.len().min(limit.saturating_sub(processed) as usize);
+                let rest = due.split_off(take);
+                for (asset_id, certificate_id) in due {
+                    processed += 1;
+                    weight = weight.saturating_add(db.reads(1));
+                    let Some(mut cert) = Self::get_certificate(&asset_id, &certificate_id) else { continue };
+                    // 已撤销、已标记或已延期的权证跳过
+                    if cert.status != CertificateStatus::Active || !cert.is_expired(now) {
+                        continue;
+                    }
+                    cert.status = CertificateStatus::Expired;
+                    Self::put_certificate(&asset_id, &cert);
+                    Self::unindex_certificate(&asset_id, &cert);
+                    weight = weight.saturating_add(db.reads_writes(3, 3));
+                    if !touched_assets.contains(&asset_id) {
+                        touched_assets.push(asset_id);
+                    }
+                    Self::deposit_event(Event::CertificateExpired { asset_id, certificate_id });
+                }
+
+                if !rest.is_empty() {
+                    ExpiryQueue::<T>::insert(bucket, BoundedVec::truncate_from(rest));
+                    break;
+                }
+                bucket = bucket.saturating_add(1);
+            }
+
+            for asset_id in touched_assets.iter() {
+                Self::update_certificate_root(asset_id);
+                // 重算权证根需要遍历该资产的权证子树
+                weight = weight.saturating_add(db.reads_writes(
+                    CertificateCounts::<T>::get(asset_id).into(),
+                    1,
+                ));
+            }
+            NextExpiryBucket::<T>::put(bucket);
+            weight.saturating_add(db.writes(1))
+        }
+
+        /// 重新计算并保存资产的权证根
+        fn update_certificate_root(asset_id: &[u8; 32]) {
+            CertificateRoots::<T>::insert(asset_id, Self::get_certificate_root(asset_id));
+        }
+
+        /// 按键顺序返回资产的前 limit 个权证ID
+        fn certificate_page(asset_id: &[u8; 32], limit: u32) -> Vec<[u8; 32]> {
+            let child_info = Self::certificate_trie_info();
+            let storage_key = child_info.storage_key();
+
+            let mut ids = Vec::new();
+            let mut key = asset_id.to_vec();
+            while (ids.len() as u32) < limit {
+                let Some(next) = sp_io::default_child_storage::next_key(storage_key, &key) else { break };
+                if !next.starts_with(asset_id) {
+                    break;
+                }
+                if let Ok(cert_id) = <[u8; 32]>::try_from(&next[32..]) {
+                    ids.push(cert_id);
+                }
+                key = next;
+            }
+            ids
+        }
+
+        /// 汇总资产的所有者、质押、存储副本、证明新鲜度、权证数量和授权状态
+        pub fn asset_health(asset_id: &[u8; 32]) -> Option<AssetHealth<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>> {
+            let asset = Self::get_asset(asset_id)?;
+            let collateral = AssetCollateral::<T>::get(asset_id);
+            let next_release_block = collateral.as_ref().and_then(|info| {
+                info.release_schedule
+                    .iter()
+                    .filter(|phase| !phase.is_released)
+                    .map(|phase| phase.unlock_block)
+                    .min()
+            });
+
+            Some(AssetHealth {
+                is_locked: asset.is_locked(),
+                status: asset.status,
+                owner: asset.owner,
+                approved_markets: ApprovalCount::<T>::get(asset_id),
+                collateral_status: collateral.as_ref().map(|info| info.status.clone()),
+                collateral_reserved: collateral.map_or_else(Zero::zero, |info| info.reserved_amount),
+                next_release_block,
+                replica_count: T::StorageProofProvider::replica_count(asset_id),
+                last_proof_block: T::StorageProofProvider::last_proof_block(asset_id),
+                certificate_count: CertificateCounts::<T>::get(asset_id),
+            })
+        }
+
+        /// 已保存的资产权证根，没有发行过权证的资产返回空树的根
+        pub fn certificate_root(asset_id: &[u8; 32]) -> H256 {
+            CertificateRoots::<T>::get(asset_id)
+                .unwrap_or_else(|| Self::get_certificate_root(asset_id))
+        }
+
+        /// 遍历权证子树中以 asset_id 为前缀的记录，重新计算该资产的权证根
+        /// (certificate_id -> 编码后的权证) 构成一棵 trie，开销与该资产的权证数量成正比
+        pub fn get_certificate_root(asset_id: &[u8; 32]) -> H256 {
+            let child_info = Self::certificate_trie_info();
+            let storage_key = child_info.storage_key();
+
+            let mut entries: Vec<(Vec<u8>, Vec<u8>)> = Vec::new();
+            let mut key = asset_id.to_vec();
+            while let Some(next) = sp_io::default_child_storage::next_key(storage_key, &key) {
+                if !next.starts_with(asset_id) {
+                    break;
+                }
+                // 只统计 asset_id + certificate_id 形式的键
+                if next.len() == 64 {
+                    if let Some(value) = child::get_raw(&child_info, &next) {
+                        entries.push((next[32..].to_vec(), value));
+                    }
+                }
+                key = next;
+            }
+
+            sp_io::trie::blake2_256_root(entries, sp_core::storage::StateVersion::V1)
+        }
+
+        /// 遍历权证子树统计资产现有的权证数量，供迁移回填计数器使用，开销与该资产的权证数量成正比
+        pub(crate) fn count_certificates(asset_id: &[u8; 32]) -> u32 {
+            let child_info = Self::certificate_trie_info();
+            let storage_key = child_info.storage_key();
+
+            let mut count = 0u32;
+            let mut key = asset_id.to_vec();
+            while let Some(next) = sp_io::default_child_storage::next_key(storage_key, &key) {
+                if !next.starts_with(asset_id) {
+                    break;
+                }
+                if next.len() == 64 {
+                    count = count.saturating_add(1);
+                }
+                key = next;
+            }
+            count
+        }
         
         // 机制导致不能通过遍历child trie获取某资产下的所有证书
         // pub fn get_asset_certificates(asset_id: &[u8; 32]) -> Vec<RightToken<T::AccountId>> {
@@ -633,17 +2221,208 @@ pub mod pallet {
             current
         }
         
+        /// 计算资产树根并缓存，返回按资产数量计算的权重
+        pub(crate) fn cache_asset_root() -> Weight {
+            CachedAssetRoot::<T>::put(Self::compute_asset_root());
+            <T as Config>::WeightInfo::cache_asset_root(Self::registered_asset_count())
+        }
+
+        /// 已分配的 token_id 数量，即资产树中资产数量的上限（注销的资产不回收 token_id）
+        pub fn registered_asset_count() -> u32 {
+            let key = [METADATA_PREFIX, b"next_token_id"].concat();
+            child::get::<u32>(&Self::asset_trie_info(), &key).unwrap_or(0)
+        }
+
         pub fn compute_asset_root() -> H256 {
             let child_info = Self::asset_trie_info();
             let root_bytes = child::root(&child_info, sp_core::storage::StateVersion::V1);
             H256::from_slice(&root_bytes)
         }
         
+        /// 事件主题：字段值的 T::Hashing 哈希，订阅方用同样的方法计算后按主题过滤
+        pub fn event_topic<V: Encode>(value: &V) -> T::Hash {
+            T::Hashing::hash_of(value)
+        }
+
+        /// 带索引主题发出事件，轻客户端不必扫描全部事件
+        fn deposit_indexed_event(event: Event<T>, topics: &[T::Hash]) {
+            let event = <T as Config>::RuntimeEvent::from(event).into();
+            frame_system::Pallet::<T>::deposit_event_indexed(topics, event);
+        }
+
+        fn deposit_certificate_issued(asset_id: [u8; 32], certificate_id: [u8; 32], issuer: T::AccountId, holder: T::AccountId) {
+            let topics = [Self::event_topic(&asset_id), Self::event_topic(&issuer), Self::event_topic(&holder)];
+            Self::deposit_indexed_event(Event::CertificateIssued { asset_id, certificate_id, issuer, holder }, &topics);
+        }
+
+        /// 记录一次所有权转移，记录已满时丢弃最早的一条
+        fn record_transfer_lineage(asset_id: &[u8; 32], from: &T::AccountId, to: &T::AccountId) {
+            let entry = LineageEntry::Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                block: frame_system::Pallet::<T>::block_number(),
+            };
+            AssetLineage::<T>::mutate(asset_id, |lineage| {
+                if lineage.is_full() && !lineage.is_empty() {
+                    lineage.remove(0);
+                }
+                let _ = lineage.try_push(entry);
+            });
+        }
+
+        fn deposit_asset_transferred(asset_id: [u8; 32], from: T::AccountId, to: T::AccountId, fee: BalanceOf<T>) {
+            let topics = [Self::event_topic(&asset_id), Self::event_topic(&from), Self::event_topic(&to)];
+            Self::deposit_indexed_event(Event::AssetTransferred { asset_id, from, to, fee }, &topics);
+        }
+
+        /// 转移手续费：固定部分 + 元证价格的比例部分
+        pub fn transfer_fee(asset: &DataAsset<T::AccountId>) -> BalanceOf<T> {
+            let price: BalanceOf<T> = asset.pricing_config.base_price.saturated_into();
+            T::TransferFee::get().saturating_add(T::TransferFeeRatio::get() * price)
+        }
+
+        /// 由转移的发起者（所有者或市场）向激励池支付转移手续费，返回实际收取的金额
+        fn charge_transfer_fee(payer: &T::AccountId, asset: &DataAsset<T::AccountId>) -> Result<BalanceOf<T>, DispatchError> {
+            let fee = Self::transfer_fee(asset);
+            if !fee.is_zero() {
+                T::Currency::transfer(payer, &T::IncentivePoolAccount::get(), fee, ExistenceRequirement::KeepAlive)
+                    .map_err(|_| Error::<T>::InsufficientBalance)?;
+            }
+            Ok(fee)
+        }
+
+        /// 分成比例不能为零、接收者不能重复，合计不超过 100%
+        fn ensure_valid_revenue_shares(shares: &[(T::AccountId, Perbill)]) -> DispatchResult {
+            let mut total: u64 = 0;
+            for (index, (recipient, share)) in shares.iter().enumerate() {
+                ensure!(!share.is_zero(), Error::<T>::InvalidRevenueShares);
+                ensure!(
+                    !shares[..index].iter().any(|(other, _)| other == recipient),
+                    Error::<T>::InvalidRevenueShares
+                );
+                total = total.saturating_add(share.deconstruct() as u64);
+            }
+            ensure!(total <= Perbill::one().deconstruct() as u64, Error::<T>::InvalidRevenueShares);
+            Ok(())
+        }
+
+        /// 按 RevenueShares 拆分长期分成，剩余部分（含舍入误差）归 owner；未设置分成时全部归 owner
+        pub fn split_revenue_share(
+            asset_id: &[u8; 32],
+            owner: &T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> Vec<(T::AccountId, BalanceOf<T>)> {
+            let mut payouts = Vec::new();
+            let mut remainder = amount;
+            for (recipient, share) in RevenueShares::<T>::get(asset_id).unwrap_or_default() {
+                let part = (share * amount).min(remainder);
+                remainder = remainder.saturating_sub(part);
+                payouts.push((recipient, part));
+            }
+            payouts.push((owner.clone(), remainder));
+            payouts
+        }
+
+        /// 市场转移资产时，由市场按元证价格的 LongTermShareRatio 支付长期分成给转移前的所有者及分成接收者
+        fn pay_long_term_share(
+            payer: &T::AccountId,
+            asset_id: &[u8; 32],
+            asset: &DataAsset<T::AccountId>,
+        ) -> DispatchResult {
+            let price: BalanceOf<T> = asset.pricing_config.base_price.saturated_into();
+            let amount = T::LongTermShareRatio::get() * price;
+            if amount.is_zero() {
+                return Ok(());
+            }
+
+            for (recipient, part) in Self::split_revenue_share(asset_id, &asset.owner, amount) {
+                if part.is_zero() || &recipient == payer {
+                    continue;
+                }
+                T::Currency::transfer(payer, &recipient, part, ExistenceRequirement::KeepAlive)
+                    .map_err(|_| Error::<T>::InsufficientBalance)?;
+            }
+
+            Self::deposit_event(Event::LongTermSharePaid { asset_id: *asset_id, payer: payer.clone(), amount });
+            Ok(())
+        }
+
         fn current_timestamp() -> u64 {
             <pallet_timestamp::Pallet<T>>::get().saturated_into::<u64>()
         }
 
+        /// 所有者发起的转移（transfer_asset / execute_transfer），门限策略下要求审批已达门限
+        fn do_owner_transfer(who: &T::AccountId, asset_id: &[u8; 32], new_owner: T::AccountId) -> DispatchResult {
+            let mut asset = Self::get_asset(asset_id).ok_or(Error::<T>::AssetNotFound)?;
+            ensure!(asset.owner == *who, Error::<T>::NotOwner); // 在未被授权的时候，只有资产所有者才能转移资产
+            ensure!(!asset.is_locked(), Error::<T>::AssetLocked); // 锁定的资产不能转移
+            ensure!(
+                !T::RequireCertificatesRevokedOnTransfer::get() || CertificateCounts::<T>::get(asset_id) == 0,
+                Error::<T>::OutstandingCertificates
+            );
+            Self::ensure_transfer_threshold(asset_id, &asset, &new_owner)?;
+            let fee = Self::charge_transfer_fee(who, &asset)?;
+            // 仍在锁定的质押金随资产一起转给新所有者
+            Self::transfer_collateral(asset_id, &new_owner)?;
+            
+            let old_owner = asset.owner.clone();
+            asset.owner = new_owner.clone();
+            asset.nonce += 1;
+            asset.transaction_count += 1;
+            asset.updated_at = Self::current_timestamp();
+            // 转移会清除所有授权，资产回到私有状态
+            asset.status = AssetStatus::Private;
+            Self::insert_asset(asset_id, &asset)?;
+            // 如果所有者自己转移资产，清除该资产上所有未完成的市场授权。确保授权记录不会残留。
+            // 但是这样会导致市场方无法继续操作资产，必须重新授权。
+            Self::clear_approvals(asset_id);
+            Self::clear_transfer_policy(asset_id);
+
+            T::IncentiveHandler::register_asset_trade(asset_id);
+            Self::record_transfer_lineage(asset_id, &old_owner, &new_owner);
+            Self::deposit_asset_transferred(*asset_id, old_owner, new_owner, fee);
+            Ok(())
+        }
+
+        /// 门限策略下检查等待中的转移：接收者一致、审批期间资产未被转移、审批人数达到门限
+        fn ensure_transfer_threshold(
+            asset_id: &[u8; 32],
+            asset: &DataAsset<T::AccountId>,
+            new_owner: &T::AccountId,
+        ) -> DispatchResult {
+            let Some(TransferPolicy::Threshold { threshold, .. }) = TransferPolicies::<T>::get(asset_id) else {
+                return Ok(());
+            };
+            let approved = PendingTransfers::<T>::get(asset_id).is_some_and(|pending| {
+                pending.asset_nonce == asset.nonce
+                    && pending.new_owner == *new_owner
+                    && pending.approvals.len() as u32 >= threshold
+            });
+            ensure!(approved, Error::<T>::ThresholdNotMet);
+            Ok(())
+        }
+
+        /// 写入新的转移策略，之前的转移审批和策略变更提议不再有效
+        fn apply_transfer_policy(asset_id: [u8; 32], policy: TransferPolicy<T::AccountId>) {
+            match &policy {
+                TransferPolicy::SingleOwner => TransferPolicies::<T>::remove(&asset_id),
+                TransferPolicy::Threshold { .. } => TransferPolicies::<T>::insert(&asset_id, policy.clone()),
+            }
+            PendingTransfers::<T>::remove(&asset_id);
+            PendingPolicyChanges::<T>::remove(&asset_id);
+            Self::deposit_event(Event::TransferPolicySet { asset_id, policy });
+        }
+
+        /// 所有权变更后旧所有者设置的审批策略和未完成的审批一并失效，新所有者回到单独转移
+        fn clear_transfer_policy(asset_id: &[u8; 32]) {
+            TransferPolicies::<T>::remove(asset_id);
+            PendingTransfers::<T>::remove(asset_id);
+            PendingPolicyChanges::<T>::remove(asset_id);
+        }
+
         /// 供其他Pallet调用的方法，跳过签名检查，但检查授权
+        /// 手续费与质押转移任一失败时整体回滚，调用方不需要自己开启存储层
+        #[transactional]
         pub fn transfer_by_market_internal(
             asset_id: &[u8; 32],
             market_account: &T::AccountId,
@@ -652,12 +2431,16 @@ pub mod pallet {
             // 1. 获取资产
             let mut asset = Self::get_asset(asset_id).ok_or(Error::<T>::AssetNotFound)?;
             
-            // 2. 核心检查：检查当前资产是否授权给了调用者 (market_account)
-            let approved_account = Self::asset_approvals(asset_id).ok_or(Error::<T>::NotAuthorized)?;
-            ensure!(approved_account == *market_account, Error::<T>::NotAuthorized);
+            // 2. 核心检查：检查当前资产是否授权给了调用者 (market_account)，且授权未过期
+            Self::ensure_approved_operator(asset_id, market_account)?;
+            Self::ensure_valid_recipient(new_owner)?;
             
-            // 3. 检查锁定状态
+            // 3. 检查锁定状态；门限策略下市场转移同样需要审批人对该接收者的审批
             ensure!(!asset.is_locked(), Error::<T>::AssetLocked);
+            Self::ensure_transfer_threshold(asset_id, &asset, new_owner)?;
+            let fee = Self::charge_transfer_fee(market_account, &asset)?;
+            Self::pay_long_term_share(market_account, asset_id, &asset)?;
+            Self::transfer_collateral(asset_id, new_owner)?;
             
             // 4. 执行转移
             let old_owner = asset.owner.clone();
@@ -665,17 +2448,16 @@ pub mod pallet {
             asset.nonce += 1;
             asset.transaction_count += 1;
             asset.updated_at = Self::current_timestamp();
+            asset.status = AssetStatus::Private;
             
-            // 5. 保存并清理授权
+            // 5. 保存并清理所有市场的授权
             Self::insert_asset(asset_id, &asset)?;
-            AssetApprovals::<T>::remove(asset_id);
+            Self::clear_approvals(asset_id);
+            Self::clear_transfer_policy(asset_id);
             T::IncentiveHandler::register_asset_trade(asset_id);
             // 6. 发出事件
-            Self::deposit_event(Event::AssetTransferred { 
-                asset_id: *asset_id, 
-                from: old_owner, 
-                to: new_owner.clone() 
-            });
+            Self::record_transfer_lineage(asset_id, &old_owner, new_owner);
+            Self::deposit_asset_transferred(*asset_id, old_owner, new_owner.clone(), fee);
             
             Ok(())
         }
@@ -697,6 +2479,25 @@ pub mod pallet {
     }
 }
 
+impl<T: Config, Role> pallet_shared_traits::SlashNotificationHandler<T::AccountId, Role, BalanceOf<T>> for Pallet<T>
+where
+    Role: pallet_shared_traits::CollateralRoleKind,
+{
+    fn on_collateral_slashed(who: &T::AccountId, role: Role, amount: BalanceOf<T>) {
+        // 只关心数据创建者的质押，其他角色不影响资产状态
+        if !role.is_data_creator() || sp_runtime::traits::Zero::is_zero(&amount) {
+            return;
+        }
+        Self::deposit_event(Event::CreatorCollateralSlashed {
+            depositor: who.clone(),
+            amount,
+            assets: frame_support::BoundedVec::truncate_from(
+                Self::collateral_assets_of(who, None, T::MaxAssetsPerDepositor::get()),
+            ),
+        });
+    }
+}
+
 impl<T: Config> pallet_shared_traits::DataAssetProvider<T::AccountId, [u8; 32]> for Pallet<T> {
     fn get_asset_owner(asset_id: &[u8; 32]) -> Result<T::AccountId, pallet_shared_traits::AssetQueryError> {
         let asset = Self::get_asset(asset_id)
@@ -713,4 +2514,20 @@ impl<T: Config> pallet_shared_traits::DataAssetProvider<T::AccountId, [u8; 32]>
         
         Ok(asset.owner)
     }
+
+    fn get_recorded_owner(asset_id: &[u8; 32]) -> Option<T::AccountId> {
+        Self::get_asset(asset_id)
+            .map(|asset| asset.owner)
+            .filter(|owner| !Self::is_zero_account(owner))
+    }
+
+    fn get_raw_data_hash(asset_id: &[u8; 32]) -> Result<sp_core::H256, pallet_shared_traits::AssetQueryError> {
+        Self::get_asset(asset_id)
+            .map(|asset| asset.raw_data_hash)
+            .ok_or(pallet_shared_traits::AssetQueryError::AssetNotFound)
+    }
+
+    fn get_data_size(asset_id: &[u8; 32]) -> Result<u64, pallet_shared_traits::AssetQueryError> {
+        ClaimedDataSize::<T>::get(asset_id).ok_or(pallet_shared_traits::AssetQueryError::AssetNotFound)
+    }
 }
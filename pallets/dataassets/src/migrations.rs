@@ -0,0 +1,133 @@
+/// Storage migrations for the data assets pallet
+///
+/// Each version step lives in its own module with an unversioned inner migration,
+/// wrapped in `VersionedMigration` so it only runs when the on-chain version matches
+/// and bumps the version afterwards. Add new steps to the runtime's `Migrations` tuple.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::UncheckedOnRuntimeUpgrade,
+    weights::Weight,
+};
+
+pub mod v1 {
+    use super::*;
+
+    /// 首次声明存储版本：数据布局不变，只把链上版本从 0 设为 1
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            log::info!("pallet-dataassets: storage version set to v1, no data migrated");
+            Weight::zero()
+        }
+    }
+
+    /// 版本检查与写入版本号由 VersionedMigration 负责
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v2 {
+    use super::*;
+    use sp_runtime::traits::{Saturating, Zero};
+
+    /// 回填统计计数器：TotalAssets、TotalCertificates、TotalCollateralLocked
+    ///
+    /// 按现有资产、权证子树和 AssetCollateral 重新计数，旧链上这三项为空
+    ///
+    /// 权证直接从权证子树统计：CertificateCounts 在 v3 才回填，旧链上可能缺失
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let next_token_id = Pallet::<T>::registered_asset_count();
+            let mut total_assets = 0u32;
+            let mut total_certificates = 0u64;
+            for asset in (0..next_token_id).filter_map(Pallet::<T>::get_asset_by_token_id) {
+                total_assets = total_assets.saturating_add(1);
+                total_certificates = total_certificates.saturating_add(Pallet::<T>::count_certificates(&asset.asset_id).into());
+            }
+
+            let mut collateral_entries: u64 = 0;
+            let total_collateral = AssetCollateral::<T>::iter_values().fold(BalanceOf::<T>::zero(), |acc, info| {
+                collateral_entries += 1;
+                acc.saturating_add(info.reserved_amount)
+            });
+
+            TotalAssets::<T>::put(total_assets);
+            TotalCertificates::<T>::put(total_certificates);
+            TotalCollateralLocked::<T>::put(total_collateral);
+
+            log::info!(
+                "pallet-dataassets: backfilled stats, {} assets, {} certificates",
+                total_assets,
+                total_certificates,
+            );
+
+            // 每个 token_id 读映射和资产各一次，每个权证一次 next_key，每个资产末尾多一次，外加 next_token_id
+            let reads = 1u64
+                .saturating_add((next_token_id as u64).saturating_mul(2))
+                .saturating_add(total_certificates.saturating_add(total_assets.into()))
+                .saturating_add(collateral_entries);
+            <T as frame_system::Config>::DbWeight::get().reads_writes(reads, 3)
+        }
+    }
+
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+pub mod v3 {
+    use super::*;
+
+    /// 回填 CertificateCounts：按权证子树中每个资产现有的权证重新计数
+    ///
+    /// 计数器引入前发行的权证没有计入，不回填时这些资产可以超出 MaxCertificatesPerAsset 继续发行
+    pub struct InnerBackfillCertificateCounts<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerBackfillCertificateCounts<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let next_token_id = Pallet::<T>::registered_asset_count();
+            let mut certificates: u64 = 0;
+            let mut writes: u64 = 0;
+            for asset in (0..next_token_id).filter_map(Pallet::<T>::get_asset_by_token_id) {
+                let count = Pallet::<T>::count_certificates(&asset.asset_id);
+                certificates = certificates.saturating_add(count.into());
+                writes = writes.saturating_add(1);
+                if count == 0 {
+                    CertificateCounts::<T>::remove(asset.asset_id);
+                } else {
+                    CertificateCounts::<T>::insert(asset.asset_id, count);
+                }
+            }
+
+            log::info!("pallet-dataassets: backfilled certificate counts, {} certificates", certificates);
+
+            // 每个 token_id 读映射和资产各一次，每个权证一次 next_key，每个资产末尾多一次，外加 next_token_id
+            let reads = 1u64
+                .saturating_add((next_token_id as u64).saturating_mul(2))
+                .saturating_add(certificates.saturating_add(writes));
+            <T as frame_system::Config>::DbWeight::get().reads_writes(reads, writes)
+        }
+    }
+
+    pub type BackfillCertificateCounts<T> = VersionedMigration<
+        2,
+        3,
+        InnerBackfillCertificateCounts<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
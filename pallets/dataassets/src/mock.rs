@@ -0,0 +1,274 @@
+use crate as pallet_dataassets;
+use crate::types::DataAsset;
+use core::cell::RefCell;
+use frame_support::{
+    assert_ok,
+    parameter_types,
+    derive_impl,
+    traits::{ConstU128, ConstU32, ConstU64},
+    weights::constants::RocksDbWeight,
+};
+use frame_system::offchain::AppCrypto;
+use pallet_shared_traits::{IncentiveError, IncentiveHandler};
+use sp_core::H256;
+use sp_runtime::{
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    BuildStorage, Perbill,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        DataAssets: pallet_dataassets,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+    // 使用真实的读写权重，便于断言扫描访问的记录数
+    type DbWeight = RocksDbWeight;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
+}
+
+/// 链下工作机的测试签名密钥，账户即 UintAuthorityId 中的数值
+pub struct MockAttesterId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for MockAttesterId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+thread_local! {
+    // 记录 register_asset_trade 的调用次数，用于断言交易登记
+    static REGISTERED_TRADES: RefCell<u32> = RefCell::new(0);
+    // 记录获得代理发行奖励的市场
+    static REWARDED_OPERATORS: RefCell<Vec<u64>> = RefCell::new(Vec::new());
+}
+
+/// 模拟的激励处理器，不发放任何奖励
+pub struct MockIncentiveHandler;
+
+impl MockIncentiveHandler {
+    pub fn registered_trades() -> u32 {
+        REGISTERED_TRADES.with(|count| *count.borrow())
+    }
+
+    pub fn rewarded_operators() -> Vec<u64> {
+        REWARDED_OPERATORS.with(|markets| markets.borrow().clone())
+    }
+}
+
+impl IncentiveHandler<u64, [u8; 32], u128> for MockIncentiveHandler {
+    fn distribute_first_create_reward(_recipient: &u64, _asset_id: &[u8; 32]) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn register_asset_trade(_asset_id: &[u8; 32]) {
+        REGISTERED_TRADES.with(|count| *count.borrow_mut() += 1);
+    }
+
+    fn distribute_market_liquidity_reward(
+        _recipient: &u64,
+        _market_contract: &u64,
+        _order_amount: u128,
+    ) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn reserve_proposal_bond(_proposer: &u64) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn distribute_proposal_outcome(_proposer: &u64, _passed: bool, _support: Perbill) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn reward_issuance_operator(market: &u64) -> Result<(), IncentiveError> {
+        REWARDED_OPERATORS.with(|markets| markets.borrow_mut().push(*market));
+        Ok(())
+    }
+}
+
+/// 测试账户初始余额
+pub const INITIAL_BALANCE: u128 = 1_000_000;
+/// 接收转移手续费的激励池账户
+pub const INCENTIVE_POOL: u64 = 99;
+/// 托管预付行权费的行权费池账户
+pub const EXERCISE_FEE_POOL: u64 = 98;
+/// 链下工作机请求的 IPFS 网关
+pub const IPFS_GATEWAY: &str = "http://127.0.0.1:8080/ipfs/";
+/// 可用性阶段到期前提前检查的区块数
+pub const AVAILABILITY_CHECK_LEAD: u64 = 10;
+
+parameter_types! {
+    pub const BaseCollateral: u128 = 2_000;
+    // 测试中可调整，模拟治理修改质押参数
+    pub static CollateralPerMB: u128 = 100;
+    pub const MaxCollateral: u128 = 75_000;
+    pub const MinBillableSize: u64 = 1024;
+    pub const IncentivePoolAccount: u64 = INCENTIVE_POOL;
+    pub const ExerciseFeePoolAccount: u64 = EXERCISE_FEE_POOL;
+    // 默认不收预付行权费，测试中按需设置
+    pub static CertificateIssuanceFee: u128 = 0;
+    // 默认不收转移手续费，测试中按需设置
+    pub static TransferFee: u128 = 0;
+    pub static TransferFeeRatio: Perbill = Perbill::from_percent(0);
+    // 权证有效期范围（毫秒），测试中按需调整
+    pub static MinCertificateValidity: u64 = 1;
+    pub static MaxCertificateValidity: u64 = 1_000_000;
+    pub static AllowPerpetualCertificates: bool = true;
+    pub static RequireCertificatesRevokedOnTransfer: bool = false;
+    // 默认不限制注册频率，测试中按需设置
+    pub static MinBlocksBetweenRegistrations: u64 = 0;
+    // 默认不收长期分成，测试中按需设置
+    pub static LongTermShareRatio: Perbill = Perbill::from_percent(0);
+    // 默认不发布资产根 digest，测试中按需开启
+    pub static PublishAssetRootDigest: bool = false;
+    pub const IpfsGatewayUrl: &'static str = IPFS_GATEWAY;
+}
+
+impl pallet_dataassets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BaseCollateral = BaseCollateral;
+    type CollateralPerMB = CollateralPerMB;
+    type MaxCollateral = MaxCollateral;
+    type MinBillableSize = MinBillableSize;
+    type MaxNameLength = ConstU32<256>;
+    type MaxDescriptionLength = ConstU32<1024>;
+    type MaxReleasesPerBlock = ConstU32<4>;
+    type MaxOperatorsPerAsset = ConstU32<3>;
+    type MaxBatchSize = ConstU32<5>;
+    type MaxAssetsPerDepositor = ConstU32<8>;
+    type MaxCertificatesPerIssuer = ConstU32<8>;
+    type MaxCertificatesPerAsset = ConstU32<6>;
+    type MinCertificateValidity = MinCertificateValidity;
+    type MaxCertificateValidity = MaxCertificateValidity;
+    type AllowPerpetualCertificates = AllowPerpetualCertificates;
+    type MaxExpirationsPerBlock = ConstU32<2>;
+    type MaxExpirationsPerBucket = ConstU32<8>;
+    type RequireCertificatesRevokedOnTransfer = RequireCertificatesRevokedOnTransfer;
+    type TransferFee = TransferFee;
+    type TransferFeeRatio = TransferFeeRatio;
+    type IncentivePoolAccount = IncentivePoolAccount;
+    type CertificateIssuanceFee = CertificateIssuanceFee;
+    type ExerciseFeePoolAccount = ExerciseFeePoolAccount;
+    type MinBlocksBetweenRegistrations = MinBlocksBetweenRegistrations;
+    type MaxLineageDepth = ConstU32<3>;
+    type LongTermShareRatio = LongTermShareRatio;
+    type MaxRevenueShareRecipients = ConstU32<3>;
+    type MaxAssetsPerContentHash = ConstU32<2>;
+    type PublishAssetRootDigest = PublishAssetRootDigest;
+    type AuthorityId = MockAttesterId;
+    type IpfsGatewayUrl = IpfsGatewayUrl;
+    type GatewayTimeoutMillis = ConstU64<2_000>;
+    type AvailabilityCheckLead = ConstU64<AVAILABILITY_CHECK_LEAD>;
+    type AvailabilityAttestationTtl = ConstU64<100>;
+    type IncentiveHandler = MockIncentiveHandler;
+    type StorageProofProvider = ();
+    type WeightInfo = crate::weights::WeightInfo<Test>;
+}
+
+/// 以 `owner` 身份注册一个资产，`seed` 用于区分同一区块内的不同资产，返回资产ID
+pub fn register_test_asset(owner: u64, seed: u8) -> [u8; 32] {
+    let raw_data_hash = H256::repeat_byte(seed);
+    assert_ok!(DataAssets::register_asset(
+        RuntimeOrigin::signed(owner),
+        b"asset".to_vec(),
+        b"test asset".to_vec(),
+        raw_data_hash,
+        1024,
+    ));
+    DataAsset::<u64>::generate_asset_id(&owner, Timestamp::get(), &raw_data_hash)
+}
+
+/// 最近一次 CertificateIssued 事件中的权证ID
+pub fn last_issued_certificate() -> [u8; 32] {
+    System::events()
+        .into_iter()
+        .rev()
+        .find_map(|record| match record.event {
+            RuntimeEvent::DataAssets(pallet_dataassets::Event::CertificateIssued { certificate_id, .. }) => {
+                Some(certificate_id)
+            }
+            _ => None,
+        })
+        .expect("no certificate issued")
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, INITIAL_BALANCE), (2, INITIAL_BALANCE), (3, INITIAL_BALANCE)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        Timestamp::set_timestamp(6_000);
+    });
+    ext
+}
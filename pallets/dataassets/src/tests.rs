@@ -0,0 +1,2443 @@
+use crate::{
+    mock::*,
+    types::{ApprovalInfo, AssetStatus, CertificateStatus, CertificateValidity, CollateralStatus, LineageEntry, RightType,
+        TransferPolicy},
+    ApprovalCount, AssetApprovals, AssetCollateral, CertificateCounts, CertificateProxy, CertificateUsageQuota, ClaimedDataSize,
+    CollateralByDepositor, DownloadCount, Error, Event, ExpiryQueue, NextExpiryBucket, TotalAssets, TotalCertificates,
+    TotalCollateralLocked, TransferPolicies, ReleaseOverflow, ReleaseQueue,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    dispatch::Pays,
+    traits::{Currency, Get, GetStorageVersion, Hooks, OnRuntimeUpgrade, ReservableCurrency, StorageVersion},
+};
+use sp_runtime::Perbill;
+
+#[test]
+fn register_asset_starts_private() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.owner, 1);
+        assert_eq!(asset.status, AssetStatus::Private);
+        assert!(asset.is_active());
+        assert!(!asset.is_locked());
+        assert!(!asset.is_approved());
+    });
+}
+
+#[test]
+fn asset_status_follows_authorization_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        // Private -> Approved
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.status, AssetStatus::Approved);
+        assert!(asset.is_approved());
+        assert!(asset.is_active());
+        System::assert_last_event(
+            Event::AssetAuthorized { asset_id, owner: 1, operator: 2, valid_until: None }.into(),
+        );
+
+        // 被授权的市场可以代理发行权证
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 1, None));
+
+        // Approved -> Private
+        assert_ok!(DataAssets::revoke_authorization(RuntimeOrigin::signed(1), asset_id, 2));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.status, AssetStatus::Private);
+        assert!(AssetApprovals::<Test>::get(asset_id, 2).is_none());
+    });
+}
+
+#[test]
+fn revoke_without_approval_is_free() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 实际撤销了授权，正常收费
+        let post_info = DataAssets::revoke_authorization(RuntimeOrigin::signed(1), asset_id, 2).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::Yes);
+
+        // 再次撤销不做任何事，不收费也不发事件
+        System::reset_events();
+        let post_info = DataAssets::revoke_authorization(RuntimeOrigin::signed(1), asset_id, 2).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn asset_status_follows_lock_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        // Private -> Locked
+        assert_ok!(DataAssets::lock_asset(RuntimeOrigin::signed(1), asset_id));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.status, AssetStatus::Locked);
+        assert!(asset.is_locked());
+        assert!(!asset.is_active());
+
+        assert_noop!(
+            DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None),
+            Error::<Test>::AssetLocked
+        );
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2),
+            Error::<Test>::AssetLocked
+        );
+
+        // Locked -> Private
+        assert_ok!(DataAssets::unlock_asset(RuntimeOrigin::signed(1), asset_id));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.status, AssetStatus::Private);
+        assert!(asset.is_active());
+    });
+}
+
+#[test]
+fn transfers_reset_approved_asset_to_private() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 市场通过链扩展转移资产后，新所有者拿到的是私有资产
+        assert_ok!(DataAssets::transfer_by_market_internal(&asset_id, &2, &3));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.owner, 3);
+        assert_eq!(asset.status, AssetStatus::Private);
+        assert!(AssetApprovals::<Test>::get(asset_id, 2).is_none());
+        assert_eq!(MockIncentiveHandler::registered_trades(), 1);
+
+        // 所有者自己转移已授权资产，同样回到私有状态
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(3), asset_id, 2, None));
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(3), asset_id, 1));
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert_eq!(asset.owner, 1);
+        assert_eq!(asset.status, AssetStatus::Private);
+        assert!(AssetApprovals::<Test>::get(asset_id, 2).is_none());
+    });
+}
+
+#[test]
+fn authorizing_an_approved_asset_fails() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 同一市场不能重复授权
+        assert_noop!(
+            DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None),
+            Error::<Test>::AlreadyAuthorized
+        );
+    });
+}
+
+#[test]
+fn stale_approval_record_counts_as_approved() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        // 只有授权记录而状态仍为 Private，也视为已授权
+        AssetApprovals::<Test>::insert(asset_id, 2, ApprovalInfo { approved_at: 1, valid_until: None });
+        ApprovalCount::<Test>::insert(asset_id, 1);
+
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert!(!asset.is_approved());
+        assert!(DataAssets::is_asset_approved(&asset_id, &asset));
+        assert_noop!(
+            DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None),
+            Error::<Test>::AlreadyAuthorized
+        );
+    });
+}
+
+#[test]
+fn transfer_by_market_requires_approval() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3),
+            Error::<Test>::NotAuthorized
+        );
+
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        // 非被授权的市场不能转移
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(3), asset_id, 3),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 3);
+    });
+}
+
+#[test]
+fn expired_authorization_blocks_market_transfer() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_noop!(
+            DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, Some(1)),
+            Error::<Test>::InvalidInput
+        );
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, Some(10)));
+
+        // 过期区块之前（含）授权有效
+        System::set_block_number(10);
+        assert!(DataAssets::is_approved_operator(&asset_id, &2));
+
+        System::set_block_number(11);
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3),
+            Error::<Test>::AuthorizationExpired
+        );
+        assert_noop!(
+            DataAssets::transfer_by_market_internal(&asset_id, &2, &3),
+            Error::<Test>::AuthorizationExpired
+        );
+    });
+}
+
+#[test]
+fn expired_authorization_can_be_cleared_or_replaced() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, Some(10)));
+        assert_noop!(
+            DataAssets::clear_expired_authorization(RuntimeOrigin::signed(3), asset_id, 2),
+            Error::<Test>::AuthorizationNotExpired
+        );
+
+        System::set_block_number(11);
+        // 过期授权不再阻止所有者重新授权
+        let asset = DataAssets::get_asset(&asset_id).unwrap();
+        assert!(!DataAssets::is_asset_approved(&asset_id, &asset));
+
+        assert_ok!(DataAssets::clear_expired_authorization(RuntimeOrigin::signed(3), asset_id, 2));
+        assert!(AssetApprovals::<Test>::get(asset_id, 2).is_none());
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().status, AssetStatus::Private);
+        System::assert_last_event(Event::AuthorizationExpiredCleared { asset_id, operator: 2 }.into());
+
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 3, None));
+        assert!(DataAssets::is_approved_operator(&asset_id, &3));
+    });
+}
+
+#[test]
+fn market_transfers_reject_invalid_recipients() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 零账户
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 0),
+            Error::<Test>::InvalidRecipient
+        );
+        assert_noop!(
+            DataAssets::transfer_by_market_internal(&asset_id, &2, &0),
+            Error::<Test>::InvalidRecipient
+        );
+        // 链上不存在的账户
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 7),
+            Error::<Test>::InvalidRecipient
+        );
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 1);
+
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 3);
+    });
+}
+
+#[test]
+fn asset_can_be_authorized_to_multiple_markets() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 3, None));
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 4, None));
+        assert_eq!(ApprovalCount::<Test>::get(asset_id), 3);
+
+        // 超过 MaxOperatorsPerAsset
+        assert_noop!(
+            DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 5, None),
+            Error::<Test>::TooManyOperators
+        );
+
+        // 撤销其中一个，资产仍处于授权状态
+        assert_ok!(DataAssets::revoke_authorization(RuntimeOrigin::signed(1), asset_id, 3));
+        assert_eq!(ApprovalCount::<Test>::get(asset_id), 2);
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().status, AssetStatus::Approved);
+        assert!(!DataAssets::is_approved_operator(&asset_id, &3));
+
+        // 任一被授权市场成交后，所有授权都被清除
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(4), asset_id, 3));
+        assert_eq!(AssetApprovals::<Test>::iter_prefix(asset_id).count(), 0);
+        assert_eq!(ApprovalCount::<Test>::get(asset_id), 0);
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 2),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn revoke_all_authorizations_resets_asset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 3, None));
+
+        assert_noop!(
+            DataAssets::revoke_all_authorizations(RuntimeOrigin::signed(2), asset_id),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(DataAssets::revoke_all_authorizations(RuntimeOrigin::signed(1), asset_id));
+
+        assert_eq!(AssetApprovals::<Test>::iter_prefix(asset_id).count(), 0);
+        assert_eq!(ApprovalCount::<Test>::get(asset_id), 0);
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().status, AssetStatus::Private);
+        System::assert_last_event(Event::AllAuthorizationsRevoked { asset_id, owner: 1, count: 2 }.into());
+    });
+}
+
+#[test]
+fn retire_asset_returns_collateral_and_removes_asset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let token_id = DataAssets::get_asset(&asset_id).unwrap().token_id;
+        let locked = AssetCollateral::<Test>::get(asset_id).unwrap().reserved_amount;
+        assert_eq!(Balances::reserved_balance(1), locked);
+
+        assert_noop!(DataAssets::retire_asset(RuntimeOrigin::signed(2), asset_id), Error::<Test>::NotOwner);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        assert_noop!(
+            DataAssets::retire_asset(RuntimeOrigin::signed(1), asset_id),
+            Error::<Test>::AssetStillApproved
+        );
+        assert_ok!(DataAssets::revoke_all_authorizations(RuntimeOrigin::signed(1), asset_id));
+
+        // 释放计划尚未开始，注销时全部质押金一次性退还
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(1), asset_id));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert!(DataAssets::get_asset(&asset_id).is_none());
+        assert!(DataAssets::get_asset_by_token_id(token_id).is_none());
+        assert!(AssetCollateral::<Test>::get(asset_id).is_none());
+        assert!(depositor_assets(1).is_empty());
+        assert!(ClaimedDataSize::<Test>::get(asset_id).is_none());
+        assert!(DataAssets::find_orphaned_collateral().is_empty());
+        assert_ok!(DataAssets::do_try_state_collateral());
+        System::assert_last_event(
+            Event::AssetRetired { asset_id, owner: 1, collateral_returned: locked }.into(),
+        );
+
+        // 队列中的释放阶段随后被跳过
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        assert_eq!(Balances::reserved_balance(1), 0);
+    });
+}
+
+#[test]
+fn retire_asset_requires_certificates_revoked() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+
+        assert_noop!(
+            DataAssets::retire_asset(RuntimeOrigin::signed(1), asset_id),
+            Error::<Test>::OutstandingCertificates
+        );
+
+        assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), asset_id));
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(1), asset_id));
+        assert!(DataAssets::get_asset(&asset_id).is_none());
+    });
+}
+
+#[test]
+fn orphaned_collateral_is_found_and_released() {
+    new_test_ext().execute_with(|| {
+        let orphan = register_test_asset(1, 1);
+        let kept = register_test_asset(1, 2);
+        let locked = AssetCollateral::<Test>::get(orphan).unwrap().reserved_amount;
+        assert!(DataAssets::find_orphaned_collateral().is_empty());
+        assert_noop!(
+            DataAssets::release_orphaned_collateral(RuntimeOrigin::root(), orphan),
+            Error::<Test>::CollateralNotOrphaned
+        );
+
+        // 资产树中的记录被删除，质押金仍被锁定
+        let child_info = sp_core::storage::ChildInfo::new_default(b":asset_trie:");
+        frame_support::storage::child::kill(&child_info, &[b"assets/".as_slice(), &orphan].concat());
+        assert_eq!(DataAssets::find_orphaned_collateral(), vec![orphan]);
+        assert!(DataAssets::do_try_state_collateral().is_err());
+
+        assert_noop!(
+            DataAssets::release_orphaned_collateral(RuntimeOrigin::signed(1), orphan),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        let reserved_before = Balances::reserved_balance(1);
+        assert_ok!(DataAssets::release_orphaned_collateral(RuntimeOrigin::root(), orphan));
+
+        assert_eq!(Balances::reserved_balance(1), reserved_before - locked);
+        assert!(AssetCollateral::<Test>::get(orphan).is_none());
+        assert_eq!(depositor_assets(1), vec![kept]);
+        assert!(DataAssets::find_orphaned_collateral().is_empty());
+        assert_ok!(DataAssets::do_try_state_collateral());
+        System::assert_last_event(
+            Event::OrphanedCollateralReleased { asset_id: orphan, depositor: 1, amount: locked }.into(),
+        );
+
+        // 之后的释放队列项因记录已删除而被跳过
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        assert_noop!(
+            DataAssets::release_orphaned_collateral(RuntimeOrigin::root(), orphan),
+            Error::<Test>::CollateralNotFound
+        );
+    });
+}
+
+#[test]
+fn asset_root_is_cached_on_initialize_and_published_on_finalize() {
+    new_test_ext().execute_with(|| {
+        register_test_asset(1, 1);
+
+        // 默认关闭：不计算也不发布
+        let base_weight = DataAssets::on_initialize(2);
+        DataAssets::on_finalize(2);
+        assert!(DataAssets::current_block_asset_root().is_none());
+
+        PublishAssetRootDigest::set(true);
+        let weight = DataAssets::on_initialize(2);
+        let root = DataAssets::compute_asset_root();
+        assert_eq!(DataAssets::cached_asset_root(), Some(root));
+        assert!(weight.ref_time() > base_weight.ref_time());
+
+        // on_finalize 之前的写入不影响已缓存的根
+        register_test_asset(1, 2);
+        DataAssets::on_finalize(2);
+        assert_eq!(DataAssets::current_block_asset_root(), Some(root));
+        assert_ne!(DataAssets::compute_asset_root(), root);
+        assert!(DataAssets::cached_asset_root().is_none());
+    });
+}
+
+#[test]
+fn try_state_checks_collateral_reservations() {
+    new_test_ext().execute_with(|| {
+        register_test_asset(1, 1);
+        register_test_asset(1, 2);
+        assert_ok!(DataAssets::do_try_state_collateral());
+
+        // 质押记录仍在，但账户的 reserve 被绕过模块释放
+        Balances::unreserve(&1, 1);
+        assert!(DataAssets::do_try_state_collateral().is_err());
+    });
+}
+
+#[test]
+fn collateral_is_billed_pro_rata_above_min_billable_size() {
+    new_test_ext().execute_with(|| {
+        const MB: u64 = 1024 * 1024;
+        let base = BaseCollateral::get();
+
+        // 小于 MinBillableSize 的资产按 1KB 计费：100 * 1KB / 1MB 向上取整为 1
+        assert_eq!(DataAssets::calculate_collateral(0), (base + 1, false, base + 1));
+        assert_eq!(DataAssets::calculate_collateral(1), (base + 1, false, base + 1));
+        // 恰好 1MB 收取完整的 CollateralPerMB
+        assert_eq!(
+            DataAssets::calculate_collateral(MB),
+            (base + CollateralPerMB::get(), false, base + CollateralPerMB::get())
+        );
+        // 超出 1MB 一个字节，差额向上取整
+        assert_eq!(
+            DataAssets::calculate_collateral(MB + 1),
+            (base + CollateralPerMB::get() + 1, false, base + CollateralPerMB::get() + 1)
+        );
+        // 超过上限时封顶
+        let (capped, over_capped, _) = DataAssets::calculate_collateral(1_000 * MB);
+        assert_eq!((capped, over_capped), (MaxCollateral::get(), true));
+    });
+}
+
+#[test]
+fn over_capped_hint_reports_uncapped_collateral_once() {
+    new_test_ext().execute_with(|| {
+        const MB: u64 = 1024 * 1024;
+        let data_size_bytes = 1_000 * MB;
+        let (capped, over_capped, total_uncapped) = DataAssets::calculate_collateral(data_size_bytes);
+        assert!(over_capped);
+        assert_eq!(total_uncapped, BaseCollateral::get() + 1_000 * CollateralPerMB::get());
+
+        System::reset_events();
+        assert_ok!(DataAssets::register_asset(
+            RuntimeOrigin::signed(1),
+            b"asset".to_vec(),
+            b"test asset".to_vec(),
+            sp_core::H256::repeat_byte(1),
+            data_size_bytes,
+        ));
+
+        let hints: Vec<_> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::DataAssets(Event::CollateralOverCappedHint {
+                    total_uncapped, capped_amount, max_collateral, ..
+                }) => Some((total_uncapped, capped_amount, max_collateral)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(hints, vec![(total_uncapped, capped, MaxCollateral::get())]);
+        assert_eq!(capped, MaxCollateral::get());
+    });
+}
+
+#[test]
+fn failed_registration_after_collateral_lock_unreserves_funds() {
+    new_test_ext().execute_with(|| {
+        // 让 token_id 分配（发生在锁定质押之后）失败
+        let child_info = sp_core::storage::ChildInfo::new_default(b":asset_trie:");
+        frame_support::storage::child::put(&child_info, b"_metadata/next_token_id", &u32::MAX);
+
+        assert_noop!(
+            DataAssets::register_asset(
+                RuntimeOrigin::signed(1),
+                b"asset".to_vec(),
+                b"test asset".to_vec(),
+                sp_core::H256::repeat_byte(1),
+                1024,
+            ),
+            Error::<Test>::TokenIdOverflow
+        );
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn certificate_root_tracks_every_certificate_mutation() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let empty_root = DataAssets::certificate_root(&asset_id);
+        assert_eq!(empty_root, DataAssets::get_certificate_root(&asset_id));
+
+        // 发行
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        let first = last_issued_certificate();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 2, None));
+        let second = last_issued_certificate();
+        let issued_root = DataAssets::certificate_root(&asset_id);
+        assert_ne!(issued_root, empty_root);
+        assert_eq!(issued_root, DataAssets::get_certificate_root(&asset_id));
+
+        // 转让
+        assert_ok!(DataAssets::transfer_certificate(RuntimeOrigin::signed(2), asset_id, first, 3));
+        assert_eq!(DataAssets::get_certificate(&asset_id, &first).unwrap().owner, 3);
+        let transferred_root = DataAssets::certificate_root(&asset_id);
+        assert_ne!(transferred_root, issued_root);
+        assert_eq!(transferred_root, DataAssets::get_certificate_root(&asset_id));
+        System::assert_last_event(
+            Event::CertificateTransferred { asset_id, certificate_id: first, from: 2, to: 3 }.into(),
+        );
+
+        // 撤销
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(1), asset_id, first));
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(2), asset_id, second));
+        assert_eq!(DataAssets::certificate_root(&asset_id), DataAssets::get_certificate_root(&asset_id));
+        assert_eq!(DataAssets::certificate_root(&asset_id), empty_root);
+    });
+}
+
+#[test]
+fn only_certificate_holder_can_transfer() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        let cert = last_issued_certificate();
+
+        assert_noop!(
+            DataAssets::transfer_certificate(RuntimeOrigin::signed(1), asset_id, cert, 3),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            DataAssets::transfer_certificate(RuntimeOrigin::signed(2), asset_id, [0u8; 32], 3),
+            Error::<Test>::CertificateNotFound
+        );
+    });
+}
+
+/// 将资产的质押记录标记为全部释放
+fn mark_collateral_fully_released(asset_id: [u8; 32]) {
+    AssetCollateral::<Test>::mutate(asset_id, |info| {
+        let info = info.as_mut().unwrap();
+        for phase in info.release_schedule.iter_mut() {
+            phase.is_released = true;
+        }
+        info.released_amount = info.total_amount;
+        info.reserved_amount = 0;
+        info.status = CollateralStatus::FullyReleased;
+    });
+}
+
+/// 每天的区块数：600 区块/小时 * 24（MILLI_SECS_PER_BLOCK = 6000）
+const BLOCKS_PER_DAY: u64 = 600 * 24;
+
+#[test]
+fn release_sweep_drops_finished_collateral_records() {
+    new_test_ext().execute_with(|| {
+        let finished = register_test_asset(1, 1);
+        let pending = register_test_asset(1, 2);
+        mark_collateral_fully_released(finished);
+
+        // 到期区块只处理队列中的两条记录，并回收已全部释放的一条
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        assert!(AssetCollateral::<Test>::get(finished).is_none());
+        assert!(AssetCollateral::<Test>::get(pending).is_some());
+        System::assert_has_event(Event::CollateralRecordReclaimed { asset_id: finished, depositor: 1 }.into());
+
+        // 后续阶段到期时，已回收的记录不再产生任何释放
+        System::reset_events();
+        DataAssets::process_collateral_releases(1 + 90 * BLOCKS_PER_DAY);
+        assert!(System::events().iter().all(|record| !matches!(
+            record.event,
+            RuntimeEvent::DataAssets(Event::CollateralReleased { asset_id, .. }) if asset_id == finished
+        )));
+    });
+}
+
+#[test]
+fn slashed_collateral_releases_only_what_is_left() {
+    new_test_ext().execute_with(|| {
+        let slashed = register_test_asset(1, 1);
+        let untouched = register_test_asset(1, 2);
+        assert_ok!(DataAssets::slash_collateral(&slashed, 80));
+        let info = AssetCollateral::<Test>::get(slashed).unwrap();
+        let left = info.reserved_amount;
+        let scheduled = info.release_schedule[0].amount;
+        assert!(left < scheduled);
+        let other_reserved = AssetCollateral::<Test>::get(untouched).unwrap().reserved_amount;
+
+        // 只解锁该资产剩余的质押，不动同一质押人其他资产的质押
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        let other_phase = AssetCollateral::<Test>::get(untouched).unwrap().release_schedule[0].amount;
+        assert_eq!(Balances::reserved_balance(1), other_reserved - other_phase);
+        let info = AssetCollateral::<Test>::get(slashed).unwrap();
+        assert!(info.release_schedule[0].is_released);
+        assert_eq!(info.reserved_amount, 0);
+        assert_eq!(info.released_amount, left);
+        System::assert_has_event(
+            Event::PartialCollateralRelease { asset_id: slashed, scheduled, released: left, phase: 50 }.into(),
+        );
+        assert_ok!(DataAssets::do_try_state_collateral());
+
+        // 后续阶段没有可解锁的质押，标记为已释放后记录被回收
+        DataAssets::process_collateral_releases(1 + 30 * BLOCKS_PER_DAY);
+        DataAssets::process_collateral_releases(1 + 90 * BLOCKS_PER_DAY);
+        assert!(AssetCollateral::<Test>::get(slashed).is_none());
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn registration_queues_each_release_phase_at_its_unlock_block() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        assert_eq!(DataAssets::release_queue(1 + BLOCKS_PER_DAY).into_inner(), vec![(asset_id, 0)]);
+        assert_eq!(DataAssets::release_queue(1 + 30 * BLOCKS_PER_DAY).into_inner(), vec![(asset_id, 1)]);
+        assert_eq!(DataAssets::release_queue(1 + 90 * BLOCKS_PER_DAY).into_inner(), vec![(asset_id, 2)]);
+    });
+}
+
+#[test]
+fn phase_that_finds_no_queue_room_is_released_from_overflow() {
+    new_test_ext().execute_with(|| {
+        // 第一阶段到期区块起连续 100 个区块的队列都已排满
+        let due = 1 + BLOCKS_PER_DAY;
+        for block in due..due + 100 {
+            ReleaseQueue::<Test>::insert(block, frame_support::BoundedVec::truncate_from(vec![([9u8; 32], 0u8); 4]));
+        }
+        let asset_id = register_test_asset(1, 1);
+        assert_eq!(ReleaseOverflow::<Test>::get((asset_id, 0)), Some(due));
+        let reserved = Balances::reserved_balance(1);
+        let phase = AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[0].clone();
+
+        // 未到期时留在溢出列表中
+        DataAssets::process_collateral_releases(due - 1);
+        assert_eq!(ReleaseOverflow::<Test>::get((asset_id, 0)), Some(due));
+
+        DataAssets::process_collateral_releases(due);
+        assert!(ReleaseOverflow::<Test>::get((asset_id, 0)).is_none());
+        assert!(AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[0].is_released);
+        assert_eq!(Balances::reserved_balance(1), reserved - phase.amount);
+    });
+}
+
+#[test]
+fn only_due_phases_are_released() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let reserved = Balances::reserved_balance(1);
+        let phase = AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[0].clone();
+
+        // 未到期的区块不处理任何阶段
+        DataAssets::process_collateral_releases(2);
+        assert_eq!(Balances::reserved_balance(1), reserved);
+
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        assert_eq!(Balances::reserved_balance(1), reserved - phase.amount);
+        assert!(DataAssets::release_queue(1 + BLOCKS_PER_DAY).is_empty());
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert!(info.release_schedule[0].is_released);
+        assert!(!info.release_schedule[1].is_released);
+        assert_eq!(info.status, CollateralStatus::PartiallyReleased);
+        System::assert_last_event(
+            Event::CollateralReleased { asset_id, amount: phase.amount, phase: 50 }.into(),
+        );
+    });
+}
+
+#[test]
+fn unmet_release_condition_is_requeued_next_day() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let due = 1 + 30 * BLOCKS_PER_DAY;
+
+        // 第二阶段要求资产有使用记录，未满足时顺延一天
+        DataAssets::process_collateral_releases(due);
+        assert!(!AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[1].is_released);
+        assert_eq!(DataAssets::release_queue(due + BLOCKS_PER_DAY).into_inner(), vec![(asset_id, 1)]);
+
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+        DataAssets::process_collateral_releases(due + BLOCKS_PER_DAY);
+        assert!(AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[1].is_released);
+    });
+}
+
+#[test]
+fn availability_attestation_requires_registered_attester() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        assert_noop!(
+            DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), asset_id, true),
+            Error::<Test>::NotAvailabilityAttester
+        );
+        assert_noop!(
+            DataAssets::set_availability_attester(RuntimeOrigin::signed(1), 2, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        assert_ok!(DataAssets::set_availability_attester(RuntimeOrigin::root(), 2, true));
+        System::assert_last_event(Event::AvailabilityAttesterSet { who: 2, enabled: true }.into());
+        assert_noop!(
+            DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), [0u8; 32], true),
+            Error::<Test>::AssetNotFound
+        );
+
+        let post_info = DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), asset_id, true).unwrap();
+        assert_eq!(post_info.pays_fee, Pays::No);
+        let attestation = DataAssets::availability_attestation(asset_id).unwrap();
+        assert_eq!((attestation.available, attestation.attester, attestation.block), (true, 2, 1));
+        System::assert_last_event(Event::AvailabilityAttested { asset_id, attester: 2, available: true }.into());
+    });
+}
+
+#[test]
+fn availability_phase_requires_recent_positive_attestation() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::set_availability_attester(RuntimeOrigin::root(), 2, true));
+        let due = 1 + 90 * BLOCKS_PER_DAY;
+
+        // 没有证明时第三阶段顺延一天
+        System::set_block_number(due);
+        DataAssets::process_collateral_releases(due);
+        assert!(!AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[2].is_released);
+        assert_eq!(DataAssets::release_queue(due + BLOCKS_PER_DAY).into_inner(), vec![(asset_id, 2)]);
+
+        // 过期的证明（超过 AvailabilityAttestationTtl）不被接受
+        let next = due + BLOCKS_PER_DAY;
+        System::set_block_number(next - 101);
+        assert_ok!(DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), asset_id, true));
+        System::set_block_number(next);
+        DataAssets::process_collateral_releases(next);
+        assert!(!AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[2].is_released);
+
+        // 否定的证明同样不能释放
+        let next = next + BLOCKS_PER_DAY;
+        System::set_block_number(next);
+        assert_ok!(DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), asset_id, false));
+        DataAssets::process_collateral_releases(next);
+        assert!(!AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[2].is_released);
+
+        let next = next + BLOCKS_PER_DAY;
+        System::set_block_number(next);
+        assert_ok!(DataAssets::submit_availability_attestation(RuntimeOrigin::signed(2), asset_id, true));
+        DataAssets::process_collateral_releases(next);
+        assert!(AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule[2].is_released);
+    });
+}
+
+#[test]
+fn offchain_worker_attests_due_availability_phase() {
+    use codec::Decode;
+    use sp_core::offchain::{
+        testing::{PendingRequest, TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+
+    let mut ext = new_test_ext();
+    let (offchain, offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let mut asset = DataAssets::get_asset(&asset_id).unwrap();
+        asset.metadata_cid = b"bafytestcid".to_vec();
+        assert_ok!(DataAssets::insert_asset(&asset_id, &asset));
+        sp_runtime::testing::UintAuthorityId::set_all_keys(vec![2u64]);
+
+        let now = 1 + 90 * BLOCKS_PER_DAY - AVAILABILITY_CHECK_LEAD;
+        assert_eq!(DataAssets::availability_checks_due(now), vec![(asset_id, b"bafytestcid".to_vec())]);
+        // 早于提前量的区块没有到期的检查
+        assert!(DataAssets::availability_checks_due(now - 1).is_empty());
+
+        offchain_state.write().expect_request(PendingRequest {
+            method: "HEAD".into(),
+            uri: [IPFS_GATEWAY, "bafytestcid"].concat(),
+            response: Some(Vec::new()),
+            sent: true,
+            ..Default::default()
+        });
+        DataAssets::offchain_worker(now);
+
+        let tx = pool_state.write().transactions.pop().expect("attestation submitted");
+        assert!(pool_state.read().transactions.is_empty());
+        let tx = Extrinsic::decode(&mut &*tx).unwrap();
+        assert_eq!(
+            tx.function,
+            RuntimeCall::DataAssets(crate::Call::submit_availability_attestation { asset_id, available: true })
+        );
+    });
+}
+
+#[test]
+fn offchain_worker_skips_attestation_for_non_utf8_cid() {
+    use sp_core::offchain::{
+        testing::{TestOffchainExt, TestTransactionPoolExt},
+        OffchainDbExt, OffchainWorkerExt, TransactionPoolExt,
+    };
+
+    let mut ext = new_test_ext();
+    let (offchain, _offchain_state) = TestOffchainExt::new();
+    let (pool, pool_state) = TestTransactionPoolExt::new();
+    ext.register_extension(OffchainDbExt::new(offchain.clone()));
+    ext.register_extension(OffchainWorkerExt::new(offchain));
+    ext.register_extension(TransactionPoolExt::new(pool));
+
+    ext.execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let mut asset = DataAssets::get_asset(&asset_id).unwrap();
+        asset.metadata_cid = vec![0xff, 0xfe, 0xfd];
+        assert_ok!(DataAssets::insert_asset(&asset_id, &asset));
+        sp_runtime::testing::UintAuthorityId::set_all_keys(vec![2u64]);
+
+        let now = 1 + 90 * BLOCKS_PER_DAY - AVAILABILITY_CHECK_LEAD;
+        assert_eq!(DataAssets::availability_checks_due(now).len(), 1);
+
+        // 不发起网关请求（未预期的请求会让测试失败），也不提交 available = false 的证明
+        DataAssets::offchain_worker(now);
+        assert!(pool_state.read().transactions.is_empty());
+    });
+}
+
+#[test]
+fn full_release_queue_spills_into_next_block() {
+    new_test_ext().execute_with(|| {
+        let limit = <Test as crate::Config>::MaxReleasesPerBlock::get() as u8;
+        let assets: Vec<[u8; 32]> = (1..=limit + 1).map(|seed| register_test_asset(1, seed)).collect();
+
+        let due = 1 + BLOCKS_PER_DAY;
+        assert_eq!(DataAssets::release_queue(due).len(), limit as usize);
+        assert_eq!(DataAssets::release_queue(due + 1).into_inner(), vec![(assets[limit as usize], 0)]);
+    });
+}
+
+#[test]
+fn reclaim_collateral_record_requires_full_release() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        assert_noop!(
+            DataAssets::reclaim_collateral_record(RuntimeOrigin::signed(2), asset_id),
+            Error::<Test>::CollateralNotFullyReleased
+        );
+        assert_noop!(
+            DataAssets::reclaim_collateral_record(RuntimeOrigin::signed(2), [0u8; 32]),
+            Error::<Test>::CollateralNotFound
+        );
+
+        mark_collateral_fully_released(asset_id);
+        assert_ok!(DataAssets::reclaim_collateral_record(RuntimeOrigin::signed(2), asset_id));
+        assert!(AssetCollateral::<Test>::get(asset_id).is_none());
+        System::assert_last_event(Event::CollateralRecordReclaimed { asset_id, depositor: 1 }.into());
+    });
+}
+
+/// 账户仍有质押记录的全部资产（按资产ID排序）
+fn depositor_assets(who: u64) -> Vec<[u8; 32]> {
+    let mut assets: Vec<[u8; 32]> = CollateralByDepositor::<Test>::iter_key_prefix(who).collect();
+    assets.sort();
+    assets
+}
+
+#[test]
+fn depositor_index_tracks_locked_collateral() {
+    new_test_ext().execute_with(|| {
+        let first = register_test_asset(1, 1);
+        let second = register_test_asset(1, 2);
+        let mut both = vec![first, second];
+        both.sort();
+        assert_eq!(depositor_assets(1), both);
+
+        let reserved = |asset_id| AssetCollateral::<Test>::get(asset_id).unwrap().reserved_amount;
+        assert_eq!(DataAssets::total_locked_collateral(&1), reserved(first) + reserved(second));
+        assert_eq!(DataAssets::total_locked_collateral(&2), 0);
+
+        // 回收记录后从索引中移除，最后一条移除时清空整个条目
+        mark_collateral_fully_released(first);
+        assert_ok!(DataAssets::reclaim_collateral_record(RuntimeOrigin::signed(2), first));
+        assert_eq!(depositor_assets(1), vec![second]);
+        assert_eq!(DataAssets::total_locked_collateral(&1), reserved(second));
+
+        mark_collateral_fully_released(second);
+        assert_ok!(DataAssets::reclaim_collateral_record(RuntimeOrigin::signed(2), second));
+        assert!(depositor_assets(1).is_empty());
+    });
+}
+
+#[test]
+fn transfer_moves_locked_collateral_to_new_owner() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let locked = AssetCollateral::<Test>::get(asset_id).unwrap().reserved_amount;
+        let seller_reserved = Balances::reserved_balance(1);
+
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+
+        // 卖方的质押金被释放，买方承接同样金额的质押
+        assert_eq!(Balances::reserved_balance(1), seller_reserved - locked);
+        assert_eq!(Balances::reserved_balance(2), locked);
+        assert_eq!(Balances::free_balance(2), INITIAL_BALANCE - locked);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.depositor, 2);
+        assert_eq!(info.reserved_amount, locked);
+        assert!(depositor_assets(1).is_empty());
+        assert_eq!(depositor_assets(2), vec![asset_id]);
+        System::assert_has_event(
+            Event::CollateralTransferred { asset_id, from: 1, to: 2, amount: locked }.into(),
+        );
+
+        // 后续阶段从新所有者的质押中释放
+        let phase = info.release_schedule[0].amount;
+        DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+        assert_eq!(Balances::reserved_balance(2), locked - phase);
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn transfer_fee_defaults_to_zero() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+        assert_eq!(Balances::free_balance(INCENTIVE_POOL), 0);
+        System::assert_has_event(Event::AssetTransferred { asset_id, from: 1, to: 2, fee: 0 }.into());
+    });
+}
+
+#[test]
+fn transfer_fee_is_paid_to_incentive_pool() {
+    new_test_ext().execute_with(|| {
+        TransferFee::set(500);
+        TransferFeeRatio::set(Perbill::from_percent(10));
+        let asset_id = register_test_asset(1, 1);
+        let mut asset = DataAssets::get_asset(&asset_id).unwrap();
+        asset.pricing_config.base_price = 1_000;
+        assert_ok!(DataAssets::insert_asset(&asset_id, &asset));
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 固定 500 + 元证价格的 10%，由发起转移的市场支付
+        let market_free = Balances::free_balance(2);
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+        assert_eq!(Balances::free_balance(INCENTIVE_POOL), 600);
+        assert_eq!(Balances::free_balance(2), market_free - 600);
+        assert_eq!(MockIncentiveHandler::registered_trades(), 1);
+        System::assert_has_event(Event::AssetTransferred { asset_id, from: 1, to: 3, fee: 600 }.into());
+    });
+}
+
+/// 注册一个元证价格为 1_000 的资产，并授权给市场 2
+fn register_priced_asset_for_market() -> [u8; 32] {
+    let asset_id = register_test_asset(1, 1);
+    let mut asset = DataAssets::get_asset(&asset_id).unwrap();
+    asset.pricing_config.base_price = 1_000;
+    assert_ok!(DataAssets::insert_asset(&asset_id, &asset));
+    assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+    asset_id
+}
+
+#[test]
+fn long_term_share_defaults_to_owner() {
+    new_test_ext().execute_with(|| {
+        LongTermShareRatio::set(Perbill::from_percent(10));
+        let asset_id = register_priced_asset_for_market();
+        assert!(DataAssets::revenue_shares(asset_id).is_none());
+
+        let owner_total = Balances::total_balance(&1);
+        let market_free = Balances::free_balance(2);
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+
+        // 未设置分成接收者，元证价格的 10% 全部归转移前的所有者
+        assert_eq!(Balances::total_balance(&1), owner_total + 100);
+        assert_eq!(Balances::free_balance(2), market_free - 100);
+        System::assert_has_event(Event::LongTermSharePaid { asset_id, payer: 2, amount: 100 }.into());
+    });
+}
+
+#[test]
+fn long_term_share_is_split_across_recipients() {
+    new_test_ext().execute_with(|| {
+        LongTermShareRatio::set(Perbill::from_percent(10));
+        let asset_id = register_priced_asset_for_market();
+
+        assert_noop!(
+            DataAssets::set_revenue_shares(RuntimeOrigin::signed(2), asset_id, vec![(5, Perbill::from_percent(30))]),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            DataAssets::set_revenue_shares(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                vec![(5, Perbill::from_percent(60)), (6, Perbill::from_percent(50))],
+            ),
+            Error::<Test>::InvalidRevenueShares
+        );
+        assert_noop!(
+            DataAssets::set_revenue_shares(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                vec![(5, Perbill::from_percent(10)), (5, Perbill::from_percent(10))],
+            ),
+            Error::<Test>::InvalidRevenueShares
+        );
+        assert_noop!(
+            DataAssets::set_revenue_shares(
+                RuntimeOrigin::signed(1),
+                asset_id,
+                (5..9).map(|who| (who, Perbill::from_percent(10))).collect(),
+            ),
+            Error::<Test>::TooManyRevenueShareRecipients
+        );
+
+        let shares = vec![(5, Perbill::from_percent(30)), (6, Perbill::from_percent(20))];
+        assert_ok!(DataAssets::set_revenue_shares(RuntimeOrigin::signed(1), asset_id, shares.clone()));
+        System::assert_last_event(Event::RevenueSharesSet { asset_id, shares }.into());
+
+        let owner_total = Balances::total_balance(&1);
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+
+        // 100 的长期分成：30% 与 20% 分给接收者，剩余 50% 归转移前的所有者
+        assert_eq!(Balances::free_balance(5), 30);
+        assert_eq!(Balances::free_balance(6), 20);
+        assert_eq!(Balances::total_balance(&1), owner_total + 50);
+
+        // 清空后恢复为全部归所有者
+        assert_ok!(DataAssets::set_revenue_shares(RuntimeOrigin::signed(3), asset_id, vec![]));
+        assert!(DataAssets::revenue_shares(asset_id).is_none());
+    });
+}
+
+#[test]
+fn transfer_requires_new_owner_to_cover_collateral() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        // 账户 4 没有余额，无法承接质押金
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 4),
+            Error::<Test>::InsufficientBalance
+        );
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 1);
+    });
+}
+
+#[test]
+fn depositor_index_does_not_limit_registrations() {
+    new_test_ext().execute_with(|| {
+        // 注册超过 MaxAssetsPerDepositor 个资产，中途注销一部分，之后仍可继续注册
+        let max = <Test as crate::Config>::MaxAssetsPerDepositor::get() as u8;
+        let mut assets = Vec::new();
+        for seed in 1..=max {
+            assets.push(register_test_asset(1, seed));
+        }
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(1), assets.remove(0)));
+        for seed in max + 1..=max * 2 {
+            assets.push(register_test_asset(1, seed));
+        }
+        assets.sort();
+        assert_eq!(depositor_assets(1), assets);
+
+        // 转让给已满一页的账户同样不受限制
+        let incoming = register_test_asset(2, max * 2 + 1);
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(2), incoming, 1));
+        assert_eq!(depositor_assets(1).len(), assets.len() + 1);
+
+        // 分页查询：每页最多 MaxAssetsPerDepositor 条，按上一页最后一个ID续读，覆盖全部资产恰好一次
+        let mut paged = Vec::new();
+        let mut start_after = None;
+        loop {
+            let page = DataAssets::locked_collateral_page(&1, start_after, u32::MAX);
+            assert!(page.len() <= max as usize);
+            let Some((last, _)) = page.last().copied() else { break };
+            paged.extend(page);
+            start_after = Some(last);
+        }
+        assert_eq!(paged.len(), assets.len() + 1);
+        let total = paged.iter().fold(0u128, |acc, (_, amount)| acc + amount);
+        assert_eq!(DataAssets::total_locked_collateral(&1), total);
+    });
+}
+
+#[test]
+fn certificate_validity_reports_window_and_expiry() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 1_000)));
+        let cert = last_issued_certificate();
+
+        assert_eq!(
+            DataAssets::certificate_validity(&asset_id, &cert),
+            Some(CertificateValidity {
+                status: CertificateStatus::Active,
+                valid_from: now,
+                valid_until: Some(now + 1_000),
+                is_valid_now: true,
+            })
+        );
+
+        // 超过有效期后报告为过期
+        Timestamp::set_timestamp(now + 1_001);
+        let validity = DataAssets::certificate_validity(&asset_id, &cert).unwrap();
+        assert_eq!(validity.status, CertificateStatus::Expired);
+        assert!(!validity.is_valid_now);
+
+        assert_eq!(DataAssets::certificate_validity(&asset_id, &[0u8; 32]), None);
+    });
+}
+
+#[test]
+fn resale_right_can_only_be_issued_by_owner() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 3, None),
+            Error::<Test>::NotAuthorized
+        );
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 3, None));
+        let cert = DataAssets::get_certificate(&asset_id, &last_issued_certificate()).unwrap();
+        assert_eq!(cert.right_type, RightType::ResaleRight);
+    });
+}
+
+#[test]
+fn sublicense_right_requires_owner_and_expiry() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        let until = Some(Timestamp::get() + 1_000);
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 4, until),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 4, None),
+            Error::<Test>::ValidityRequired
+        );
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 4, until));
+        let cert = DataAssets::get_certificate(&asset_id, &last_issued_certificate()).unwrap();
+        assert_eq!(cert.right_type, RightType::SublicenseRight);
+    });
+}
+
+#[test]
+fn time_limited_view_requires_future_expiry() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        let now = Timestamp::get();
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 5, None),
+            Error::<Test>::ValidityRequired
+        );
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 5, Some(now)),
+            Error::<Test>::ValidityRequired
+        );
+        // 市场可以代理发行限时查看权证
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 5, Some(now + 1)));
+        let cert = DataAssets::get_certificate(&asset_id, &last_issued_certificate()).unwrap();
+        assert_eq!(cert.right_type, RightType::TimeLimitedView);
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 6, None),
+            Error::<Test>::InvalidRightType
+        );
+    });
+}
+
+#[test]
+fn issue_certificates_batch_issues_all_and_updates_root_once() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        System::reset_events();
+
+        assert_ok!(DataAssets::issue_certificates_batch(
+            RuntimeOrigin::signed(1),
+            asset_id,
+            vec![(2, 1, None), (3, 2, None), (4, 5, Some(Timestamp::get() + 1))],
+        ));
+
+        let holders: Vec<u64> = System::events()
+            .into_iter()
+            .filter_map(|record| match record.event {
+                RuntimeEvent::DataAssets(Event::CertificateIssued { asset_id: id, certificate_id, holder, .. }) => {
+                    assert_eq!(id, asset_id);
+                    assert!(DataAssets::get_certificate(&asset_id, &certificate_id).is_some());
+                    Some(holder)
+                }
+                _ => None,
+            })
+            .collect();
+        assert_eq!(holders, vec![2, 3, 4]);
+        assert_eq!(DataAssets::certificate_root(&asset_id), DataAssets::get_certificate_root(&asset_id));
+    });
+}
+
+#[test]
+fn issued_certificates_are_indexed_by_issuer_and_proxy() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 所有者自己发行：只记入发行者索引
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 1, None));
+        let own = DataAssets::get_certificate(&asset_id, &last_issued_certificate()).unwrap().token_id;
+        assert!(DataAssets::certificates_by_proxy(1).is_empty());
+
+        // 市场代理发行：发行者仍是所有者，同时记入市场的代理索引
+        assert_ok!(DataAssets::issue_certificates_batch(RuntimeOrigin::signed(2), asset_id, vec![(3, 1, None)]));
+        let proxied = DataAssets::get_certificate(&asset_id, &last_issued_certificate()).unwrap().token_id;
+
+        assert_eq!(
+            DataAssets::certificates_by_issuer(1).into_inner(),
+            vec![(asset_id, own), (asset_id, proxied)]
+        );
+        assert_eq!(DataAssets::certificates_by_proxy(2).into_inner(), vec![(asset_id, proxied)]);
+        assert!(DataAssets::certificates_by_issuer(2).is_empty());
+    });
+}
+
+#[test]
+fn issuer_certificate_index_is_bounded() {
+    new_test_ext().execute_with(|| {
+        // 分散到两个资产上，避免先触发单个资产的权证上限
+        let assets = [register_test_asset(1, 1), register_test_asset(1, 2)];
+        let max = <Test as crate::Config>::MaxCertificatesPerIssuer::get();
+        for i in 0..max {
+            assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), assets[(i % 2) as usize], 2, 1, None));
+        }
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), assets[0], 2, 1, None),
+            Error::<Test>::TooManyIssuedCertificates
+        );
+    });
+}
+
+#[test]
+fn revoked_certificates_free_issuer_index_slots() {
+    new_test_ext().execute_with(|| {
+        let assets = [register_test_asset(1, 1), register_test_asset(1, 2)];
+        for asset_id in assets {
+            assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        }
+        let max = <Test as crate::Config>::MaxCertificatesPerIssuer::get();
+        let issue_all = || {
+            for i in 0..max {
+                assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), assets[(i % 2) as usize], 3, 1, None));
+            }
+        };
+
+        // 市场代理发行，填满发行者索引和代理索引
+        issue_all();
+        assert_eq!(DataAssets::certificates_by_issuer(1).len() as u32, max);
+        assert_eq!(DataAssets::certificates_by_proxy(2).len() as u32, max);
+
+        // 撤销全部权证后两个索引都被清空，可以再次发行满额
+        for asset_id in assets {
+            assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), asset_id));
+        }
+        assert!(DataAssets::certificates_by_issuer(1).is_empty());
+        assert!(DataAssets::certificates_by_proxy(2).is_empty());
+        assert_eq!(CertificateProxy::<Test>::iter().count(), 0);
+        issue_all();
+
+        // 单个撤销同样释放一个名额
+        let asset_id = assets[((max - 1) % 2) as usize];
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(1), asset_id, last_issued_certificate()));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 1, None));
+        assert_eq!(DataAssets::certificates_by_issuer(1).len() as u32, max);
+        assert_eq!(DataAssets::certificates_by_proxy(2).len() as u32, max);
+    });
+}
+
+#[test]
+fn expired_certificates_leave_issuer_index_until_extended() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        let valid_until = Timestamp::get() + 10;
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), asset_id, 3, 1, Some(valid_until)));
+        let cert = last_issued_certificate();
+        let token_id = DataAssets::get_certificate(&asset_id, &cert).unwrap().token_id;
+
+        Timestamp::set_timestamp((valid_until / crate::EXPIRY_BUCKET_MILLIS + 1) * crate::EXPIRY_BUCKET_MILLIS);
+        DataAssets::on_initialize(2);
+        assert_eq!(DataAssets::get_certificate(&asset_id, &cert).unwrap().status, CertificateStatus::Expired);
+        assert!(DataAssets::certificates_by_issuer(1).is_empty());
+        assert!(DataAssets::certificates_by_proxy(2).is_empty());
+
+        // 延期后重新生效，按原代理市场记回索引
+        assert_ok!(DataAssets::extend_certificate(RuntimeOrigin::signed(1), asset_id, cert, Timestamp::get() + 100));
+        assert_eq!(DataAssets::certificates_by_issuer(1).into_inner(), vec![(asset_id, token_id)]);
+        assert_eq!(DataAssets::certificates_by_proxy(2).into_inner(), vec![(asset_id, token_id)]);
+    });
+}
+
+#[test]
+fn certificates_per_asset_are_capped() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let max = <Test as crate::Config>::MaxCertificatesPerAsset::get();
+        for _ in 0..max {
+            assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        }
+        assert_eq!(CertificateCounts::<Test>::get(asset_id), max);
+
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None),
+            Error::<Test>::CertificateLimitReached
+        );
+
+        // 撤销一个后腾出名额
+        let certificate_id = last_issued_certificate();
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(1), asset_id, certificate_id));
+        assert_eq!(CertificateCounts::<Test>::get(asset_id), max - 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+    });
+}
+
+#[test]
+fn certificate_validity_must_be_within_configured_window() {
+    new_test_ext().execute_with(|| {
+        MinCertificateValidity::set(100);
+        MaxCertificateValidity::set(1_000);
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+
+        // 过去或短于最小有效期
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now - 1)),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 99)),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        // 两个边界均可发行
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 100)));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 1_000)));
+        // 超过最大有效期
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 1_001)),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        // 批量发行同样检查
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(1), asset_id, vec![(2, 1, Some(now + 1_001))]),
+            Error::<Test>::InvalidValidityPeriod
+        );
+    });
+}
+
+#[test]
+fn perpetual_certificates_require_flag() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+
+        AllowPerpetualCertificates::set(false);
+        assert_noop!(
+            DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        assert_ok!(DataAssets::issue_certificate(
+            RuntimeOrigin::signed(1),
+            asset_id,
+            2,
+            1,
+            Some(Timestamp::get() + 1_000)
+        ));
+    });
+}
+
+#[test]
+fn revoke_all_certificates_paginates_and_updates_root() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let empty_root = DataAssets::get_certificate_root(&asset_id);
+        for _ in 0..6 {
+            assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        }
+
+        assert_noop!(
+            DataAssets::revoke_all_certificates(RuntimeOrigin::signed(2), asset_id),
+            Error::<Test>::NotOwner
+        );
+
+        // 每次最多撤销 MaxBatchSize (5) 个
+        System::reset_events();
+        assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), asset_id));
+        System::assert_last_event(
+            Event::AllCertificatesRevoked { asset_id, owner: 1, count: 5, remaining: 1 }.into(),
+        );
+        let revoked = System::events()
+            .iter()
+            .filter(|record| matches!(record.event, RuntimeEvent::DataAssets(Event::CertificateRevoked { .. })))
+            .count();
+        assert_eq!(revoked, 5);
+        assert_eq!(CertificateCounts::<Test>::get(asset_id), 1);
+        assert_eq!(DataAssets::count_certificates(&asset_id), 1);
+
+        assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), asset_id));
+        System::assert_last_event(
+            Event::AllCertificatesRevoked { asset_id, owner: 1, count: 1, remaining: 0 }.into(),
+        );
+        assert!(!CertificateCounts::<Test>::contains_key(asset_id));
+        assert_eq!(DataAssets::certificate_root(&asset_id), empty_root);
+    });
+}
+
+#[test]
+fn transfer_can_require_certificates_revoked() {
+    new_test_ext().execute_with(|| {
+        RequireCertificatesRevokedOnTransfer::set(true);
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 3),
+            Error::<Test>::OutstandingCertificates
+        );
+        assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), asset_id));
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 3));
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 3);
+    });
+}
+
+#[test]
+fn issue_certificates_batch_rolls_back_on_failure() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 第二个权证类型无效，第一个已写入的权证也要回滚
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(1), asset_id, vec![(2, 1, None), (3, 9, None)]),
+            Error::<Test>::InvalidRightType
+        );
+        // 市场不能代理发行只有所有者才能发行的权证
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(2), asset_id, vec![(3, 1, None), (3, 3, None)]),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(3), asset_id, vec![(3, 1, None)]),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(1), asset_id, vec![]),
+            Error::<Test>::InvalidInput
+        );
+
+        let max: u32 = <Test as crate::Config>::MaxBatchSize::get();
+        let oversized = (0..=max).map(|_| (2u64, 1u8, None)).collect();
+        assert_noop!(
+            DataAssets::issue_certificates_batch(RuntimeOrigin::signed(1), asset_id, oversized),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn asset_health_aggregates_asset_state() {
+    new_test_ext().execute_with(|| {
+        assert!(DataAssets::asset_health(&[0u8; 32]).is_none());
+
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 3, 2, None));
+
+        let collateral = AssetCollateral::<Test>::get(asset_id).unwrap();
+        let health = DataAssets::asset_health(&asset_id).unwrap();
+        assert_eq!(health.owner, 1);
+        assert!(!health.is_locked);
+        assert_eq!(health.approved_markets, 1);
+        assert_eq!(health.collateral_status, Some(CollateralStatus::FullyLocked));
+        assert_eq!(health.collateral_reserved, collateral.reserved_amount);
+        assert_eq!(health.next_release_block, Some(collateral.release_schedule[0].unlock_block));
+        assert_eq!(health.certificate_count, 2);
+        // mock 未接入存储模块
+        assert_eq!(health.replica_count, 0);
+        assert_eq!(health.last_proof_block, None);
+    });
+}
+
+#[test]
+fn storage_version_is_set_and_migration_is_versioned() {
+    new_test_ext().execute_with(|| {
+        // 创世时直接写入代码中的版本
+        assert_eq!(DataAssets::on_chain_storage_version(), StorageVersion::new(3));
+        assert_eq!(DataAssets::in_code_storage_version(), StorageVersion::new(3));
+
+        // 模拟未声明版本的旧链：迁移把版本升到 v1
+        StorageVersion::new(0).put::<DataAssets>();
+        crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(DataAssets::on_chain_storage_version(), StorageVersion::new(1));
+
+        // 已是 v1 时再次执行不做任何事
+        let weight = crate::migrations::v1::MigrateV0ToV1::<Test>::on_runtime_upgrade();
+        assert_eq!(weight, <Test as frame_system::Config>::DbWeight::get().reads(1));
+        assert_eq!(DataAssets::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
+
+#[test]
+fn ecosystem_counters_track_assets_certificates_and_collateral() {
+    new_test_ext().execute_with(|| {
+        let reserved_sum = || {
+            AssetCollateral::<Test>::iter_values().map(|info| info.reserved_amount).sum::<u128>()
+        };
+
+        let first = register_test_asset(1, 1);
+        let second = register_test_asset(2, 2);
+        assert_eq!(DataAssets::total_assets(), 2);
+        assert_eq!(DataAssets::total_collateral_locked(), reserved_sum());
+        assert!(DataAssets::total_collateral_locked() > 0);
+
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), first, 2, 1, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), first, 3, 1, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), second, 1, 1, None));
+        assert_eq!(DataAssets::total_certificates(), 3);
+
+        assert_ok!(DataAssets::revoke_all_certificates(RuntimeOrigin::signed(1), first));
+        assert_eq!(DataAssets::total_certificates(), 1);
+
+        // 注销资产后资产数和锁定的质押金同步减少
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(1), first));
+        assert_eq!(DataAssets::total_assets(), 1);
+        assert_eq!(DataAssets::total_collateral_locked(), reserved_sum());
+        assert_eq!(DataAssets::total_collateral_locked(), Balances::reserved_balance(2));
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn stats_migration_backfills_counters() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        register_test_asset(2, 2);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        let locked = DataAssets::total_collateral_locked();
+
+        // 模拟升级前的 v1 链：计数器均未写入，CertificateCounts 也尚未回填
+        TotalAssets::<Test>::kill();
+        TotalCertificates::<Test>::kill();
+        TotalCollateralLocked::<Test>::kill();
+        CertificateCounts::<Test>::remove(asset_id);
+        StorageVersion::new(1).put::<DataAssets>();
+
+        crate::migrations::v2::MigrateV1ToV2::<Test>::on_runtime_upgrade();
+        assert_eq!(DataAssets::on_chain_storage_version(), StorageVersion::new(2));
+        assert_eq!(DataAssets::total_assets(), 2);
+        assert_eq!(DataAssets::total_certificates(), 1);
+        assert_eq!(DataAssets::total_collateral_locked(), locked);
+    });
+}
+
+#[test]
+fn certificate_count_migration_backfills_counts() {
+    new_test_ext().execute_with(|| {
+        let first = register_test_asset(1, 1);
+        let second = register_test_asset(2, 2);
+        let third = register_test_asset(3, 3);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), first, 2, 1, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), first, 3, 1, None));
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(2), second, 1, 1, None));
+
+        // 模拟计数器引入前的 v2 链：计数缺失或与实际不符
+        CertificateCounts::<Test>::remove(first);
+        CertificateCounts::<Test>::insert(second, 5);
+        CertificateCounts::<Test>::insert(third, 1);
+        StorageVersion::new(2).put::<DataAssets>();
+
+        crate::migrations::v3::BackfillCertificateCounts::<Test>::on_runtime_upgrade();
+        assert_eq!(DataAssets::on_chain_storage_version(), StorageVersion::new(3));
+        assert_eq!(CertificateCounts::<Test>::get(first), 2);
+        assert_eq!(CertificateCounts::<Test>::get(second), 1);
+        assert!(!CertificateCounts::<Test>::contains_key(third));
+    });
+}
+
+#[test]
+fn key_events_are_indexed_by_asset_and_account() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let registered = System::events().pop().unwrap();
+        assert_eq!(registered.topics, vec![DataAssets::event_topic(&asset_id), DataAssets::event_topic(&1u64)]);
+
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 3));
+
+        // 按持有者主题可以直接找到权证发行事件，按资产主题找到全部三条事件
+        let holder_events = System::event_topics(DataAssets::event_topic(&2u64));
+        assert_eq!(holder_events.len(), 1);
+        assert_eq!(System::event_topics(DataAssets::event_topic(&asset_id)).len(), 3);
+        assert_eq!(System::event_topics(DataAssets::event_topic(&3u64)).len(), 1);
+    });
+}
+
+#[test]
+fn under_reported_size_is_recollateralized() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_eq!(ClaimedDataSize::<Test>::get(asset_id), Some(1024));
+        let locked = AssetCollateral::<Test>::get(asset_id).unwrap().total_amount;
+
+        assert_noop!(
+            DataAssets::report_size_mismatch(RuntimeOrigin::signed(1), asset_id, 100 * 1024 * 1024),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_noop!(
+            DataAssets::report_size_mismatch(RuntimeOrigin::root(), asset_id, 1024),
+            Error::<Test>::SizeNotUnderReported
+        );
+
+        // 实际 100MB：2_000 + 100 * 100 = 12_000
+        let reserved_before = Balances::reserved_balance(1);
+        assert_ok!(DataAssets::report_size_mismatch(RuntimeOrigin::root(), asset_id, 100 * 1024 * 1024));
+        let top_up = 12_000 - locked;
+        assert_eq!(Balances::reserved_balance(1), reserved_before + top_up);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.total_amount, 12_000);
+        assert_eq!(ClaimedDataSize::<Test>::get(asset_id), Some(100 * 1024 * 1024));
+        System::assert_last_event(
+            Event::DataSizeMisreported {
+                asset_id,
+                claimed_size: 1024,
+                actual_size: 100 * 1024 * 1024,
+                top_up,
+                slashed: false,
+            }
+            .into(),
+        );
+    });
+}
+
+/// 依次满足注册时三个释放阶段的条件并处理各自的到期区块（通过转让资产满足使用条件）
+fn run_registration_schedule(asset_id: [u8; 32]) {
+    DataAssets::process_collateral_releases(1 + BLOCKS_PER_DAY);
+    assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+    DataAssets::process_collateral_releases(1 + 30 * BLOCKS_PER_DAY);
+    let due = 1 + 90 * BLOCKS_PER_DAY;
+    System::set_block_number(due);
+    assert_ok!(DataAssets::set_availability_attester(RuntimeOrigin::root(), 3, true));
+    assert_ok!(DataAssets::submit_availability_attestation(RuntimeOrigin::signed(3), asset_id, true));
+    DataAssets::process_collateral_releases(due);
+}
+
+/// 注册时释放计划之外一直保留的质押
+fn retained_collateral(info: &crate::types::CollateralInfo<u64, u128, u64>) -> u128 {
+    info.total_amount - info.release_schedule.iter().map(|phase| phase.amount).sum::<u128>()
+}
+
+#[test]
+fn recollateralized_top_up_is_released_with_the_last_phase() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let before = AssetCollateral::<Test>::get(asset_id).unwrap();
+        let retained = retained_collateral(&before);
+
+        assert_ok!(DataAssets::report_size_mismatch(RuntimeOrigin::root(), asset_id, 100 * 1024 * 1024));
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        let top_up = info.total_amount - before.total_amount;
+        assert_eq!(info.release_schedule.len(), 3);
+        assert_eq!(info.release_schedule[2].amount, before.release_schedule[2].amount + top_up);
+        assert_eq!(retained_collateral(&info), retained);
+
+        // 全部阶段释放后，补足的质押已全部解锁，只剩注册时保留的部分
+        run_registration_schedule(asset_id);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert!(info.release_schedule.iter().all(|phase| phase.is_released));
+        assert_eq!(info.reserved_amount, retained);
+        assert_eq!(info.released_amount, info.total_amount - retained);
+        assert_eq!(Balances::reserved_balance(2), retained);
+        System::assert_has_event(
+            Event::CollateralReleased { asset_id, amount: before.release_schedule[2].amount + top_up, phase: 20 }.into(),
+        );
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn top_up_after_the_schedule_gets_its_own_phase() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        run_registration_schedule(asset_id);
+        let before = AssetCollateral::<Test>::get(asset_id).unwrap();
+
+        // 原有阶段均已释放时，补足的质押单独排入 90 天后释放的阶段
+        assert_ok!(DataAssets::report_size_mismatch(RuntimeOrigin::root(), asset_id, 100 * 1024 * 1024));
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        let top_up = info.total_amount - before.total_amount;
+        let phase = info.release_schedule[3].clone();
+        assert_eq!((phase.amount, phase.condition), (top_up, crate::types::ReleaseCondition::TimeAndAvailability));
+        assert_eq!(DataAssets::release_queue(phase.unlock_block).into_inner(), vec![(asset_id, 3)]);
+
+        System::set_block_number(phase.unlock_block);
+        assert_ok!(DataAssets::submit_availability_attestation(RuntimeOrigin::signed(3), asset_id, true));
+        DataAssets::process_collateral_releases(phase.unlock_block);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.reserved_amount, before.reserved_amount);
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn under_reported_size_slashes_when_top_up_fails() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let locked = AssetCollateral::<Test>::get(asset_id).unwrap().reserved_amount;
+        // 质押人没有余额补足差额
+        Balances::make_free_balance_be(&1, 1);
+
+        assert_ok!(DataAssets::report_size_mismatch(RuntimeOrigin::root(), asset_id, 100 * 1024 * 1024));
+
+        assert_eq!(Balances::reserved_balance(1), 0);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.status, CollateralStatus::Slashed(locked));
+        System::assert_last_event(
+            Event::DataSizeMisreported {
+                asset_id,
+                claimed_size: 1024,
+                actual_size: 100 * 1024 * 1024,
+                top_up: 0,
+                slashed: true,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn recalculated_collateral_requires_a_top_up() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_eq!(AssetCollateral::<Test>::get(asset_id).unwrap().total_amount, 2_001);
+
+        // 治理提高费率：1KB 的资产需要 2_000 + 3_000 = 5_000
+        CollateralPerMB::set(3_072_000);
+        assert_noop!(
+            DataAssets::recalculate_collateral(RuntimeOrigin::signed(1), asset_id),
+            sp_runtime::DispatchError::BadOrigin
+        );
+
+        let reserved_before = Balances::reserved_balance(1);
+        assert_ok!(DataAssets::recalculate_collateral(RuntimeOrigin::root(), asset_id));
+        assert_eq!(Balances::reserved_balance(1), reserved_before + 2_999);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.total_amount, 5_000);
+        assert_eq!(info.reserved_amount, 5_000);
+        let retained = retained_collateral(&info);
+        assert_eq!(retained, 2_001 - 800);
+        System::assert_last_event(
+            Event::CollateralRecalculated {
+                asset_id,
+                previous: 2_001,
+                required: 5_000,
+                top_up: 2_999,
+                scheduled_release: 0,
+            }
+            .into(),
+        );
+
+        // 质押人余额不足时不能补足
+        CollateralPerMB::set(6_144_000);
+        let free = Balances::free_balance(1);
+        Balances::make_free_balance_be(&1, 1);
+        assert_noop!(
+            DataAssets::recalculate_collateral(RuntimeOrigin::root(), asset_id),
+            Error::<Test>::InsufficientBalance
+        );
+        Balances::make_free_balance_be(&1, free);
+
+        // 补足的质押随最后一个阶段释放，全部阶段释放后只剩注册时保留的部分
+        run_registration_schedule(asset_id);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert!(info.release_schedule.iter().all(|phase| phase.is_released));
+        assert_eq!(info.reserved_amount, retained);
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn recalculated_collateral_releases_the_excess() {
+    new_test_ext().execute_with(|| {
+        CollateralPerMB::set(3_072_000);
+        let asset_id = register_test_asset(1, 1);
+        assert_eq!(Balances::reserved_balance(1), 5_000);
+
+        // 治理降低费率，多出的 2_999 在下一个区块释放
+        CollateralPerMB::set(100);
+        assert_ok!(DataAssets::recalculate_collateral(RuntimeOrigin::root(), asset_id));
+        System::assert_last_event(
+            Event::CollateralRecalculated {
+                asset_id,
+                previous: 5_000,
+                required: 2_001,
+                top_up: 0,
+                scheduled_release: 2_999,
+            }
+            .into(),
+        );
+        assert_eq!(Balances::reserved_balance(1), 5_000);
+
+        System::set_block_number(2);
+        DataAssets::on_initialize(2);
+        assert_eq!(Balances::reserved_balance(1), 2_001);
+        let info = AssetCollateral::<Test>::get(asset_id).unwrap();
+        assert_eq!(info.reserved_amount, 2_001);
+        assert_eq!(info.status, CollateralStatus::PartiallyReleased);
+        System::assert_has_event(Event::CollateralReleased { asset_id, amount: 2_999, phase: 0 }.into());
+
+        // 已按当前参数调整过，再次计算不会重复释放
+        assert_ok!(DataAssets::recalculate_collateral(RuntimeOrigin::root(), asset_id));
+        assert_eq!(AssetCollateral::<Test>::get(asset_id).unwrap().release_schedule.len(), 4);
+        assert_ok!(DataAssets::do_try_state_collateral());
+    });
+}
+
+#[test]
+fn exercising_a_certificate_records_the_usage() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+
+        assert_noop!(
+            DataAssets::exercise_certificate(RuntimeOrigin::signed(1), asset_id, cert),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert));
+        assert_ok!(DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert));
+        assert_eq!(DownloadCount::<Test>::get(asset_id), 2);
+        assert_eq!(DataAssets::get_certificate(&asset_id, &cert).unwrap().nonce, 2);
+        assert_eq!(DataAssets::certificate_root(&asset_id), DataAssets::get_certificate_root(&asset_id));
+        System::assert_last_event(
+            Event::CertificateExercised { asset_id, certificate_id: cert, holder: 2, remaining: None }.into(),
+        );
+
+        // 过期的权证不能再行权
+        Timestamp::set_timestamp(now + 11);
+        assert_noop!(
+            DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert),
+            Error::<Test>::CertificateNotActive
+        );
+
+        // 转售权不是使用权，不能行权
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 3, None));
+        assert_noop!(
+            DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, last_issued_certificate()),
+            Error::<Test>::RightNotExercisable
+        );
+    });
+}
+
+#[test]
+fn usage_quota_is_consumed_by_exercise() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 2, None));
+        let cert = last_issued_certificate();
+
+        assert_noop!(
+            DataAssets::set_usage_quota(RuntimeOrigin::signed(2), asset_id, cert, Some(1)),
+            Error::<Test>::NotOwner
+        );
+        assert_ok!(DataAssets::set_usage_quota(RuntimeOrigin::signed(1), asset_id, cert, Some(1)));
+
+        assert_ok!(DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert));
+        System::assert_last_event(
+            Event::CertificateExercised { asset_id, certificate_id: cert, holder: 2, remaining: Some(0) }.into(),
+        );
+        assert_noop!(
+            DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert),
+            Error::<Test>::UsageQuotaExhausted
+        );
+        assert_eq!(DownloadCount::<Test>::get(asset_id), 1);
+
+        // 撤销权证时一并清除额度记录
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(1), asset_id, cert));
+        assert_eq!(CertificateUsageQuota::<Test>::get(asset_id, cert), None);
+    });
+}
+
+#[test]
+fn revoking_an_unexercised_certificate_refunds_the_issuance_fee() {
+    new_test_ext().execute_with(|| {
+        CertificateIssuanceFee::set(50);
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        let free_before = Balances::free_balance(1);
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+        assert_eq!(Balances::free_balance(1), free_before - 50);
+        assert_eq!(Balances::free_balance(EXERCISE_FEE_POOL), 50);
+        assert_eq!(DataAssets::issuance_fee_escrow(asset_id, cert), Some((1, 50)));
+
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(2), asset_id, cert));
+        assert_eq!(Balances::free_balance(1), free_before);
+        assert_eq!(Balances::free_balance(EXERCISE_FEE_POOL), 0);
+        assert_eq!(DataAssets::issuance_fee_escrow(asset_id, cert), None);
+        System::assert_has_event(
+            Event::IssuanceFeeRefunded { asset_id, certificate_id: cert, issuer: 1, amount: 50 }.into(),
+        );
+    });
+}
+
+#[test]
+fn revoking_an_exercised_certificate_forfeits_the_issuance_fee() {
+    new_test_ext().execute_with(|| {
+        CertificateIssuanceFee::set(50);
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+        let free_before = Balances::free_balance(1);
+
+        assert_ok!(DataAssets::exercise_certificate(RuntimeOrigin::signed(2), asset_id, cert));
+        assert_eq!(DataAssets::issuance_fee_escrow(asset_id, cert), None);
+
+        assert_ok!(DataAssets::revoke_certificate(RuntimeOrigin::signed(1), asset_id, cert));
+        assert_eq!(Balances::free_balance(1), free_before);
+        assert_eq!(Balances::free_balance(EXERCISE_FEE_POOL), 50);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::DataAssets(Event::IssuanceFeeRefunded { .. })
+        )));
+    });
+}
+
+#[test]
+fn extending_an_active_certificate_updates_validity_and_root() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+        let root_before = DataAssets::certificate_root(&asset_id);
+
+        // 持有者不能自行延期
+        assert_noop!(
+            DataAssets::extend_certificate(RuntimeOrigin::signed(2), asset_id, cert, now + 100),
+            Error::<Test>::NotAuthorized
+        );
+        assert_noop!(
+            DataAssets::extend_certificate(RuntimeOrigin::signed(1), asset_id, cert, now + MaxCertificateValidity::get() + 1),
+            Error::<Test>::InvalidValidityPeriod
+        );
+
+        assert_ok!(DataAssets::extend_certificate(RuntimeOrigin::signed(1), asset_id, cert, now + 100));
+        let extended = DataAssets::get_certificate(&asset_id, &cert).unwrap();
+        assert_eq!(extended.valid_until, Some(now + 100));
+        assert_eq!(extended.nonce, 1);
+        assert_ne!(DataAssets::certificate_root(&asset_id), root_before);
+        assert_eq!(DataAssets::certificate_root(&asset_id), DataAssets::get_certificate_root(&asset_id));
+        System::assert_last_event(
+            Event::CertificateExtended { asset_id, certificate_id: cert, valid_until: now + 100 }.into(),
+        );
+    });
+}
+
+#[test]
+fn extending_an_expired_certificate_reactivates_it() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 3, None));
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(3), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+
+        Timestamp::set_timestamp(now + 20);
+        assert_eq!(DataAssets::certificate_validity(&asset_id, &cert).unwrap().status, CertificateStatus::Expired);
+
+        // 新的有效期必须覆盖当前时间
+        assert_noop!(
+            DataAssets::extend_certificate(RuntimeOrigin::signed(3), asset_id, cert, now + 15),
+            Error::<Test>::InvalidValidityPeriod
+        );
+        // 被授权的市场也可以延期，权证ID保持不变
+        assert_ok!(DataAssets::extend_certificate(RuntimeOrigin::signed(3), asset_id, cert, now + 100));
+        let validity = DataAssets::certificate_validity(&asset_id, &cert).unwrap();
+        assert_eq!(validity.status, CertificateStatus::Active);
+        assert_eq!(validity.valid_until, Some(now + 100));
+        assert_ok!(DataAssets::transfer_certificate(RuntimeOrigin::signed(2), asset_id, cert, 3));
+    });
+}
+
+#[test]
+fn expiration_sweep_processes_a_full_bucket_across_blocks() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        let valid_until = now + 10;
+        for holder in 0..6u64 {
+            assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 10 + holder, 1, Some(valid_until)));
+        }
+        let bucket = valid_until / crate::EXPIRY_BUCKET_MILLIS;
+        assert_eq!(ExpiryQueue::<Test>::get(bucket).len(), 6);
+
+        // 桶尚未结束时不处理
+        DataAssets::on_initialize(2);
+        assert_eq!(ExpiryQueue::<Test>::get(bucket).len(), 6);
+
+        // 每个区块最多处理 MaxExpirationsPerBlock (2) 个，剩余的留到下一个区块
+        Timestamp::set_timestamp((bucket + 1) * crate::EXPIRY_BUCKET_MILLIS);
+        System::reset_events();
+        for (block, remaining) in [(3u64, 4usize), (4, 2), (5, 0)] {
+            let weight = DataAssets::on_initialize(block);
+            assert!(weight.ref_time() > 0);
+            assert_eq!(ExpiryQueue::<Test>::get(bucket).len(), remaining);
+        }
+        let expired = System::events()
+            .iter()
+            .filter(|record| matches!(record.event, RuntimeEvent::DataAssets(Event::CertificateExpired { .. })))
+            .count();
+        assert_eq!(expired, 6);
+        assert_eq!(NextExpiryBucket::<Test>::get(), Some(bucket + 1));
+        assert_eq!(DataAssets::certificate_root(&asset_id), DataAssets::get_certificate_root(&asset_id));
+    });
+}
+
+#[test]
+fn expiration_sweep_skips_extended_certificates() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let now = Timestamp::get();
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, Some(now + 10)));
+        let cert = last_issued_certificate();
+        let later = now + 2 * crate::EXPIRY_BUCKET_MILLIS;
+        assert_ok!(DataAssets::extend_certificate(RuntimeOrigin::signed(1), asset_id, cert, later));
+
+        Timestamp::set_timestamp(now + crate::EXPIRY_BUCKET_MILLIS);
+        DataAssets::on_initialize(2);
+        assert_eq!(DataAssets::get_certificate(&asset_id, &cert).unwrap().status, CertificateStatus::Active);
+
+        // 延期后排入的新桶到期后才标记
+        Timestamp::set_timestamp(later + crate::EXPIRY_BUCKET_MILLIS);
+        DataAssets::on_initialize(3);
+        assert_eq!(DataAssets::get_certificate(&asset_id, &cert).unwrap().status, CertificateStatus::Expired);
+    });
+}
+
+#[test]
+fn only_market_proxied_issuance_rewards_the_operator() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 3, None));
+
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(1), asset_id, 2, 1, None));
+        assert!(MockIncentiveHandler::rewarded_operators().is_empty());
+
+        assert_ok!(DataAssets::issue_certificate(RuntimeOrigin::signed(3), asset_id, 2, 1, None));
+        assert_eq!(MockIncentiveHandler::rewarded_operators(), vec![3]);
+    });
+}
+
+/// 测试用的质押角色，模拟 pallet-collaterals 的 CollateralRole
+#[derive(Clone, Copy)]
+enum TestRole {
+    DataCreator,
+    MarketOperator,
+}
+
+impl pallet_shared_traits::CollateralRoleKind for TestRole {
+    fn is_data_creator(&self) -> bool {
+        matches!(self, TestRole::DataCreator)
+    }
+}
+
+#[test]
+fn creator_collateral_slash_notifies_with_affected_assets() {
+    use pallet_shared_traits::SlashNotificationHandler;
+
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        System::reset_events();
+
+        // 其他角色的罚没不影响资产
+        <DataAssets as SlashNotificationHandler<u64, TestRole, u128>>::on_collateral_slashed(&1, TestRole::MarketOperator, 500);
+        assert!(System::events().is_empty());
+
+        <DataAssets as SlashNotificationHandler<u64, TestRole, u128>>::on_collateral_slashed(&1, TestRole::DataCreator, 500);
+        System::assert_last_event(
+            Event::CreatorCollateralSlashed {
+                depositor: 1,
+                amount: 500,
+                assets: vec![asset_id].try_into().unwrap(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn registration_rate_limit_boundary() {
+    new_test_ext().execute_with(|| {
+        MinBlocksBetweenRegistrations::set(5);
+        register_test_asset(1, 1);
+        assert_eq!(DataAssets::last_registration(1), Some(1));
+
+        // 间隔不足 5 个区块时拒绝
+        System::set_block_number(5);
+        assert_noop!(
+            DataAssets::register_asset(RuntimeOrigin::signed(1), b"asset".to_vec(), b"test asset".to_vec(), sp_core::H256::repeat_byte(2), 1024),
+            Error::<Test>::RegistrationRateLimited
+        );
+        // 其他账户不受影响
+        register_test_asset(2, 2);
+
+        // 恰好间隔 5 个区块时允许
+        System::set_block_number(6);
+        register_test_asset(1, 3);
+        assert_eq!(DataAssets::last_registration(1), Some(6));
+    });
+}
+
+#[test]
+fn exempt_accounts_bypass_registration_rate_limit() {
+    new_test_ext().execute_with(|| {
+        MinBlocksBetweenRegistrations::set(5);
+        assert_noop!(
+            DataAssets::set_registration_exempt(RuntimeOrigin::signed(1), 1, true),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(DataAssets::set_registration_exempt(RuntimeOrigin::root(), 1, true));
+        System::assert_last_event(Event::RegistrationExemptionSet { who: 1, exempt: true }.into());
+
+        register_test_asset(1, 1);
+        register_test_asset(1, 2);
+
+        // 移出豁免名单后重新受限
+        assert_ok!(DataAssets::set_registration_exempt(RuntimeOrigin::root(), 1, false));
+        assert!(!DataAssets::is_registration_exempt(1));
+        assert_noop!(
+            DataAssets::register_asset(RuntimeOrigin::signed(1), b"asset".to_vec(), b"test asset".to_vec(), sp_core::H256::repeat_byte(3), 1024),
+            Error::<Test>::RegistrationRateLimited
+        );
+    });
+}
+
+#[test]
+fn lineage_records_transfers_and_prunes_oldest() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert!(DataAssets::asset_lineage(asset_id).is_empty());
+
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+        System::set_block_number(2);
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(2), asset_id, 3, None));
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(3), asset_id, 1));
+        assert_eq!(
+            DataAssets::asset_lineage(asset_id).to_vec(),
+            vec![
+                LineageEntry::Transfer { from: 1, to: 2, block: 1 },
+                LineageEntry::Transfer { from: 2, to: 1, block: 2 },
+            ]
+        );
+
+        // 超过 MaxLineageDepth（3）时丢弃最早的记录
+        System::set_block_number(3);
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 3));
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(3), asset_id, 2));
+        assert_eq!(
+            DataAssets::asset_lineage(asset_id).to_vec(),
+            vec![
+                LineageEntry::Transfer { from: 2, to: 1, block: 2 },
+                LineageEntry::Transfer { from: 1, to: 3, block: 3 },
+                LineageEntry::Transfer { from: 3, to: 2, block: 3 },
+            ]
+        );
+    });
+}
+
+#[test]
+fn required_collateral_matches_locked_amount() {
+    new_test_ext().execute_with(|| {
+        // 未封顶与封顶两种数据大小
+        for (seed, data_size_bytes) in [(1u8, 5 * 1024 * 1024u64), (2, 1024 * 1024 * 1024)] {
+            let (required, capped, _) = DataAssets::calculate_collateral(data_size_bytes);
+            assert_eq!(capped, required == MaxCollateral::get());
+
+            let reserved_before = Balances::reserved_balance(1);
+            assert_ok!(DataAssets::register_asset(
+                RuntimeOrigin::signed(1),
+                b"asset".to_vec(),
+                b"test asset".to_vec(),
+                sp_core::H256::repeat_byte(seed),
+                data_size_bytes,
+            ));
+            assert_eq!(Balances::reserved_balance(1) - reserved_before, required);
+        }
+    });
+}
+
+fn threshold_policy(approvers: Vec<u64>, threshold: u32) -> TransferPolicy<u64> {
+    TransferPolicy::Threshold { approvers: approvers.try_into().unwrap(), threshold }
+}
+
+#[test]
+fn two_of_three_transfer_requires_two_approvals() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let policy = threshold_policy(vec![3, 4, 5], 2);
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, policy.clone()));
+        System::assert_last_event(Event::TransferPolicySet { asset_id, policy }.into());
+
+        // 没有审批时所有者不能直接转移
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2),
+            Error::<Test>::ThresholdNotMet
+        );
+        assert_noop!(
+            DataAssets::execute_transfer(RuntimeOrigin::signed(1), asset_id),
+            Error::<Test>::NoPendingTransfer
+        );
+        assert_noop!(
+            DataAssets::approve_transfer(RuntimeOrigin::signed(2), asset_id, 2),
+            Error::<Test>::NotTransferApprover
+        );
+
+        assert_ok!(DataAssets::approve_transfer(RuntimeOrigin::signed(3), asset_id, 2));
+        System::assert_last_event(
+            Event::TransferApproved { asset_id, approver: 3, new_owner: 2, approvals: 1 }.into(),
+        );
+        assert_noop!(
+            DataAssets::approve_transfer(RuntimeOrigin::signed(3), asset_id, 2),
+            Error::<Test>::AlreadyApprovedTransfer
+        );
+        assert_noop!(
+            DataAssets::approve_transfer(RuntimeOrigin::signed(4), asset_id, 3),
+            Error::<Test>::TransferTargetMismatch
+        );
+        // 一个审批不足门限
+        assert_noop!(
+            DataAssets::execute_transfer(RuntimeOrigin::signed(1), asset_id),
+            Error::<Test>::ThresholdNotMet
+        );
+
+        assert_ok!(DataAssets::approve_transfer(RuntimeOrigin::signed(4), asset_id, 2));
+        // 只有所有者能执行，且 transfer_asset 只接受已审批的接收者
+        assert_noop!(
+            DataAssets::execute_transfer(RuntimeOrigin::signed(3), asset_id),
+            Error::<Test>::NotOwner
+        );
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 3),
+            Error::<Test>::ThresholdNotMet
+        );
+        assert_ok!(DataAssets::execute_transfer(RuntimeOrigin::signed(1), asset_id));
+
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 2);
+        System::assert_last_event(Event::AssetTransferred { asset_id, from: 1, to: 2, fee: 0 }.into());
+        // 新所有者回到单独转移，旧审批记录被清除
+        assert!(TransferPolicies::<Test>::get(asset_id).is_none());
+        assert!(DataAssets::pending_transfer(asset_id).is_none());
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(2), asset_id, 1));
+    });
+}
+
+#[test]
+fn transfer_policy_validation_and_reset() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+
+        assert_noop!(
+            DataAssets::set_transfer_policy(RuntimeOrigin::signed(2), asset_id, threshold_policy(vec![3, 4], 1)),
+            Error::<Test>::NotOwner
+        );
+        for invalid in [threshold_policy(vec![3, 4], 0), threshold_policy(vec![3, 4], 3), threshold_policy(vec![3, 3], 2)] {
+            assert_noop!(
+                DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, invalid),
+                Error::<Test>::InvalidTransferPolicy
+            );
+        }
+
+        // 修改策略后之前的审批作废
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, threshold_policy(vec![3], 1)));
+        assert_ok!(DataAssets::approve_transfer(RuntimeOrigin::signed(3), asset_id, 2));
+        let next = threshold_policy(vec![3, 4], 1);
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, next.clone()));
+        assert_ok!(DataAssets::approve_policy_change(RuntimeOrigin::signed(3), asset_id, next));
+        assert!(DataAssets::pending_transfer(asset_id).is_none());
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2),
+            Error::<Test>::ThresholdNotMet
+        );
+
+        // 恢复 SingleOwner 后所有者可以直接转移
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, TransferPolicy::SingleOwner));
+        assert_ok!(DataAssets::approve_policy_change(RuntimeOrigin::signed(4), asset_id, TransferPolicy::SingleOwner));
+        assert!(TransferPolicies::<Test>::get(asset_id).is_none());
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+    });
+}
+
+#[test]
+fn leaving_threshold_policy_needs_current_approvals() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        let policy = threshold_policy(vec![3, 4, 5], 2);
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, policy.clone()));
+        assert_noop!(
+            DataAssets::approve_policy_change(RuntimeOrigin::signed(3), asset_id, TransferPolicy::SingleOwner),
+            Error::<Test>::NoPendingPolicyChange
+        );
+
+        // 所有者不能单方面切回 SingleOwner，只记录为待审批的变更
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, TransferPolicy::SingleOwner));
+        System::assert_last_event(
+            Event::TransferPolicyChangeProposed { asset_id, policy: TransferPolicy::SingleOwner }.into(),
+        );
+        assert_eq!(TransferPolicies::<Test>::get(asset_id), Some(policy.clone()));
+        assert_noop!(
+            DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2),
+            Error::<Test>::ThresholdNotMet
+        );
+
+        assert_noop!(
+            DataAssets::approve_policy_change(RuntimeOrigin::signed(2), asset_id, TransferPolicy::SingleOwner),
+            Error::<Test>::NotTransferApprover
+        );
+        // 审批的策略必须与提议一致
+        assert_noop!(
+            DataAssets::approve_policy_change(RuntimeOrigin::signed(3), asset_id, threshold_policy(vec![3], 1)),
+            Error::<Test>::PolicyChangeMismatch
+        );
+        assert_ok!(DataAssets::approve_policy_change(RuntimeOrigin::signed(3), asset_id, TransferPolicy::SingleOwner));
+        System::assert_last_event(
+            Event::TransferPolicyChangeApproved { asset_id, approver: 3, approvals: 1 }.into(),
+        );
+        assert_noop!(
+            DataAssets::approve_policy_change(RuntimeOrigin::signed(3), asset_id, TransferPolicy::SingleOwner),
+            Error::<Test>::AlreadyApprovedTransfer
+        );
+        assert_eq!(TransferPolicies::<Test>::get(asset_id), Some(policy));
+
+        // 达到当前门限后变更生效
+        assert_ok!(DataAssets::approve_policy_change(RuntimeOrigin::signed(5), asset_id, TransferPolicy::SingleOwner));
+        System::assert_last_event(Event::TransferPolicySet { asset_id, policy: TransferPolicy::SingleOwner }.into());
+        assert!(TransferPolicies::<Test>::get(asset_id).is_none());
+        assert!(DataAssets::pending_policy_change(asset_id).is_none());
+        assert_ok!(DataAssets::transfer_asset(RuntimeOrigin::signed(1), asset_id, 2));
+    });
+}
+
+#[test]
+fn market_transfers_respect_threshold_policy() {
+    new_test_ext().execute_with(|| {
+        let asset_id = register_test_asset(1, 1);
+        assert_ok!(DataAssets::set_transfer_policy(RuntimeOrigin::signed(1), asset_id, threshold_policy(vec![4], 1)));
+        assert_ok!(DataAssets::authorize_market(RuntimeOrigin::signed(1), asset_id, 2, None));
+
+        // 被授权的市场也不能绕过门限策略
+        assert_noop!(
+            DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3),
+            Error::<Test>::ThresholdNotMet
+        );
+        assert_noop!(
+            DataAssets::transfer_by_market_internal(&asset_id, &2, &3),
+            Error::<Test>::ThresholdNotMet
+        );
+
+        assert_ok!(DataAssets::approve_transfer(RuntimeOrigin::signed(4), asset_id, 3));
+        assert_ok!(DataAssets::transfer_asset_by_market(RuntimeOrigin::signed(2), asset_id, 3));
+        assert_eq!(DataAssets::get_asset(&asset_id).unwrap().owner, 3);
+        assert!(TransferPolicies::<Test>::get(asset_id).is_none());
+        assert!(DataAssets::pending_transfer(asset_id).is_none());
+    });
+}
+
+#[test]
+fn assets_with_same_content_hash_are_found_by_hash() {
+    new_test_ext().execute_with(|| {
+        let content_hash = sp_core::H256::repeat_byte(5);
+        assert!(DataAssets::find_assets_by_hash(content_hash).is_empty());
+
+        let first = register_test_asset(1, 5);
+        let second = register_test_asset(2, 5);
+        // 其他内容的资产不计入
+        register_test_asset(3, 6);
+        assert_eq!(DataAssets::find_assets_by_hash(content_hash), vec![first, second]);
+
+        // 索引已满时仍可注册同一内容的资产，只是不记入索引
+        let third = register_test_asset(3, 5);
+        assert!(DataAssets::get_asset(&third).is_some());
+        assert_eq!(DataAssets::find_assets_by_hash(content_hash), vec![first, second]);
+
+        // 注销的资产从索引中移除
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(1), first));
+        assert_eq!(DataAssets::find_assets_by_hash(content_hash), vec![second]);
+        assert_ok!(DataAssets::retire_asset(RuntimeOrigin::signed(2), second));
+        assert!(!crate::AssetIdByContentHash::<Test>::contains_key(content_hash));
+        assert!(DataAssets::get_asset(&third).is_some());
+    });
+}
@@ -184,6 +184,30 @@ pub enum CollateralStatus<Balance> {
     Slashed(Balance),
 }
 
+/// Market Authorization Information
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct ApprovalInfo<BlockNumber> {
+    /// Block at which the authorization was granted
+    pub approved_at: BlockNumber,
+
+    /// Block after which the authorization is no longer valid (None = until revoked)
+    pub valid_until: Option<BlockNumber>,
+}
+
+impl<BlockNumber: PartialOrd> ApprovalInfo<BlockNumber> {
+    /// Check if authorization has expired at the given block
+    pub fn is_expired(&self, current_block: BlockNumber) -> bool {
+        self.valid_until.as_ref().map_or(false, |until| current_block > *until)
+    }
+}
+
+/// Asset lineage entry (provenance trail)
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum LineageEntry<AccountId, BlockNumber> {
+    /// Ownership transferred (by the owner or an authorized market)
+    Transfer { from: AccountId, to: AccountId, block: BlockNumber },
+}
+
 /// Encryption Information
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
 pub struct EncryptionInfo {
@@ -206,9 +230,47 @@ pub struct MerkleNode {
 pub enum RightType {
     Usage = 1,
     Access = 2,
+    /// 转售权：持有者可将权证转售，只能由资产所有者发行
+    ResaleRight = 3,
+    /// 再授权权：持有者可再授权他人使用，只能由资产所有者发行且必须设置有效期
+    SublicenseRight = 4,
+    /// 限时查看：必须设置未来的有效期，市场可代理发行
+    TimeLimitedView = 5,
+}
+
+impl RightType {
+    /// 将 issue_certificate 的 u8 参数转换为权证类型
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(RightType::Usage),
+            2 => Some(RightType::Access),
+            3 => Some(RightType::ResaleRight),
+            4 => Some(RightType::SublicenseRight),
+            5 => Some(RightType::TimeLimitedView),
+            _ => None,
+        }
+    }
+
+    /// 是否只能由资产所有者发行（被授权的市场不能代理）
+    pub fn owner_only(&self) -> bool {
+        matches!(self, RightType::ResaleRight | RightType::SublicenseRight)
+    }
+
+    /// 是否必须设置有效期
+    pub fn requires_expiry(&self) -> bool {
+        matches!(self, RightType::SublicenseRight | RightType::TimeLimitedView)
+    }
+
+    /// 是否可以通过 exercise_certificate 行权（记录一次数据使用/访问）
+    pub fn exercisable(&self) -> bool {
+        matches!(self, RightType::Usage | RightType::Access)
+    }
 }
 
 /// Asset Status Enumeration
+///
+/// 状态流转：注册后为 Private；authorize_market 进入 Approved，撤销授权或资产转移后回到 Private；
+/// lock_asset 进入 Locked，unlock_asset 回到 Private
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
 pub enum AssetStatus {
     Private = 1, // 私有资产，只有资产所有者可以使用
@@ -224,6 +286,90 @@ pub enum CertificateStatus {
     Expired = 2,
 }
 
+/// Certificate validity window and status at query time
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct CertificateValidity {
+    /// Effective status: an `Active` certificate past `valid_until` is reported as `Expired`
+    pub status: CertificateStatus,
+    pub valid_from: u64,
+    pub valid_until: Option<u64>,
+    /// Whether the certificate can be used at the current timestamp
+    pub is_valid_now: bool,
+}
+
+/// Aggregated health summary of an asset across collateral, storage and certificates
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct AssetHealth<AccountId, Balance, BlockNumber> {
+    pub owner: AccountId,
+    pub status: AssetStatus,
+    pub is_locked: bool,
+    /// Number of markets currently authorized to operate the asset
+    pub approved_markets: u32,
+    /// None if no collateral record exists (e.g. reclaimed after full release)
+    pub collateral_status: Option<CollateralStatus<Balance>>,
+    pub collateral_reserved: Balance,
+    /// Unlock block of the earliest phase not yet released
+    pub next_release_block: Option<BlockNumber>,
+    /// Number of storage providers that have submitted proofs for the asset
+    pub replica_count: u32,
+    pub last_proof_block: Option<BlockNumber>,
+    pub certificate_count: u32,
+}
+
+/// Latest off-chain gateway check of an asset's data, consulted by the availability release phase
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct AvailabilityAttestation<AccountId, BlockNumber> {
+    /// Whether the gateway served the asset's CID
+    pub available: bool,
+    pub attester: AccountId,
+    /// Block the attestation was recorded in
+    pub block: BlockNumber,
+}
+
+/// Maximum number of approvers in an N-of-M transfer policy
+pub const MAX_TRANSFER_APPROVERS: u32 = 10;
+
+/// Who has to sign off before the owner can transfer an asset
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub enum TransferPolicy<AccountId> {
+    /// The owner transfers alone (the default when no policy is stored)
+    SingleOwner,
+    /// `threshold` of `approvers` must approve the same recipient before the transfer executes
+    Threshold { approvers: BoundedVec<AccountId, ConstU32<MAX_TRANSFER_APPROVERS>>, threshold: u32 },
+}
+
+/// Owner transfer waiting for approvals under a threshold policy
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId> {
+    pub new_owner: AccountId,
+    pub approvals: BoundedVec<AccountId, ConstU32<MAX_TRANSFER_APPROVERS>>,
+    /// Asset nonce when the first approval was given; a transfer in between voids the approvals
+    pub asset_nonce: u32,
+}
+
+/// Policy change waiting for approvals when the current policy is a threshold policy
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo, MaxEncodedLen)]
+pub struct PendingPolicyChange<AccountId> {
+    pub policy: TransferPolicy<AccountId>,
+    /// Approvers of the current policy who agreed to the change
+    pub approvals: BoundedVec<AccountId, ConstU32<MAX_TRANSFER_APPROVERS>>,
+}
+
+/// Ecosystem-wide totals for dashboards, aggregated from counters kept by each pallet
+#[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
+pub struct EcosystemStats<Balance> {
+    /// Assets currently registered (retired assets are not counted)
+    pub total_assets: u32,
+    /// Certificates currently stored (revoked or removed certificates are not counted)
+    pub total_certificates: u64,
+    /// Asset collateral still reserved
+    pub total_collateral_locked: Balance,
+    /// Registered markets, including inactive ones
+    pub markets: u32,
+    /// Accounts holding an IPFS provider pledge
+    pub storage_providers: u32,
+}
+
 #[derive(Encode, Decode, Clone, PartialEq, Eq, Debug, TypeInfo)]
 pub enum PriceType {
     Fixed, // 固定价格
@@ -340,14 +486,16 @@ impl<AccountId: Clone> DataAsset<AccountId> {
         self.is_locked || self.status == AssetStatus::Locked
     }
 
+    /// Check if asset status is Approved
+    /// 只反映资产本身的状态，完整判断需结合 AssetApprovals（见 Pallet::is_asset_approved）
     pub fn is_approved(&self) -> bool {
         self.status == AssetStatus::Approved
     }
     
     /// Check if asset is active
-    /// 应该修改成is_not_locked
+    /// 未锁定即为活跃，已授权给市场（Approved）的资产仍可由市场代理发行权证
     pub fn is_active(&self) -> bool {
-        self.status == AssetStatus::Private && !self.is_locked()
+        matches!(self.status, AssetStatus::Private | AssetStatus::Approved) && !self.is_locked()
     }
 }
 
@@ -379,6 +527,21 @@ impl<AccountId: Clone> RightToken<AccountId> {
     pub fn is_expired(&self, current_time: u64) -> bool {
         self.valid_until.map_or(false, |until| current_time > until)
     }
+
+    /// Validity window and effective status at `current_time`
+    pub fn validity(&self, current_time: u64) -> CertificateValidity {
+        let status = if self.is_expired(current_time) {
+            CertificateStatus::Expired
+        } else {
+            self.status.clone()
+        };
+        CertificateValidity {
+            status,
+            valid_from: self.valid_from,
+            valid_until: self.valid_until,
+            is_valid_now: self.is_valid(current_time),
+        }
+    }
 }
 
 // Builder pattern constructors
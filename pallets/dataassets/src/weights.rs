@@ -36,6 +36,10 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_dataassets`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
+	/// Storage: `DataAssets::RegistrationRateLimitExempt` (r:1 w:0)
+	/// Proof: `DataAssets::RegistrationRateLimitExempt` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::LastRegistration` (r:1 w:1)
+	/// Proof: `DataAssets::LastRegistration` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
@@ -48,6 +52,8 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 	/// Proof: `Incentive::IncentivePoolUsed` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `DataAssets::AssetCollateral` (r:0 w:1)
 	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetIdByContentHash` (r:1 w:1)
+	/// Proof: `DataAssets::AssetIdByContentHash` (`max_values`: None, `max_size`: Some(561), added: 3036, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x6173736574732fc008023d5802a250b3ad74741b8ba83cd40ecb3a9db0b03072` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x6173736574732fc008023d5802a250b3ad74741b8ba83cd40ecb3a9db0b03072` (r:1 w:1)
 	/// Storage: UNKNOWN KEY `0x5f6d657461646174612f6e6578745f746f6b656e5f6964` (r:1 w:1)
@@ -63,11 +69,17 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 43_340_000 picoseconds.
 		Weight::from_parts(62_584_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(6))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
 	}
 	/// Storage: `DataAssets::AssetApprovals` (r:1 w:0)
 	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateCounts` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificatesByIssuer` (r:1 w:1)
+	/// Proof: `DataAssets::CertificatesByIssuer` (`max_values`: None, `max_size`: Some(36052), added: 38527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificatesByProxy` (r:1 w:1)
+	/// Proof: `DataAssets::CertificatesByProxy` (`max_values`: None, `max_size`: Some(36052), added: 38527, mode: `MaxEncodedLen`)
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a3a6365727469666963` (r:0 w:1)
@@ -80,29 +92,35 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:0 w:1)
 	fn issue_certificate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `626`
-		//  Estimated: `4091`
-		// Minimum execution time: 42_620_000 picoseconds.
-		Weight::from_parts(56_824_000, 0)
-			.saturating_add(Weight::from_parts(0, 4091))
-			.saturating_add(T::DbWeight::get().reads(4))
-			.saturating_add(T::DbWeight::get().writes(4))
+		//  Measured:  `762`
+		//  Estimated: `39517`
+		// Minimum execution time: 47_310_000 picoseconds.
+		Weight::from_parts(61_507_000, 0)
+			.saturating_add(Weight::from_parts(0, 39517))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: `DataAssets::AssetApprovals` (r:0 w:1)
 	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(302), added: 2777, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CollateralByDepositor` (r:2 w:2)
+	/// Proof: `DataAssets::CollateralByDepositor` (`max_values`: None, `max_size`: Some(8242), added: 10717, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
 	fn transfer_asset() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `521`
-		//  Estimated: `3986`
-		// Minimum execution time: 21_530_000 picoseconds.
-		Weight::from_parts(23_064_000, 0)
-			.saturating_add(Weight::from_parts(0, 3986))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+		//  Measured:  `1021`
+		//  Estimated: `22424`
+		// Minimum execution time: 58_910_000 picoseconds.
+		Weight::from_parts(61_302_000, 0)
+			.saturating_add(Weight::from_parts(0, 22424))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(7))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -112,15 +130,17 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
 	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:1 w:1)
+	/// Storage: `DataAssets::CertificateCounts` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
 	fn revoke_certificate() -> Weight {
 		// Proof Size summary in bytes:
-		//  Measured:  `819`
-		//  Estimated: `4284`
-		// Minimum execution time: 32_591_000 picoseconds.
-		Weight::from_parts(36_334_000, 0)
-			.saturating_add(Weight::from_parts(0, 4284))
-			.saturating_add(T::DbWeight::get().reads(3))
-			.saturating_add(T::DbWeight::get().writes(3))
+		//  Measured:  `871`
+		//  Estimated: `4336`
+		// Minimum execution time: 34_018_000 picoseconds.
+		Weight::from_parts(37_902_000, 0)
+			.saturating_add(Weight::from_parts(0, 4336))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Timestamp::Now` (r:1 w:0)
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
@@ -196,4 +216,388 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(3))
 			.saturating_add(T::DbWeight::get().writes(2))
 	}
+	/// Storage: `DataAssets::ApprovalCount` (r:1 w:1)
+	/// Proof: `DataAssets::ApprovalCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetApprovals` (r:0 w:10)
+	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
+	/// The range of component `n` is `[1, 10]`.
+	fn revoke_all_authorizations(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `666 + n * (72 ±0)`
+		//  Estimated: `4131 + n * (2585 ±0)`
+		// Minimum execution time: 24_310_000 picoseconds.
+		Weight::from_parts(27_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 4131))
+			// Standard Error: 3_214
+			.saturating_add(Weight::from_parts(1_932_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2585).saturating_mul(n.into()))
+	}
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn transfer_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `4207`
+		// Minimum execution time: 28_914_000 picoseconds.
+		Weight::from_parts(31_276_000, 0)
+			.saturating_add(Weight::from_parts(0, 4207))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	fn reclaim_collateral_record() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3726`
+		// Minimum execution time: 14_208_000 picoseconds.
+		Weight::from_parts(15_371_000, 0)
+			.saturating_add(Weight::from_parts(0, 3726))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAssets::AssetApprovals` (r:1 w:0)
+	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateCounts` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificatesByIssuer` (r:1 w:1)
+	/// Proof: `DataAssets::CertificatesByIssuer` (`max_values`: None, `max_size`: Some(36052), added: 38527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificatesByProxy` (r:1 w:1)
+	/// Proof: `DataAssets::CertificatesByProxy` (`max_values`: None, `max_size`: Some(36052), added: 38527, mode: `MaxEncodedLen`)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a3a6365727469666963` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x3a6368696c645f73746f726167653a64656661756c743a3a6365727469666963` (r:1 w:1)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn issue_certificates_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `762 + n * (100 ±0)`
+		//  Estimated: `39517 + n * (100 ±0)`
+		// Minimum execution time: 49_640_000 picoseconds.
+		Weight::from_parts(52_981_000, 0)
+			.saturating_add(Weight::from_parts(0, 39517))
+			// Standard Error: 4_391
+			.saturating_add(Weight::from_parts(10_463_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 100).saturating_mul(n.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:51 w:50)
+	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:51 w:50)
+	/// Storage: `DataAssets::CertificateCounts` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 50]`.
+	fn revoke_all_certificates(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `640 + n * (165 ±0)`
+		//  Estimated: `4105 + n * (2640 ±0)`
+		// Minimum execution time: 31_820_000 picoseconds.
+		Weight::from_parts(30_544_000, 0)
+			.saturating_add(Weight::from_parts(0, 4105))
+			// Standard Error: 3_905
+			.saturating_add(Weight::from_parts(9_217_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2640).saturating_mul(n.into()))
+	}
+	/// Storage: `DataAssets::ClaimedDataSize` (r:1 w:1)
+	/// Proof: `DataAssets::ClaimedDataSize` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn report_size_mismatch() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3726`
+		// Minimum execution time: 31_406_000 picoseconds.
+		Weight::from_parts(33_118_000, 0)
+			.saturating_add(Weight::from_parts(0, 3726))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateUsageQuota` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateUsageQuota` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::DownloadCount` (r:1 w:1)
+	/// Proof: `DataAssets::DownloadCount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn exercise_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `804`
+		//  Estimated: `4269`
+		// Minimum execution time: 34_652_000 picoseconds.
+		Weight::from_parts(37_013_000, 0)
+			.saturating_add(Weight::from_parts(0, 4269))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:1 w:0)
+	/// Storage: `DataAssets::CertificateUsageQuota` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateUsageQuota` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	fn set_usage_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `742`
+		//  Estimated: `4207`
+		// Minimum execution time: 21_587_000 picoseconds.
+		Weight::from_parts(23_194_000, 0)
+			.saturating_add(Weight::from_parts(0, 4207))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Storage: `DataAssets::AssetApprovals` (r:1 w:0)
+	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Proof: UNKNOWN KEY `0xe6ef66d8243e6909eec0ac5b0ae04b0cf2da4043e0e03f68ce1dfde2b06a591f` (r:2 w:1)
+	/// Storage: `Timestamp::Now` (r:1 w:0)
+	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn extend_certificate() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `861`
+		//  Estimated: `4326`
+		// Minimum execution time: 35_208_000 picoseconds.
+		Weight::from_parts(37_645_000, 0)
+			.saturating_add(Weight::from_parts(0, 4326))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `DataAssets::ClaimedDataSize` (r:1 w:0)
+	/// Proof: `DataAssets::ClaimedDataSize` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::ReleaseQueue` (r:1 w:1)
+	/// Proof: `DataAssets::ReleaseQueue` (`max_values`: None, `max_size`: Some(142), added: 2617, mode: `MaxEncodedLen`)
+	fn recalculate_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `498`
+		//  Estimated: `3726`
+		// Minimum execution time: 29_873_000 picoseconds.
+		Weight::from_parts(31_402_000, 0)
+			.saturating_add(Weight::from_parts(0, 3726))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `DataAssets::RegistrationRateLimitExempt` (r:0 w:1)
+	/// Proof: `DataAssets::RegistrationRateLimitExempt` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_registration_exempt() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_214_000 picoseconds.
+		Weight::from_parts(7_683_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CollateralByDepositor` (r:1 w:1)
+	/// Proof: `DataAssets::CollateralByDepositor` (`max_values`: None, `max_size`: Some(306), added: 2781, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::ClaimedDataSize` (r:0 w:1)
+	/// Proof: `DataAssets::ClaimedDataSize` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	fn release_orphaned_collateral() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3771`
+		// Minimum execution time: 27_146_000 picoseconds.
+		Weight::from_parts(28_530_000, 0)
+			.saturating_add(Weight::from_parts(0, 3771))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:0)
+	/// Storage: `DataAssets::RevenueShares` (r:0 w:1)
+	/// Proof: `DataAssets::RevenueShares` (`max_values`: None, `max_size`: Some(661), added: 3136, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn set_revenue_shares(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `3917`
+		// Minimum execution time: 14_372_000 picoseconds.
+		Weight::from_parts(15_108_000, 0)
+			.saturating_add(Weight::from_parts(0, 3917))
+			// Standard Error: 1_284
+			.saturating_add(Weight::from_parts(412_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0x6173736574732fe3d73b1b8d7f7d0801726064a02cf59dcd858332b0d073a935` (r:1 w:1)
+	/// Storage: `DataAssets::ApprovalCount` (r:1 w:1)
+	/// Proof: `DataAssets::ApprovalCount` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateCounts` (r:1 w:1)
+	/// Proof: `DataAssets::CertificateCounts` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetCollateral` (r:1 w:1)
+	/// Proof: `DataAssets::AssetCollateral` (`max_values`: None, `max_size`: Some(261), added: 2736, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CollateralByDepositor` (r:1 w:1)
+	/// Proof: `DataAssets::CollateralByDepositor` (`max_values`: None, `max_size`: Some(306), added: 2781, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x5f6d657461646174612f746f6b656e5f6d617070696e67732f00000000` (r:0 w:1)
+	/// Proof: UNKNOWN KEY `0x5f6d657461646174612f746f6b656e5f6d617070696e67732f00000000` (r:0 w:1)
+	/// Storage: `DataAssets::AssetApprovals` (r:0 w:3)
+	/// Proof: `DataAssets::AssetApprovals` (`max_values`: None, `max_size`: Some(97), added: 2572, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::ClaimedDataSize` (r:0 w:1)
+	/// Proof: `DataAssets::ClaimedDataSize` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::CertificateRoots` (r:0 w:1)
+	/// Proof: `DataAssets::CertificateRoots` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::DownloadCount` (r:0 w:1)
+	/// Proof: `DataAssets::DownloadCount` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::AssetLineage` (r:0 w:1)
+	/// Proof: `DataAssets::AssetLineage` (`max_values`: None, `max_size`: Some(6186), added: 8661, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::RevenueShares` (r:0 w:1)
+	/// Proof: `DataAssets::RevenueShares` (`max_values`: None, `max_size`: Some(661), added: 3136, mode: `MaxEncodedLen`)
+	fn retire_asset() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `905`
+		//  Estimated: `4370`
+		// Minimum execution time: 52_318_000 picoseconds.
+		Weight::from_parts(54_026_000, 0)
+			.saturating_add(Weight::from_parts(0, 4370))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(16))
+	}
+	/// Storage: UNKNOWN KEY `0x5f6d657461646174612f6e6578745f746f6b656e5f6964` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x5f6d657461646174612f6e6578745f746f6b656e5f6964` (r:1 w:0)
+	/// Storage: `DataAssets::CachedAssetRoot` (r:0 w:1)
+	/// Proof: `DataAssets::CachedAssetRoot` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 1000]`.
+	fn cache_asset_root(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `120 + n * (233 ±0)`
+		//  Estimated: `3585 + n * (2708 ±0)`
+		// Minimum execution time: 11_904_000 picoseconds.
+		Weight::from_parts(12_377_000, 0)
+			.saturating_add(Weight::from_parts(0, 3585))
+			// Standard Error: 1_736
+			.saturating_add(Weight::from_parts(3_128_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1))
+			.saturating_add(Weight::from_parts(0, 2708).saturating_mul(n.into()))
+	}
+	/// Storage: `DataAssets::AvailabilityAttesters` (r:0 w:1)
+	/// Proof: `DataAssets::AvailabilityAttesters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn set_availability_attester() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_102_000 picoseconds.
+		Weight::from_parts(7_561_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAssets::AvailabilityAttesters` (r:1 w:0)
+	/// Proof: `DataAssets::AvailabilityAttesters` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Storage: `DataAssets::AvailabilityAttestations` (r:0 w:1)
+	/// Proof: `DataAssets::AvailabilityAttestations` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	fn submit_availability_attestation() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 18_940_000 picoseconds.
+		Weight::from_parts(19_633_000, 0)
+			.saturating_add(Weight::from_parts(0, 3977))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Storage: `DataAssets::TransferPolicies` (r:1 w:1)
+	/// Proof: `DataAssets::TransferPolicies` (`max_values`: None, `max_size`: Some(374), added: 2849, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::PendingTransfers` (r:0 w:1)
+	/// Proof: `DataAssets::PendingTransfers` (`max_values`: None, `max_size`: Some(405), added: 2880, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::PendingPolicyChanges` (r:0 w:1)
+	/// Proof: `DataAssets::PendingPolicyChanges` (`max_values`: None, `max_size`: Some(695), added: 3170, mode: `MaxEncodedLen`)
+	fn set_transfer_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `452`
+		//  Estimated: `3917`
+		// Minimum execution time: 17_836_000 picoseconds.
+		Weight::from_parts(20_105_000, 0)
+			.saturating_add(Weight::from_parts(0, 3917))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x6173736574732f` (r:1 w:0)
+	/// Storage: `DataAssets::TransferPolicies` (r:1 w:0)
+	/// Proof: `DataAssets::TransferPolicies` (`max_values`: None, `max_size`: Some(374), added: 2849, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::PendingTransfers` (r:1 w:1)
+	/// Proof: `DataAssets::PendingTransfers` (`max_values`: None, `max_size`: Some(405), added: 2880, mode: `MaxEncodedLen`)
+	fn approve_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1138`
+		//  Estimated: `4603`
+		// Minimum execution time: 26_407_000 picoseconds.
+		Weight::from_parts(27_291_000, 0)
+			.saturating_add(Weight::from_parts(0, 4603))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `DataAssets::PendingTransfers` (r:1 w:1)
+	/// Proof: `DataAssets::PendingTransfers` (`max_values`: None, `max_size`: Some(405), added: 2880, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::TransferPolicies` (r:1 w:1)
+	/// Proof: `DataAssets::TransferPolicies` (`max_values`: None, `max_size`: Some(374), added: 2849, mode: `MaxEncodedLen`)
+	fn execute_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1756`
+		//  Estimated: `23159`
+		// Minimum execution time: 71_224_000 picoseconds.
+		Weight::from_parts(73_908_000, 0)
+			.saturating_add(Weight::from_parts(0, 23159))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(9))
+	}
+	/// Storage: `DataAssets::TransferPolicies` (r:1 w:1)
+	/// Proof: `DataAssets::TransferPolicies` (`max_values`: None, `max_size`: Some(374), added: 2849, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::PendingPolicyChanges` (r:1 w:1)
+	/// Proof: `DataAssets::PendingPolicyChanges` (`max_values`: None, `max_size`: Some(695), added: 3170, mode: `MaxEncodedLen`)
+	/// Storage: `DataAssets::PendingTransfers` (r:0 w:1)
+	/// Proof: `DataAssets::PendingTransfers` (`max_values`: None, `max_size`: Some(405), added: 2880, mode: `MaxEncodedLen`)
+	fn approve_policy_change() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1320`
+		//  Estimated: `4785`
+		// Minimum execution time: 28_912_000 picoseconds.
+		Weight::from_parts(29_870_000, 0)
+			.saturating_add(Weight::from_parts(0, 4785))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
 }
@@ -78,10 +78,9 @@ benchmarks! {
 
     // 3. 市场交易额登记测试
     register_market_monthly_volume {
-        let caller: T::AccountId = whitelisted_caller();
         let market_id: [u8; 32] = [2u8; 32];
         let volume: BalanceOf<T> = 10_000u32.into();
-    }: _(RawOrigin::Signed(caller), market_id, volume)
+    }: _(RawOrigin::Root, market_id, volume)
     verify {
         assert_eq!(MarketMonthlyVolume::<T>::get(&market_id), volume);
     }
@@ -95,5 +94,59 @@ benchmarks! {
         assert_eq!(GovernanceVotingWeight::<T>::get(&voter), weight);
     }
 
+    fund_incentive_pool {
+        let source: T::AccountId = account("source", 0, 0);
+        let amount = T::InitialIncentivePool::get();
+        T::Currency::make_free_balance_be(&source, amount.saturating_mul(2u32.into()));
+    }: _(RawOrigin::Root, source.clone(), amount)
+    verify {
+        assert_eq!(IncentivePoolFunded::<T>::get(), amount);
+    }
+
+    add_to_denylist {
+        let target: T::AccountId = account("denied", 0, 0);
+    }: _(RawOrigin::Root, target.clone())
+    verify {
+        assert!(RewardDenylist::<T>::get(&target));
+    }
+
+    remove_from_denylist {
+        let target: T::AccountId = account("denied", 0, 0);
+        RewardDenylist::<T>::insert(&target, true);
+    }: _(RawOrigin::Root, target.clone())
+    verify {
+        assert!(!RewardDenylist::<T>::get(&target));
+    }
+
+    register_voting_weights_batch {
+        let n in 1 .. T::MaxBatchSize::get();
+        let weight: BalanceOf<T> = 5_000u32.into();
+        let weights: Vec<(T::AccountId, BalanceOf<T>)> =
+            (0..n).map(|i| (account("voter", i, 0), weight)).collect();
+    }: _(RawOrigin::Root, weights)
+    verify {
+        let voter: T::AccountId = account("voter", n - 1, 0);
+        assert_eq!(GovernanceVotingWeight::<T>::get(&voter), weight);
+    }
+
+    set_distribution_interval {
+        let interval: BlockNumberFor<T> = 100_800u32.into();
+    }: _(RawOrigin::Root, interval)
+    verify {
+        assert_eq!(DistributionInterval::<T>::get(), interval);
+    }
+
+    claim_pending_reward {
+        setup_pool_v1::<T>();
+        let caller: T::AccountId = whitelisted_caller();
+        T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance());
+        let amount = T::QualityDataReward::get();
+        PendingReward::<T>::insert(&caller, amount);
+    }: _(RawOrigin::Signed(caller.clone()))
+    verify {
+        assert!(!PendingReward::<T>::contains_key(&caller));
+        assert_eq!(IncentivePoolUsed::<T>::get(), amount);
+    }
+
     impl_benchmark_test_suite!(Incentive, crate::mock::new_test_ext(), crate::mock::Test);
 }
\ No newline at end of file
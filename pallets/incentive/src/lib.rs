@@ -20,33 +20,49 @@ use alloc::vec::Vec;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 pub mod weights;
 
 pub use pallet::*;
 use frame_support::{
     pallet_prelude::*,
-    traits::{Currency, ReservableCurrency, Get, StorageVersion, ExistenceRequirement},
+    traits::{Currency, ReservableCurrency, Get, StorageVersion, ExistenceRequirement, VestingSchedule, BalanceStatus},
+    PalletId,
 };
 use frame_system::pallet_prelude::*;
 use sp_runtime::{
-    traits::{Saturating, CheckedDiv},
+    traits::{AccountIdConversion, Hash as HashT, Saturating, CheckedDiv, One, Zero},
     Perbill,
 };
 use hex_literal::hex;
+use pallet_shared_traits::{IncentiveError, MarketProvider, PledgeStatusProvider};
 
 type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
-// 激励池账户（固定地址）- 使用更通用的方式
+// 激励池账户，由 Config::PalletId 派生（无私钥）
 fn incentive_pool_account<T: Config>() -> T::AccountId {
+    T::PalletId::get().into_account_truncating()
+}
+
+// v2 之前使用的固定地址激励池账户，只用于存储迁移
+fn legacy_incentive_pool_account<T: Config>() -> T::AccountId {
     let raw_account: [u8; 32] = hex!("1a9de66d5ca5a6a7bad9add630d85b972f351082b0422e5f64c78a4eecc4a427");
     T::AccountId::decode(&mut &raw_account[..])
         .unwrap_or_else(|_| panic!("Failed to decode incentive pool account"))
 }
 
 // 存储版本（用于后续升级）
-const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+// v1：激励池初始化；v2：激励池账户从固定地址迁移到 PalletId 派生账户
+const STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
 // 月度奖励触发间隔（按区块计算：18秒/块 / 24×3600秒/天 ×30天 ≈ 144000块）
 const MONTH_BLOCKS: u32 = 144000;
+// 基金会解锁进度检查间隔（约一天）
+const VESTING_CHECK_BLOCKS: u32 = MONTH_BLOCKS / 30;
 type AssetId = [u8; 32];
 
 #[frame_support::pallet]
@@ -59,12 +75,40 @@ pub mod pallet {
         fn distribute_quality_data_reward() -> Weight;
         fn register_market_monthly_volume() -> Weight;
         fn register_voting_weight() -> Weight;
+        fn fund_incentive_pool() -> Weight;
+        fn add_to_denylist() -> Weight;
+        fn remove_from_denylist() -> Weight;
+        fn register_voting_weights_batch(n: u32) -> Weight;
+        fn set_distribution_interval() -> Weight;
+        fn claim_pending_reward() -> Weight;
     }
 
     #[pallet::pallet]
     #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
+    /// 创世配置：只用于在创世时校验激励池账户余额与经济模型一致
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            Pallet::<T>::warn_rewards_below_unit();
+            // 激励池余额不一致会导致整个奖励体系资金不足，创世时直接报错
+            if let Err((expected, actual)) = Pallet::<T>::check_pool_funding() {
+                panic!(
+                    "激励池创世余额与经济模型不一致: expected={:?}, actual={:?}",
+                    expected, actual
+                );
+            }
+        }
+    }
+
     /// 配置Trait
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -75,13 +119,35 @@ pub mod pallet {
         
         type DataAssetProvider: pallet_shared_traits::DataAssetProvider<Self::AccountId, AssetId>;
 
+        /// 已注册市场查询，流动性奖励只发给在已注册且启用的市场上下单的交易者
+        type MarketProvider: pallet_shared_traits::MarketProvider<Self::AccountId>;
+
+        /// 治理质押生效查询，质押未满生效期的投票者不参与投票奖励分配
+        type PledgeStatusProvider: pallet_shared_traits::PledgeStatusProvider<Self::AccountId>;
+
         /// 激励池初始余额（3亿DAT，对应经济模型30%总量）
         #[pallet::constant]
         type InitialIncentivePool: Get<BalanceOf<Self>>;
         
-        /// 动态释放比例（按生态活跃度，默认1%/月）
+        /// 动态释放比例（没有生态活跃度数据时使用，默认1%/月）
         #[pallet::constant]
         type DynamicReleaseRatio: Get<Perbill>;
+
+        /// 按生态活跃度调整时的最低释放比例（默认0.5%/月）
+        #[pallet::constant]
+        type MinReleaseRatio: Get<Perbill>;
+
+        /// 按生态活跃度调整时的最高释放比例（默认2%/月）
+        #[pallet::constant]
+        type MaxReleaseRatio: Get<Perbill>;
+
+        /// 收尾释放阈值：剩余未释放额度不超过该值时一次性全部释放，使累计释放量精确等于 InitialIncentivePool
+        #[pallet::constant]
+        type FinalReleaseThreshold: Get<BalanceOf<Self>>;
+
+        /// 目标月交易总额：全部市场月交易额达到该值时按最高比例释放
+        #[pallet::constant]
+        type TargetMonthlyTradeVolume: Get<BalanceOf<Self>>;
         
         // -------------------------- 奖励参数配置 --------------------------
         /// 数据创建者：首次创建元证奖励（默认1000DAT）
@@ -103,6 +169,22 @@ pub mod pallet {
         /// 市场运营者：优质市场月度奖励（默认50000DAT）
         #[pallet::constant]
         type TopMarketMonthlyReward: Get<BalanceOf<Self>>;
+
+        /// 市场运营者：每代理发行一张权证的奖励（默认0，即不发放）
+        #[pallet::constant]
+        type IssuanceOperatorReward: Get<BalanceOf<Self>>;
+
+        /// 市场运营者：按月交易额计算的基础奖励比例（ratio × 月交易额），与优质市场奖励分开发放（默认0，即不发放）
+        #[pallet::constant]
+        type OperatorVolumeRewardRatio: Get<Perbill>;
+
+        /// 市场运营者：每个市场每月交易额奖励的上限，超过部分不发放
+        #[pallet::constant]
+        type MaxOperatorVolumeReward: Get<BalanceOf<Self>>;
+
+        /// 每个区块最多发放的交易额奖励笔数，其余留在队列中由后续区块继续发放
+        #[pallet::constant]
+        type MaxOperatorRewardsPerBlock: Get<u32>;
         
         /// 交易者：手续费返还阈值（月交易额≥N DAT，默认10万）
         #[pallet::constant]
@@ -115,6 +197,10 @@ pub mod pallet {
         /// 交易者：流动性奖励比例（默认0.05%）
         #[pallet::constant]
         type LiquidityRewardRatio: Get<Perbill>;
+
+        /// 交易者：获得流动性奖励的最小订单金额，低于该金额的订单不发放也不累积奖励
+        #[pallet::constant]
+        type MinOrderForLiquidityReward: Get<BalanceOf<Self>>;
         
         /// 治理参与者：月度投票奖励总额（默认5000DAT）
         #[pallet::constant]
@@ -123,14 +209,89 @@ pub mod pallet {
         /// 治理参与者：提案通过奖励（默认2000DAT）
         #[pallet::constant]
         type GovernanceProposalReward: Get<BalanceOf<Self>>;
+
+        /// 治理参与者：提交提案时锁定的保证金，提案结束后退还或罚没
+        #[pallet::constant]
+        type GovernanceProposalBond: Get<BalanceOf<Self>>;
+
+        /// 提案被否决且支持率低于该比例时罚没保证金（转入激励池），否则退还
+        #[pallet::constant]
+        type ProposalSupportThreshold: Get<Perbill>;
         
         /// 验证节点：元证验证奖励（默认50DAT/次）
         #[pallet::constant]
         type ValidatorVerificationReward: Get<BalanceOf<Self>>;
 
+        /// 最小奖励发放额（低于该值的奖励先累积到 PendingReward，避免低于ED的粉尘转账）
+        #[pallet::constant]
+        type MinRewardPayout: Get<BalanceOf<Self>>;
+
+        /// 资产所有者账户已被回收（OwnerAccountDoesNotExist）时是否托管优质数据奖励：
+        /// true 时奖励记入所有者的 PendingReward，账户重新创建后通过 claim_pending_reward 领取；
+        /// false 时与之前一样直接返回 OwnerAccountDoesNotExist
+        #[pallet::constant]
+        type EscrowRewardsForMissingOwner: Get<bool>;
+
+        /// 1 DAT 对应的最小单位数量（10^decimals），只用于检查奖励常量是否按精度配置
+        /// 奖励常量低于该值时很可能漏乘了 UNIT，启动时会输出警告
+        #[pallet::constant]
+        type Unit: Get<BalanceOf<Self>>;
+
+        /// 基金会储备的线性解锁计划（pallet_vesting）
+        type FoundationVesting: VestingSchedule<Self::AccountId, Currency = Self::Currency>;
+
+        /// 基金会储备账户
+        #[pallet::constant]
+        type FoundationAccount: Get<Self::AccountId>;
+
+        /// 基金会储备的解锁总额
+        #[pallet::constant]
+        type FoundationVestingTotal: Get<BalanceOf<Self>>;
+
+        /// 每解锁该比例触发一次 VestingMilestone 事件（如 10%）
+        #[pallet::constant]
+        type VestingMilestoneStep: Get<Perbill>;
+
+        /// 无法送达的奖励（如运营者账户无法解析）转入的暂存账户，避免激励池向自身转账
+        #[pallet::constant]
+        type UndistributedRewardAccount: Get<Self::AccountId>;
+
+        /// MonthlyDistributionLog 保留的月度发放记录数量，更早的记录会被删除
+        #[pallet::constant]
+        type MaxDistributionHistory: Get<u32>;
+
+        /// 转账失败的奖励最多在之后的发放轮次中重试的次数，用完后放弃
+        #[pallet::constant]
+        type MaxPayoutRetries: Get<u32>;
+
+        /// 单次批量登记治理投票权重的最大条数
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// 激励池账户由该 PalletId 派生
+        #[pallet::constant]
+        type PalletId: Get<PalletId>;
+
         type WeightInfo: WeightInfo;
     }
 
+    /// 月度发放汇总：各类奖励实际发放总额及发放前的激励池余额
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct DistributionSummary<Balance, BlockNumber> {
+        /// 发放所在区块
+        pub block: BlockNumber,
+        /// 优质市场奖励
+        pub top_market: Balance,
+        /// 交易者手续费返还
+        pub trader_rebate: Balance,
+        /// 治理投票奖励
+        pub governance_voting: Balance,
+        /// 本月发放合计
+        pub total: Balance,
+        /// 发放前激励池账户的可用余额
+        pub pool_balance: Balance,
+    }
+
     // -------------------------- 存储 --------------------------
     // !!!!!怎么判断市场流动性（活力）不足
     /// 激励池已释放总额（用于动态释放计算）
@@ -203,11 +364,116 @@ pub mod pallet {
         ValueQuery,
     >;
 
+    /// 通过 fund_incentive_pool 追加注入激励池的总额（注入后立即可用于发放）
+    #[pallet::storage]
+    #[pallet::getter(fn incentive_pool_funded)]
+    pub type IncentivePoolFunded<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// 待发放的奖励：(recipient_account, 累积金额)
+    /// - 小额奖励累积，超过 MinRewardPayout 后一次性发放
+    /// - 所有者账户不存在时托管的优质数据奖励，账户重新创建后通过 claim_pending_reward 领取
+    #[pallet::storage]
+    #[pallet::getter(fn pending_reward)]
+    pub type PendingReward<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// 提案人尚未结算的提案保证金总额（每个进行中的提案锁定一份 GovernanceProposalBond）
+    #[pallet::storage]
+    #[pallet::getter(fn proposal_bond)]
+    pub type ProposalBonds<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        ValueQuery,
+    >;
+
+    /// 等待发放交易额奖励的市场：(market_id, 月交易额)，月度发放时写入，每个区块按 MaxOperatorRewardsPerBlock 分页发放
+    #[pallet::storage]
+    #[pallet::getter(fn pending_operator_volume_reward)]
+    pub type PendingOperatorVolumeRewards<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32],
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// 转账失败、等待下一轮发放时重试的奖励：(recipient_account, 累计金额)
+    #[pallet::storage]
+    #[pallet::getter(fn failed_payout)]
+    pub type FailedPayouts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BalanceOf<T>,
+        OptionQuery,
+    >;
+
+    /// FailedPayouts 中每条记录已重试失败的次数
+    #[pallet::storage]
+    #[pallet::getter(fn failed_payout_attempts)]
+    pub type FailedPayoutAttempts<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// 已触发的基金会解锁里程碑数量（已解锁比例 / VestingMilestoneStep）
+    #[pallet::storage]
+    #[pallet::getter(fn vesting_milestones_reached)]
+    pub type VestingMilestonesReached<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 禁止领取奖励的账户（治理维护），所有奖励发放都会跳过这些账户
+    #[pallet::storage]
+    #[pallet::getter(fn is_reward_denied)]
+    pub type RewardDenylist<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        bool,
+        ValueQuery,
+    >;
+
     /// 最后一次月度奖励发放的区块号
     #[pallet::storage]
     #[pallet::getter(fn last_monthly_reward_block)]
     pub type LastMonthlyRewardBlock<T: Config> = StorageValue<_, BlockNumberFor<T>, ValueQuery>;
 
+    #[pallet::type_value]
+    pub fn DefaultDistributionInterval<T: Config>() -> BlockNumberFor<T> {
+        MONTH_BLOCKS.into()
+    }
+
+    /// 周期性奖励发放的间隔区块数（治理可调），未设置时为 MONTH_BLOCKS
+    #[pallet::storage]
+    #[pallet::getter(fn distribution_interval)]
+    pub type DistributionInterval<T: Config> =
+        StorageValue<_, BlockNumberFor<T>, ValueQuery, DefaultDistributionInterval<T>>;
+
+    /// 已完成的月度发放次数，同时是下一次发放记录的月份序号
+    #[pallet::storage]
+    #[pallet::getter(fn distribution_month_count)]
+    pub type DistributionMonthCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 历史月度发放记录：(月份序号, 发放汇总)，只保留最近 MaxDistributionHistory 个月
+    #[pallet::storage]
+    #[pallet::getter(fn monthly_distribution)]
+    pub type MonthlyDistributionLog<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u32,
+        DistributionSummary<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
     // -------------------------- 事件 --------------------------
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
@@ -216,21 +482,43 @@ pub mod pallet {
         IncentivePoolInitialized { balance: BalanceOf<T>, pool_account: T::AccountId },
         
         /// 激励池动态释放成功
-        IncentivePoolReleased { amount: BalanceOf<T>, new_balance: BalanceOf<T>, pool_account: T::AccountId },
+        IncentivePoolReleased { amount: BalanceOf<T>, new_balance: BalanceOf<T>, ratio: Perbill, pool_account: T::AccountId },
+
+        /// 本次动态释放跳过：InitialIncentivePool 已全部释放
+        ReleaseSkippedExhausted { released: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 本次动态释放跳过：激励池账户锁定的余额不足，实际未能解锁任何金额
+        ReleaseSkippedInsufficientBalance { required: BalanceOf<T>, reserved: BalanceOf<T>, pool_account: T::AccountId },
         
         /// 数据创建者：首次创建元证奖励发放
+        /// Topics: `event_topic(recipient)`, `event_topic(asset_id)`
         FirstCreateRewardDistributed { recipient: T::AccountId, amount: BalanceOf<T>, asset_id: AssetId, pool_account: T::AccountId },
         
         /// 数据创建者：优质数据奖励发放
+        /// Topics: `event_topic(recipient)`, `event_topic(asset_id)`
         QualityDataRewardDistributed { recipient: T::AccountId, amount: BalanceOf<T>, asset_id: AssetId, pool_account: T::AccountId },
                 
         /// 市场运营者：优质市场月度奖励发放
         TopMarketRewardDistributed { recipient: T::AccountId, amount: BalanceOf<T>, market_id: [u8; 32], pool_account: T::AccountId },
+
+        /// 市场运营者：代理发行权证奖励发放
+        IssuanceOperatorRewardDistributed { market: T::AccountId, amount: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 市场运营者：按月交易额计算的基础奖励发放
+        OperatorVolumeRewardDistributed {
+            recipient: T::AccountId,
+            amount: BalanceOf<T>,
+            market_id: [u8; 32],
+            monthly_volume: BalanceOf<T>,
+            pool_account: T::AccountId,
+        },
         
         /// 交易者：手续费返还发放
+        /// Topics: `event_topic(recipient)`
         TraderRebateDistributed { recipient: T::AccountId, amount: BalanceOf<T>, monthly_volume: BalanceOf<T>, pool_account: T::AccountId },
         
         /// 交易者：流动性奖励发放
+        /// Topics: `event_topic(recipient)`
         LiquidityRewardDistributed { recipient: T::AccountId, amount: BalanceOf<T>, order_amount: BalanceOf<T>, pool_account: T::AccountId },
         
         /// 治理参与者：投票奖励发放
@@ -238,10 +526,61 @@ pub mod pallet {
         
         /// 治理参与者：提案通过奖励发放
         GovernanceProposalRewardDistributed { recipient: T::AccountId, amount: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 提交提案时锁定保证金
+        ProposalBondReserved { proposer: T::AccountId, amount: BalanceOf<T> },
+
+        /// 提案通过，或被否决但支持率达到 ProposalSupportThreshold，保证金退还
+        ProposalBondReturned { proposer: T::AccountId, amount: BalanceOf<T>, support: Perbill },
+
+        /// 提案被否决且支持率低于 ProposalSupportThreshold，保证金罚没转入激励池
+        ProposalBondSlashed { proposer: T::AccountId, amount: BalanceOf<T>, support: Perbill, pool_account: T::AccountId },
                 
+        /// 激励池账户实际余额与经济模型配置不一致
+        IncentivePoolMisconfigured { expected: BalanceOf<T>, actual: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 存储迁移把固定地址激励池账户的全部余额（含锁定部分）转入 PalletId 派生账户
+        PoolAccountMigrated { from: T::AccountId, to: T::AccountId, balance: BalanceOf<T> },
+
+        /// 激励池追加注资
+        IncentivePoolFunded { source: T::AccountId, amount: BalanceOf<T>, pool_account: T::AccountId },
+
         /// 激励池余额不足，奖励发放失败
         IncentivePoolInsufficientBalance { required: BalanceOf<T>, available: BalanceOf<T>, pool_account: T::AccountId },
         
+        /// 奖励低于最小发放额，已累积到待发放奖励中
+        RewardBelowMinimum { recipient: T::AccountId, amount: BalanceOf<T>, pending: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 资产所有者账户不存在，优质数据奖励已托管到待发放奖励中
+        RewardEscrowed { recipient: T::AccountId, amount: BalanceOf<T>, asset_id: AssetId, pending: BalanceOf<T> },
+
+        /// 待发放奖励已领取
+        PendingRewardClaimed { recipient: T::AccountId, amount: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 基金会储备解锁达到新的里程碑
+        VestingMilestone { account: T::AccountId, released: BalanceOf<T>, remaining: BalanceOf<T>, milestone: Perbill },
+
+        /// 账户在禁止名单中，本次奖励不发放
+        RewardDenied { recipient: T::AccountId },
+
+        /// 账户加入奖励禁止名单
+        AddedToDenylist { account: T::AccountId },
+
+        /// 账户移出奖励禁止名单
+        RemovedFromDenylist { account: T::AccountId },
+
+        /// 奖励无法送达，已转入 UndistributedRewardAccount 暂存
+        RewardUndeliverable { market_id: [u8; 32], amount: BalanceOf<T>, parked_at: T::AccountId },
+
+        /// 之前转账失败的奖励在本轮重试成功
+        FailedPayoutRetried { recipient: T::AccountId, amount: BalanceOf<T>, pool_account: T::AccountId },
+
+        /// 奖励重试 MaxPayoutRetries 次仍失败，已放弃发放（金额留在激励池）
+        PayoutAbandoned { recipient: T::AccountId, amount: BalanceOf<T>, attempts: u32 },
+
+        /// 批量登记治理投票权重（count 为本批条数）
+        VotingWeightsRegistered { count: u32 },
+
         /// 奖励参数更新（治理操作）
         RewardParameterUpdated { parameter_name: Vec<u8>, old_value: Vec<u8>, new_value: Vec<u8>, pool_account: T::AccountId },
     }
@@ -272,12 +611,27 @@ pub mod pallet {
         
         /// 市场不存在
         MarketNotFound,
+
+        /// 订单所在的市场未注册或未处于 Active 状态
+        UnregisteredMarket,
         
         /// 参数更新权限不足（需治理权限）
         UnauthorizedToUpdateParameter,
         
         /// 参数值无效（如比例超过100%）
         InvalidParameterValue,
+
+        /// 批量操作为空或超过 MaxBatchSize
+        BatchTooLarge,
+
+        /// 可用余额不足以锁定提案保证金
+        InsufficientProposalBond,
+
+        /// 提案人没有未结算的提案保证金
+        ProposalBondNotFound,
+
+        /// 没有可领取的待发放奖励
+        NoPendingReward,
     }
 
     // -------------------------- Hooks（周期性任务） --------------------------
@@ -289,11 +643,19 @@ pub mod pallet {
             
             // 月度奖励发放
             let last_block = Self::last_monthly_reward_block();
-            if current_block.saturating_sub(last_block) >= MONTH_BLOCKS.into() {
+            if current_block.saturating_sub(last_block) >= Self::distribution_interval() {
                 weight = weight.saturating_add(Self::dynamic_release_incentive_pool());
                 weight = weight.saturating_add(Self::distribute_monthly_rewards());
                 LastMonthlyRewardBlock::<T>::put(current_block);
             }
+
+            // 分页发放市场交易额奖励，月度发放所在区块即发放第一页
+            weight = weight.saturating_add(Self::process_operator_volume_rewards());
+
+            // 基金会解锁进度
+            if (current_block % VESTING_CHECK_BLOCKS.into()).is_zero() {
+                weight = weight.saturating_add(Self::check_vesting_milestone());
+            }
             
             weight
         }
@@ -301,30 +663,53 @@ pub mod pallet {
         /// 链启动时初始化辅助存储 、激励池初始化！！！！！！！！！！！！！
         /// 创世块（区块0）的构建过程中，on_runtime_upgrade钩子是不会被调用的。on_runtime_upgrade只会在链上升级时调用
         fn on_runtime_upgrade() -> Weight {
-            if StorageVersion::get::<Self>() < STORAGE_VERSION {
+            Self::warn_rewards_below_unit();
+            let on_chain = StorageVersion::get::<Self>();
+            let mut weight = Weight::zero();
+            // v2：先把旧账户的资金转入派生账户，首次初始化（v1）才能在派生账户上锁定
+            if on_chain < STORAGE_VERSION {
+                weight = weight.saturating_add(Self::migrate_legacy_pool_account());
+            }
+            if on_chain < StorageVersion::new(1) {
                 let pool_account = incentive_pool_account::<T>();
                 let actual_balance = <T as Config>::Currency::free_balance(&pool_account);
                 let expected_balance = T::InitialIncentivePool::get();
                 
-                if actual_balance != expected_balance {
-                    log::warn!("创世配置激励池余额与经济模型不一致");
+                if let Err((expected, actual)) = Self::check_pool_funding() {
+                    log::error!(
+                        "创世配置激励池余额与经济模型不一致: expected={:?}, actual={:?}",
+                        expected, actual
+                    );
+                    Self::deposit_event(Event::IncentivePoolMisconfigured {
+                        expected,
+                        actual,
+                        pool_account: pool_account.clone(),
+                    });
                 }
                 
                 // 执行首次释放（链启动时立即释放1%）
-                let initial_release = T::DynamicReleaseRatio::get() * expected_balance;
-                let locked_amount = expected_balance.saturating_sub(initial_release);
+                // 释放额不能超过账户实际余额，否则 IncentivePoolReleased 会记录并不存在的资金
+                let target_release = T::DynamicReleaseRatio::get() * expected_balance;
+                let initial_release = target_release.min(actual_balance);
+                if initial_release < target_release {
+                    log::warn!(
+                        "激励池余额不足，首次释放额从 {:?} 调整为实际余额 {:?}",
+                        target_release, initial_release
+                    );
+                }
+                let mut locked_amount = expected_balance.saturating_sub(target_release);
                 
                 // 使用 reserve 机制锁定未释放部分（与质押模块相同）
                 if let Err(e) = T::Currency::reserve(&pool_account, locked_amount) {
                     log::error!("激励池资金锁定失败: {:?}", e);
-                    // 继续执行，但记录错误
+                    // 继续执行，但记录错误；未能锁定的资金不计入 IncentivePoolReserved
+                    locked_amount = BalanceOf::<T>::zero();
                 }
                 
                 IncentivePoolReleased::<T>::put(initial_release);
                 IncentivePoolUsed::<T>::put(BalanceOf::<T>::zero());
                 IncentivePoolReserved::<T>::put(locked_amount);
                 LastMonthlyRewardBlock::<T>::put(BlockNumberFor::<T>::zero());
-                StorageVersion::new(1).put::<Self>();
                 
                 Self::deposit_event(Event::IncentivePoolInitialized { 
                     balance: actual_balance,
@@ -333,12 +718,33 @@ pub mod pallet {
                 Self::deposit_event(Event::IncentivePoolReleased {
                     amount: initial_release,
                     new_balance: initial_release,
+                    ratio: T::DynamicReleaseRatio::get(),
                     pool_account: pool_account.clone(),
                 });
                 
-                T::DbWeight::get().writes(4)
-            } else {
-                Weight::zero()
+                weight = weight.saturating_add(T::DbWeight::get().writes(4));
+            }
+            if on_chain < STORAGE_VERSION {
+                STORAGE_VERSION.put::<Self>();
+                weight = weight.saturating_add(T::DbWeight::get().writes(1));
+            }
+            weight
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn try_state(_n: BlockNumberFor<T>) -> Result<(), sp_runtime::TryRuntimeError> {
+            Self::do_try_state()
+        }
+
+        /// 奖励常量低于 ExistentialDeposit 时发放会失败或产生粉尘账户，属于配置错误
+        fn integrity_test() {
+            let existential_deposit = T::Currency::minimum_balance();
+            for (name, reward) in Self::reward_constants() {
+                assert!(
+                    reward >= existential_deposit,
+                    "奖励常量 {} 低于 ExistentialDeposit，请检查是否按 UNIT 配置",
+                    name
+                );
             }
         }
     }
@@ -374,14 +780,19 @@ pub mod pallet {
                     return Err(Error::<T>::OwnerAccountIsEmpty.into());
                 }
                 Err(pallet_shared_traits::AssetQueryError::OwnerAccountDoesNotExist) => {
-                    log::warn!("资产所有者账户不存在，但仍尝试分发奖励 {:?}", asset_id);
-                    return Err(Error::<T>::OwnerAccountDoesNotExist.into());
+                    // 所有者账户已被回收：按配置托管奖励，等待账户重新创建后领取
+                    let owner = T::DataAssetProvider::get_recorded_owner(&asset_id)
+                        .filter(|_| T::EscrowRewardsForMissingOwner::get())
+                        .ok_or(Error::<T>::OwnerAccountDoesNotExist)?;
+                    log::warn!("资产所有者账户不存在，优质数据奖励转入托管 {:?}", asset_id);
+                    Self::do_escrow_quality_data_reward(&owner, &asset_id)?;
                 }
             }
             Ok(())
         }
 
-        /// 4. 登记市场月交易额（市场运营者调用，用于优质市场判定）
+        /// 4. 登记市场月交易额（用于优质市场判定和交易额奖励）
+        /// 只接受 Root 或启用中的注册市场为自己登记，market_id 必须解析为调用者账户
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::register_market_monthly_volume())]
         pub fn register_market_monthly_volume(
@@ -389,12 +800,15 @@ pub mod pallet {
             market_id: [u8; 32],
             volume: BalanceOf<T>,
         ) -> DispatchResult {
-            let _who = ensure_signed(origin)?;
+            if let Some(who) = ensure_signed_or_root(origin)? {
+                let market = T::AccountId::decode(&mut &market_id[..]).map_err(|_| Error::<T>::UnregisteredMarket)?;
+                ensure!(market == who && T::MarketProvider::is_active_market(&who), Error::<T>::UnregisteredMarket);
+            }
             MarketMonthlyVolume::<T>::insert(&market_id, volume);
             Ok(())
         }
 
-        /// 5. 登记治理投票权重（治理模块调用）
+        /// 5. 登记治理投票权重（治理模块调用），权重为 0 时删除该投票者的记录而不是写入 0
         #[pallet::call_index(4)]
         #[pallet::weight(T::WeightInfo::register_voting_weight())]
         pub fn register_voting_weight(
@@ -403,19 +817,331 @@ pub mod pallet {
             weight: BalanceOf<T>,
         ) -> DispatchResult {
             ensure_root(origin)?;
-            GovernanceVotingWeight::<T>::insert(&voter, weight);
+            if weight.is_zero() {
+                GovernanceVotingWeight::<T>::remove(&voter);
+            } else {
+                GovernanceVotingWeight::<T>::insert(&voter, weight);
+            }
+            Ok(())
+        }
+
+        /// 6. 追加注资激励池（仅治理权限），从 sudo 控制的 source 账户转入，注入部分立即可用
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::fund_incentive_pool())]
+        pub fn fund_incentive_pool(
+            origin: OriginFor<T>,
+            source: T::AccountId,
+            amount: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(!amount.is_zero(), Error::<T>::InvalidParameterValue);
+
+            let pool_account = incentive_pool_account::<T>();
+            <T as Config>::Currency::transfer(
+                &source,
+                &pool_account,
+                amount,
+                ExistenceRequirement::KeepAlive,
+            )?;
+            IncentivePoolFunded::<T>::mutate(|funded| *funded = (*funded).saturating_add(amount));
+
+            Self::deposit_event(Event::IncentivePoolFunded { source, amount, pool_account });
+            Ok(())
+        }
+
+        /// 7. 将账户加入奖励禁止名单（仅治理权限）
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::add_to_denylist())]
+        pub fn add_to_denylist(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            RewardDenylist::<T>::insert(&account, true);
+            Self::deposit_event(Event::AddedToDenylist { account });
+            Ok(())
+        }
+
+        /// 8. 将账户移出奖励禁止名单（仅治理权限）
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::remove_from_denylist())]
+        pub fn remove_from_denylist(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+            ensure_root(origin)?;
+            RewardDenylist::<T>::remove(&account);
+            Self::deposit_event(Event::RemovedFromDenylist { account });
+            Ok(())
+        }
+
+        /// 9. 批量登记治理投票权重（治理模块调用），权重累加到已有值上，同一投票者可出现多次
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::register_voting_weights_batch(weights.len() as u32))]
+        pub fn register_voting_weights_batch(
+            origin: OriginFor<T>,
+            weights: Vec<(T::AccountId, BalanceOf<T>)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(
+                !weights.is_empty() && weights.len() as u32 <= T::MaxBatchSize::get(),
+                Error::<T>::BatchTooLarge
+            );
+
+            let count = weights.len() as u32;
+            // 零权重不改变累加结果，跳过以免写入空记录
+            for (voter, weight) in weights.into_iter().filter(|(_, weight)| !weight.is_zero()) {
+                GovernanceVotingWeight::<T>::mutate(&voter, |w| *w = w.saturating_add(weight));
+            }
+            Self::deposit_event(Event::VotingWeightsRegistered { count });
+            Ok(())
+        }
+
+        /// 10. 设置周期性奖励发放的间隔区块数（仅治理权限），必须大于 0
+        /// 下一次发放在距上次发放满 interval 个区块时触发
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::set_distribution_interval())]
+        pub fn set_distribution_interval(origin: OriginFor<T>, interval: BlockNumberFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+            ensure!(!interval.is_zero(), Error::<T>::InvalidParameterValue);
+
+            let old_interval = DistributionInterval::<T>::get();
+            DistributionInterval::<T>::put(interval);
+
+            Self::deposit_event(Event::RewardParameterUpdated {
+                parameter_name: b"DistributionInterval".to_vec(),
+                old_value: old_interval.encode(),
+                new_value: interval.encode(),
+                pool_account: incentive_pool_account::<T>(),
+            });
+            Ok(())
+        }
+
+        /// 11. 领取待发放奖励（包括账户被回收期间托管的优质数据奖励），一次性全部转出
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::claim_pending_reward())]
+        pub fn claim_pending_reward(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let amount = Self::pending_reward(&who);
+            ensure!(!amount.is_zero(), Error::<T>::NoPendingReward);
+
+            Self::transfer_from_incentive_pool(&who, amount)?;
+            PendingReward::<T>::remove(&who);
+
+            Self::deposit_event(Event::PendingRewardClaimed {
+                recipient: who,
+                amount,
+                pool_account: incentive_pool_account::<T>(),
+            });
             Ok(())
         }
     }
 }
 
+/// 月度统计窗口在边界处的快照：取出的条目同时从存储中删除，
+/// 之后登记的交易额和投票权重（包括同一区块内稍后登记的）留在存储中计入下一个窗口
+struct MonthlyStatistics<T: Config> {
+    market_volumes: Vec<([u8; 32], BalanceOf<T>)>,
+    trader_volumes: Vec<(T::AccountId, BalanceOf<T>)>,
+    voting_weights: Vec<(T::AccountId, BalanceOf<T>)>,
+}
+
 // -------------------------- 核心逻辑实现 --------------------------
 impl<T: Config> Pallet<T> {
-    /// 获取激励池可用余额（已释放 - 已使用）
+    /// 获取激励池可用余额（已释放 + 追加注资 - 已使用）
     fn get_available_balance() -> BalanceOf<T> {
         let released = Self::incentive_pool_released();
+        let funded = Self::incentive_pool_funded();
         let used = Self::incentive_pool_used();
-        released.saturating_sub(used)
+        released.saturating_add(funded).saturating_sub(used)
+    }
+
+    /// 事件主题：字段值的 T::Hashing 哈希，订阅方用同样的方法计算后按主题过滤
+    pub fn event_topic<V: Encode>(value: &V) -> T::Hash {
+        T::Hashing::hash_of(value)
+    }
+
+    /// 带索引主题发出事件，轻客户端不必扫描全部事件
+    fn deposit_indexed_event(event: Event<T>, topics: &[T::Hash]) {
+        let event = <T as Config>::RuntimeEvent::from(event).into();
+        frame_system::Pallet::<T>::deposit_event_indexed(topics, event);
+    }
+
+    /// 将无法送达的奖励转入 UndistributedRewardAccount；转账失败时奖励留在激励池，不计入已使用额度
+    fn park_undeliverable_reward(market_id: &[u8; 32], amount: BalanceOf<T>) {
+        let parked_at = T::UndistributedRewardAccount::get();
+        if let Err(e) = Self::transfer_from_incentive_pool(&parked_at, amount) {
+            log::error!("无法送达的奖励暂存失败：market_id={:?}, error={:?}", market_id, e);
+            return;
+        }
+        Self::deposit_event(Event::RewardUndeliverable { market_id: *market_id, amount, parked_at });
+    }
+
+    /// 记录转账失败的奖励，下一轮发放开始时重试
+    fn record_failed_payout(recipient: &T::AccountId, amount: BalanceOf<T>) {
+        FailedPayouts::<T>::mutate(recipient, |failed| {
+            *failed = Some(failed.unwrap_or_else(Zero::zero).saturating_add(amount));
+        });
+    }
+
+    /// 重试之前转账失败的奖励（在处理本轮新奖励之前执行）
+    /// 成功后清除记录；失败次数达到 MaxPayoutRetries 时放弃该笔奖励并发出 PayoutAbandoned
+    pub(crate) fn retry_failed_payouts() -> Weight {
+        let mut weight = Weight::zero();
+        let pool_account = incentive_pool_account::<T>();
+        let failed: Vec<(T::AccountId, BalanceOf<T>)> = FailedPayouts::<T>::iter().collect();
+
+        for (recipient, amount) in failed {
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
+            match Self::transfer_from_incentive_pool(&recipient, amount) {
+                Ok(()) => {
+                    FailedPayouts::<T>::remove(&recipient);
+                    FailedPayoutAttempts::<T>::remove(&recipient);
+                    Self::deposit_event(Event::FailedPayoutRetried {
+                        recipient,
+                        amount,
+                        pool_account: pool_account.clone(),
+                    });
+                }
+                Err(e) => {
+                    let attempts = FailedPayoutAttempts::<T>::get(&recipient).saturating_add(1);
+                    log::warn!("奖励重试转账失败：recipient={:?}, attempts={}, error={:?}", recipient, attempts, e);
+                    if attempts >= T::MaxPayoutRetries::get() {
+                        FailedPayouts::<T>::remove(&recipient);
+                        FailedPayoutAttempts::<T>::remove(&recipient);
+                        Self::deposit_event(Event::PayoutAbandoned { recipient, amount, attempts });
+                    } else {
+                        FailedPayoutAttempts::<T>::insert(&recipient, attempts);
+                    }
+                }
+            }
+        }
+
+        weight
+    }
+
+    /// 接收者在禁止名单中时发出 RewardDenied 并返回 true，调用方应跳过本次发放
+    fn reward_denied(recipient: &T::AccountId) -> bool {
+        if Self::is_reward_denied(recipient) {
+            Self::deposit_event(Event::RewardDenied { recipient: recipient.clone() });
+            return true;
+        }
+        false
+    }
+
+    /// 激励池记账不变量检查（try-runtime 与测试中每块执行）
+    ///
+    /// 1. 已使用额度不超过可用总额度：IncentivePoolUsed <= IncentivePoolReleased + IncentivePoolFunded
+    /// 2. 释放额度与锁定额度之和不超过经济模型总量：IncentivePoolReleased + IncentivePoolReserved <= InitialIncentivePool
+    /// 3. 记账的锁定额度确实被锁定在激励池账户：IncentivePoolReserved <= reserved_balance(激励池)
+    /// 4. 待发放奖励没有零额记录（托管的奖励可以超过最小发放额，需主动领取）
+    /// 5. 固定额度的奖励常量都不低于 ExistentialDeposit
+    #[cfg(any(feature = "try-runtime", test))]
+    pub(crate) fn do_try_state() -> Result<(), sp_runtime::TryRuntimeError> {
+        let released = Self::incentive_pool_released();
+        let funded = Self::incentive_pool_funded();
+        let used = Self::incentive_pool_used();
+        let reserved = Self::incentive_pool_reserved();
+
+        ensure!(
+            used <= released.saturating_add(funded),
+            "IncentivePoolUsed 超过已释放与注资额度之和"
+        );
+        ensure!(
+            released.saturating_add(reserved) <= T::InitialIncentivePool::get(),
+            "IncentivePoolReleased 与 IncentivePoolReserved 之和超过 InitialIncentivePool"
+        );
+        ensure!(
+            reserved <= T::Currency::reserved_balance(&incentive_pool_account::<T>()),
+            "IncentivePoolReserved 超过激励池账户实际锁定余额"
+        );
+        ensure!(
+            PendingReward::<T>::iter_values().all(|pending| !pending.is_zero()),
+            "PendingReward 存在零额记录"
+        );
+        let existential_deposit = T::Currency::minimum_balance();
+        ensure!(
+            Self::reward_constants().iter().all(|(_, reward)| *reward >= existential_deposit),
+            "奖励常量低于 ExistentialDeposit"
+        );
+
+        Ok(())
+    }
+
+    /// 以 DAT 计价的固定额度奖励常量及其名称，用于检查精度配置
+    fn reward_constants() -> [(&'static str, BalanceOf<T>); 6] {
+        [
+            ("FirstCreateReward", T::FirstCreateReward::get()),
+            ("QualityDataReward", T::QualityDataReward::get()),
+            ("TopMarketMonthlyReward", T::TopMarketMonthlyReward::get()),
+            ("GovernanceVotingRewardTotal", T::GovernanceVotingRewardTotal::get()),
+            ("GovernanceProposalReward", T::GovernanceProposalReward::get()),
+            ("ValidatorVerificationReward", T::ValidatorVerificationReward::get()),
+        ]
+    }
+
+    /// 低于一个 Unit 的奖励常量名称（很可能漏乘了 UNIT，如 1000 被当成 1000 DAT）
+    pub(crate) fn rewards_below_unit() -> Vec<&'static str> {
+        let unit = T::Unit::get();
+        Self::reward_constants()
+            .into_iter()
+            .filter(|(_, reward)| *reward < unit)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// 启动时（创世或运行时升级）对疑似未按精度配置的奖励常量输出警告
+    fn warn_rewards_below_unit() {
+        for name in Self::rewards_below_unit() {
+            log::warn!("奖励常量 {} 低于 1 UNIT，可能未按精度配置", name);
+        }
+    }
+
+    /// 校验激励池账户余额（含已锁定部分）是否与 InitialIncentivePool 一致，不一致时返回 (expected, actual)
+    fn check_pool_funding() -> Result<(), (BalanceOf<T>, BalanceOf<T>)> {
+        let pool_account = incentive_pool_account::<T>();
+        let expected = T::InitialIncentivePool::get();
+        let actual = T::Currency::total_balance(&pool_account);
+        if actual == expected {
+            Ok(())
+        } else {
+            Err((expected, actual))
+        }
+    }
+
+    /// v1 -> v2：把固定地址激励池账户的全部余额转入 PalletId 派生账户
+    /// 锁定部分先作为可用余额转入（同时创建新账户），再在新账户上重新锁定；
+    /// 已释放、已使用、追加注资等记账金额与账户无关保持不变，未能重新锁定的金额从 IncentivePoolReserved 中扣除
+    fn migrate_legacy_pool_account() -> Weight {
+        let from = legacy_incentive_pool_account::<T>();
+        let to = incentive_pool_account::<T>();
+        if from == to {
+            return T::DbWeight::get().reads(1);
+        }
+
+        let reserved = T::Currency::reserved_balance(&from);
+        let unmoved = T::Currency::repatriate_reserved(&from, &to, reserved, BalanceStatus::Free)
+            .unwrap_or(reserved);
+        let moved_reserved = reserved.saturating_sub(unmoved);
+
+        let free = T::Currency::free_balance(&from);
+        if let Err(e) = T::Currency::transfer(&from, &to, free, ExistenceRequirement::AllowDeath) {
+            log::error!("激励池旧账户余额迁移失败: {:?}", e);
+        }
+        let moved_free = free.saturating_sub(T::Currency::free_balance(&from));
+
+        let relocked = match T::Currency::reserve(&to, moved_reserved) {
+            Ok(()) => moved_reserved,
+            Err(e) => {
+                log::error!("激励池新账户重新锁定失败: {:?}", e);
+                BalanceOf::<T>::zero()
+            }
+        };
+        let lost_lock = reserved.saturating_sub(relocked);
+        if !lost_lock.is_zero() {
+            IncentivePoolReserved::<T>::mutate(|locked| *locked = locked.saturating_sub(lost_lock));
+        }
+
+        let balance = moved_free.saturating_add(moved_reserved);
+        if !balance.is_zero() {
+            Self::deposit_event(Event::PoolAccountMigrated { from, to, balance });
+        }
+        T::DbWeight::get().reads_writes(4, 4)
     }
 
     /// 内部转账函数，处理从激励池转账并更新已使用金额
@@ -447,6 +1173,31 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// 发放小额奖励：与已累积的待发放奖励合并，低于 MinRewardPayout 时只累积不转账。
+    /// 返回实际转账的金额（仅累积时为0）
+    fn pay_or_accrue_reward(
+        recipient: &T::AccountId,
+        amount: BalanceOf<T>,
+    ) -> Result<BalanceOf<T>, DispatchError> {
+        let total = Self::pending_reward(recipient).saturating_add(amount);
+
+        if total < T::MinRewardPayout::get() {
+            PendingReward::<T>::insert(recipient, total);
+            Self::deposit_event(Event::RewardBelowMinimum {
+                recipient: recipient.clone(),
+                amount,
+                pending: total,
+                pool_account: incentive_pool_account::<T>(),
+            });
+            return Ok(BalanceOf::<T>::zero());
+        }
+
+        Self::transfer_from_incentive_pool(recipient, total)?;
+        PendingReward::<T>::remove(recipient);
+
+        Ok(total)
+    }
+
     /// 1. 激励池动态释放（从创世配置的账户余额中释放）
     fn dynamic_release_incentive_pool() -> Weight {
         let pool_account = incentive_pool_account::<T>();
@@ -456,14 +1207,18 @@ impl<T: Config> Pallet<T> {
         let remaining = total_initial.saturating_sub(released);
         
         if remaining.is_zero() {
+            Self::deposit_event(Event::ReleaseSkippedExhausted { released, pool_account });
             return Weight::zero();
         }
 
-        let release_ratio = T::DynamicReleaseRatio::get();
-        let release_amount = release_ratio * remaining;
-        if release_amount.is_zero() {
-            return Weight::zero();
-        }
+        let release_ratio = Self::effective_release_ratio();
+        // 按比例释放永远无法精确到达总额，剩余额度很小（或按比例计算为 0）时直接释放全部剩余
+        let proportional = release_ratio * remaining;
+        let release_amount = if remaining <= T::FinalReleaseThreshold::get() || proportional.is_zero() {
+            remaining
+        } else {
+            proportional
+        };
 
         // 未解除锁定的金额
         let remaining_reserved = T::Currency::unreserve(&pool_account, release_amount);
@@ -473,6 +1228,11 @@ impl<T: Config> Pallet<T> {
 
         if actual_unreserved.is_zero() {
             log::error!("激励池资金释放失败: 实际释放为 0");
+            Self::deposit_event(Event::ReleaseSkippedInsufficientBalance {
+                required: release_amount,
+                reserved: T::Currency::reserved_balance(&pool_account),
+                pool_account,
+            });
             return Weight::zero();
         }
 
@@ -485,38 +1245,134 @@ impl<T: Config> Pallet<T> {
         Self::deposit_event(Event::IncentivePoolReleased {
             amount: actual_unreserved,
             new_balance: new_released,
+            ratio: release_ratio,
             pool_account: pool_account.clone(),
         });
 
         T::DbWeight::get().writes(2)
     }
 
+    /// 下一次月度奖励发放所在的区块：LastMonthlyRewardBlock + DistributionInterval
+    /// 该区块已经过去（间隔被治理调小，或已到期尚未执行）时，发放在下一个区块的 on_initialize 中执行
+    pub fn next_distribution_block() -> BlockNumberFor<T> {
+        let scheduled = Self::last_monthly_reward_block().saturating_add(Self::distribution_interval());
+        let next_block = frame_system::Pallet::<T>::block_number().saturating_add(One::one());
+        scheduled.max(next_block)
+    }
+
+    /// 账户在 pallet_vesting 中仍处于锁定状态的余额
+    pub fn vesting_remaining(account: &T::AccountId) -> BalanceOf<T> {
+        T::FoundationVesting::vesting_balance(account).unwrap_or_else(Zero::zero)
+    }
+
+    /// 检查基金会储备的解锁进度，每跨过一个 VestingMilestoneStep 触发一次事件
+    pub(crate) fn check_vesting_milestone() -> Weight {
+        let step = T::VestingMilestoneStep::get().deconstruct();
+        let total = T::FoundationVestingTotal::get();
+        if step == 0 || total.is_zero() {
+            return Weight::zero();
+        }
+
+        let account = T::FoundationAccount::get();
+        let remaining = Self::vesting_remaining(&account).min(total);
+        let released = total.saturating_sub(remaining);
+        let reached = Perbill::from_rational(released, total).deconstruct() / step;
+
+        if reached <= VestingMilestonesReached::<T>::get() {
+            return T::DbWeight::get().reads(2);
+        }
+
+        VestingMilestonesReached::<T>::put(reached);
+        Self::deposit_event(Event::VestingMilestone {
+            account,
+            released,
+            remaining,
+            milestone: Perbill::from_parts(step.saturating_mul(reached)),
+        });
+        T::DbWeight::get().reads_writes(2, 1)
+    }
+
+    /// 按生态活跃度计算本次释放比例
+    ///
+    /// 活跃度 = 本月全部市场交易总额 / TargetMonthlyTradeVolume（最多100%），
+    /// 释放比例 = MinReleaseRatio + 活跃度 * (MaxReleaseRatio - MinReleaseRatio)。
+    /// 本月没有任何交易额数据时使用固定的 DynamicReleaseRatio。
+    pub fn effective_release_ratio() -> Perbill {
+        let total_volume = MarketMonthlyVolume::<T>::iter_values()
+            .fold(BalanceOf::<T>::zero(), |acc, v| acc.saturating_add(v));
+        if total_volume.is_zero() {
+            return T::DynamicReleaseRatio::get();
+        }
+
+        let target = T::TargetMonthlyTradeVolume::get();
+        let activity = if target.is_zero() {
+            Perbill::one()
+        } else {
+            Perbill::from_rational(total_volume, target)
+        };
+
+        let min = T::MinReleaseRatio::get();
+        let max = T::MaxReleaseRatio::get().max(min);
+        let range = max.deconstruct().saturating_sub(min.deconstruct());
+        Perbill::from_parts(min.deconstruct().saturating_add(activity * range))
+    }
+
     /// 2. 月度奖励统一发放（优质市场、交易者返还、治理投票奖励）
+    /// 按 IncentivePoolUsed 的增量统计各类奖励的发放总额，写入 MonthlyDistributionLog
+    /// 本月的统计先整体取出为快照再发放，发放只读快照，不会清除快照之后登记的数据
     fn distribute_monthly_rewards() -> Weight {
-        let mut weight = Weight::zero();
-
-        weight = weight.saturating_add(Self::distribute_top_market_rewards());
-        weight = weight.saturating_add(Self::distribute_trader_rebates());
-        weight = weight.saturating_add(Self::distribute_governance_voting_rewards());
-        Self::reset_monthly_statistics();
+        // 先重试上一轮转账失败的奖励，重试发放的金额不计入本月各类奖励
+        let mut weight = Self::retry_failed_payouts();
+        let pool_balance = T::Currency::free_balance(&incentive_pool_account::<T>());
+        let used_before = Self::incentive_pool_used();
+        let (statistics, take_weight) = Self::take_monthly_statistics();
+        weight = weight.saturating_add(take_weight);
+
+        weight = weight.saturating_add(Self::distribute_top_market_rewards(&statistics));
+        weight = weight.saturating_add(Self::queue_operator_volume_rewards(&statistics));
+        let used_after_markets = Self::incentive_pool_used();
+        weight = weight.saturating_add(Self::distribute_trader_rebates(&statistics));
+        let used_after_rebates = Self::incentive_pool_used();
+        weight = weight.saturating_add(Self::distribute_governance_voting_rewards(&statistics));
+        let used_after_voting = Self::incentive_pool_used();
+
+        Self::record_monthly_distribution(DistributionSummary {
+            block: frame_system::Pallet::<T>::block_number(),
+            top_market: used_after_markets.saturating_sub(used_before),
+            trader_rebate: used_after_rebates.saturating_sub(used_after_markets),
+            governance_voting: used_after_voting.saturating_sub(used_after_rebates),
+            total: used_after_voting.saturating_sub(used_before),
+            pool_balance,
+        });
+        weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 3));
 
         weight
     }
 
+    /// 写入本月发放记录，并删除超出 MaxDistributionHistory 的最早记录
+    fn record_monthly_distribution(summary: DistributionSummary<BalanceOf<T>, BlockNumberFor<T>>) {
+        let month = Self::distribution_month_count();
+        MonthlyDistributionLog::<T>::insert(month, summary);
+        if let Some(expired) = month.checked_sub(T::MaxDistributionHistory::get()) {
+            MonthlyDistributionLog::<T>::remove(expired);
+        }
+        DistributionMonthCount::<T>::put(month.saturating_add(1));
+    }
+
     /// 2.1 优质市场月度奖励发放
-    fn distribute_top_market_rewards() -> Weight {
+    fn distribute_top_market_rewards(statistics: &MonthlyStatistics<T>) -> Weight {
         let mut weight = Weight::zero();
         let reward_per_market = T::TopMarketMonthlyReward::get();
         let pool_account = incentive_pool_account::<T>();
 
         // 收集所有市场
-        let mut markets: Vec<([u8; 32], BalanceOf<T>)> = MarketMonthlyVolume::<T>::iter().collect();
+        let mut markets = statistics.market_volumes.clone();
         if markets.is_empty() {
             return Weight::zero();
         }
 
-        // 按交易额降序排序
-        markets.sort_by(|a, b| b.1.cmp(&a.1));
+        // 按交易额降序排序，交易额相同时按 market_id 升序，保证结果与存储遍历顺序无关
+        markets.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
 
         // 计算优质市场数量（前10%，最少1个）- 使用整数运算
         let top_count = if markets.len() < 10 {
@@ -550,11 +1406,22 @@ impl<T: Config> Pallet<T> {
         for (market_id, _volume) in top_markets {
             // ################: 需要从市场模块获取真实的运营者账户
             // 这里################3使用市场ID作为账户（实际项目中需要修改）
-            let operator = T::AccountId::decode(&mut &market_id[..])
-                .unwrap_or_else(|_| incentive_pool_account::<T>());
+            // 解析失败或解析为激励池自身时奖励无法送达，转入暂存账户而不是向激励池自身转账
+            let operator = match T::AccountId::decode(&mut &market_id[..]) {
+                Ok(operator) if operator != pool_account => operator,
+                _ => {
+                    Self::park_undeliverable_reward(market_id, reward_per_market);
+                    weight = weight.saturating_add(T::DbWeight::get().writes(1));
+                    continue;
+                }
+            };
+            if Self::reward_denied(&operator) {
+                continue;
+            }
 
             if let Err(e) = Self::transfer_from_incentive_pool(&operator, reward_per_market) {
                 log::error!("优质市场奖励转账失败：market_id={:?}, error={:?}", market_id, e);
+                Self::record_failed_payout(&operator, reward_per_market);
                 continue;
             }
 
@@ -571,15 +1438,99 @@ impl<T: Config> Pallet<T> {
         weight
     }
 
+    /// 2.1.1 将本月有交易额的市场加入交易额奖励队列（比例为0时不发放），由 on_initialize 分页发放
+    fn queue_operator_volume_rewards(statistics: &MonthlyStatistics<T>) -> Weight {
+        if T::OperatorVolumeRewardRatio::get().is_zero() {
+            return Weight::zero();
+        }
+
+        let mut queued = 0u64;
+        for (market_id, volume) in statistics.market_volumes.iter() {
+            if volume.is_zero() {
+                continue;
+            }
+            // 上月队列尚未发完时合并交易额
+            PendingOperatorVolumeRewards::<T>::mutate(market_id, |pending| {
+                *pending = Some(pending.unwrap_or_else(Zero::zero).saturating_add(*volume));
+            });
+            queued = queued.saturating_add(1);
+        }
+
+        T::DbWeight::get().reads_writes(queued, queued)
+    }
+
+    /// 2.1.2 从队列中取出至多 MaxOperatorRewardsPerBlock 个市场发放交易额奖励，队列为空时只有一次读取
+    /// 每笔奖励不超过 MaxOperatorVolumeReward，并检查激励池可用余额，余额不足的市场本月不再发放
+    /// 发放时已不是启用中注册市场的运营者不发放
+    fn process_operator_volume_rewards() -> Weight {
+        let max = T::MaxOperatorRewardsPerBlock::get() as usize;
+        let page: Vec<([u8; 32], BalanceOf<T>)> = PendingOperatorVolumeRewards::<T>::iter().take(max).collect();
+        let mut weight = T::DbWeight::get().reads(1);
+        if page.is_empty() {
+            return weight;
+        }
+
+        let ratio = T::OperatorVolumeRewardRatio::get();
+        let pool_account = incentive_pool_account::<T>();
+
+        for (market_id, monthly_volume) in page {
+            PendingOperatorVolumeRewards::<T>::remove(market_id);
+            weight = weight.saturating_add(T::DbWeight::get().reads_writes(2, 2));
+
+            let amount = (ratio * monthly_volume).min(T::MaxOperatorVolumeReward::get());
+            if amount.is_zero() {
+                continue;
+            }
+
+            let available = Self::get_available_balance();
+            if available < amount {
+                Self::deposit_event(Event::IncentivePoolInsufficientBalance {
+                    required: amount,
+                    available,
+                    pool_account: pool_account.clone(),
+                });
+                continue;
+            }
+
+            // 与优质市场奖励一样以市场ID解析运营者账户
+            let operator = match T::AccountId::decode(&mut &market_id[..]) {
+                Ok(operator) if operator != pool_account => operator,
+                _ => {
+                    Self::park_undeliverable_reward(&market_id, amount);
+                    continue;
+                }
+            };
+            if !T::MarketProvider::is_active_market(&operator) || Self::reward_denied(&operator) {
+                continue;
+            }
+
+            if let Err(e) = Self::transfer_from_incentive_pool(&operator, amount) {
+                log::error!("市场交易额奖励转账失败：market_id={:?}, error={:?}", market_id, e);
+                Self::record_failed_payout(&operator, amount);
+                continue;
+            }
+
+            Self::deposit_event(Event::OperatorVolumeRewardDistributed {
+                recipient: operator,
+                amount,
+                market_id,
+                monthly_volume,
+                pool_account: pool_account.clone(),
+            });
+        }
+
+        weight
+    }
+
     /// 2.2 交易者手续费返还发放
-    fn distribute_trader_rebates() -> Weight {
+    fn distribute_trader_rebates(statistics: &MonthlyStatistics<T>) -> Weight {
         let mut weight = Weight::zero();
         let threshold = T::TraderRebateThreshold::get();
         let rebate_ratio = T::TraderRebateRatio::get();
         let pool_account = incentive_pool_account::<T>();
 
-        for (trader, monthly_volume) in TraderMonthlyVolume::<T>::iter() {
-            if monthly_volume < threshold {
+        for (trader, monthly_volume) in statistics.trader_volumes.iter().cloned() {
+            if monthly_volume < threshold || Self::reward_denied(&trader) {
                 continue;
             }
 
@@ -601,15 +1552,16 @@ impl<T: Config> Pallet<T> {
 
             if let Err(e) = Self::transfer_from_incentive_pool(&trader, rebate_amount) {
                 log::error!("交易者手续费返还转账失败：trader={:?}, error={:?}", trader, e);
+                Self::record_failed_payout(&trader, rebate_amount);
                 continue;
             }
 
-            Self::deposit_event(Event::TraderRebateDistributed {
+            Self::deposit_indexed_event(Event::TraderRebateDistributed {
                 recipient: trader.clone(),
                 amount: rebate_amount,
                 monthly_volume,
                 pool_account: pool_account.clone(),
-            });
+            }, &[Self::event_topic(&trader)]);
 
             weight = weight.saturating_add(T::DbWeight::get().writes(1));
         }
@@ -618,7 +1570,7 @@ impl<T: Config> Pallet<T> {
     }
 
     /// 2.3 治理参与者投票奖励发放
-    fn distribute_governance_voting_rewards() -> Weight {
+    fn distribute_governance_voting_rewards(statistics: &MonthlyStatistics<T>) -> Weight {
         let mut weight = Weight::zero();
         let total_reward = T::GovernanceVotingRewardTotal::get();
         let pool_account = incentive_pool_account::<T>();
@@ -635,16 +1587,29 @@ impl<T: Config> Pallet<T> {
         }
 
         // 计算总投票权重
+        // 禁止名单中的投票者和治理质押未生效的投票者不参与分配，也不计入总权重，避免稀释其他投票者
         let mut total_weight = BalanceOf::<T>::zero();
-        for (_, weight_val) in GovernanceVotingWeight::<T>::iter() {
-            total_weight = total_weight.saturating_add(weight_val);
+        for (voter, weight_val) in statistics.voting_weights.iter().cloned() {
+            if weight_val.is_zero() {
+                continue;
+            }
+            if !Self::is_reward_denied(&voter) && T::PledgeStatusProvider::is_governance_pledge_active(&voter) {
+                total_weight = total_weight.saturating_add(weight_val);
+            }
         }
 
         if total_weight.is_zero() {
             return Weight::zero();
         }
 
-        for (voter, weight_val) in GovernanceVotingWeight::<T>::iter() {
+        for (voter, weight_val) in statistics.voting_weights.iter().cloned() {
+            // 零权重分不到奖励，跳过以免查询质押状态和禁止名单
+            if weight_val.is_zero() {
+                continue;
+            }
+            if !T::PledgeStatusProvider::is_governance_pledge_active(&voter) || Self::reward_denied(&voter) {
+                continue;
+            }
             let reward_amount = if let Some(amount) = total_reward.checked_div(&total_weight) {
                 amount.saturating_mul(weight_val)
             } else {
@@ -655,14 +1620,21 @@ impl<T: Config> Pallet<T> {
                 continue;
             }
 
-            if let Err(e) = Self::transfer_from_incentive_pool(&voter, reward_amount) {
-                log::error!("治理投票奖励转账失败：voter={:?}, error={:?}", voter, e);
+            let paid_amount = match Self::pay_or_accrue_reward(&voter, reward_amount) {
+                Ok(paid) => paid,
+                Err(e) => {
+                    log::error!("治理投票奖励转账失败：voter={:?}, error={:?}", voter, e);
+                    Self::record_failed_payout(&voter, reward_amount);
+                    continue;
+                }
+            };
+            if paid_amount.is_zero() {
                 continue;
             }
 
             Self::deposit_event(Event::GovernanceVotingRewardDistributed {
                 recipient: voter.clone(),
-                amount: reward_amount,
+                amount: paid_amount,
                 weight: weight_val,
                 pool_account: pool_account.clone(),
             });
@@ -673,18 +1645,31 @@ impl<T: Config> Pallet<T> {
         weight
     }
 
-    /// 2.4 重置月度统计数据
-    fn reset_monthly_statistics() {
-        // 使用clear替代remove_all
-        let _ = MarketMonthlyVolume::<T>::clear(u32::MAX, None);
-        let _ = TraderMonthlyVolume::<T>::clear(u32::MAX, None);
-        let _ = GovernanceVotingWeight::<T>::clear(u32::MAX, None);
-        let _ = Asset30dTradeCount::<T>::clear(u32::MAX, None);
+    /// 2.4 取出本月统计并重置：逐条读取后删除（drain），只删除已读入快照的条目，
+    /// 不会像整体 clear 那样丢掉读取之后才登记的数据；返回快照及读写权重
+    fn take_monthly_statistics() -> (MonthlyStatistics<T>, Weight) {
+        let statistics = MonthlyStatistics::<T> {
+            market_volumes: MarketMonthlyVolume::<T>::drain().collect(),
+            trader_volumes: TraderMonthlyVolume::<T>::drain().collect(),
+            voting_weights: GovernanceVotingWeight::<T>::drain().collect(),
+        };
+        // 30 天交易笔数不参与月度发放，随窗口一起重置
+        let trade_counts = Asset30dTradeCount::<T>::drain().count();
+
+        let entries = statistics.market_volumes.len()
+            + statistics.trader_volumes.len()
+            + statistics.voting_weights.len()
+            + trade_counts;
+        (statistics, T::DbWeight::get().reads_writes(entries as u64, entries as u64))
     }
 
     /// 3. 数据创建者：首次创建元证奖励（供dataassets模块调用）
     pub fn distribute_first_create_reward(recipient: &T::AccountId, asset_id: &AssetId) -> DispatchResult {
         ensure!(!Self::has_first_create_reward(recipient), Error::<T>::FirstCreateRewardAlreadyClaimed);
+        // 不标记为已领取，移出禁止名单后下次创建仍可领取
+        if Self::reward_denied(recipient) {
+            return Ok(());
+        }
         
         let reward_amount = T::FirstCreateReward::get();
         
@@ -694,22 +1679,21 @@ impl<T: Config> Pallet<T> {
         HasFirstCreateReward::<T>::insert(recipient, true);
 
         let pool_account = incentive_pool_account::<T>();
-        Self::deposit_event(Event::FirstCreateRewardDistributed {
+        Self::deposit_indexed_event(Event::FirstCreateRewardDistributed {
             recipient: recipient.clone(),
             amount: reward_amount,
             asset_id: *asset_id,
             pool_account: pool_account.clone(),
-        });
+        }, &[Self::event_topic(recipient), Self::event_topic(asset_id)]);
 
         Ok(())
     }
 
     /// 4. 数据创建者：优质数据奖励（供自动触发或手动调用）
     fn do_distribute_quality_data_reward(recipient: &T::AccountId, asset_id: &AssetId) -> DispatchResult {
-        let trade_count = Self::asset_30d_trade_count(asset_id);
-        let threshold = T::QualityDataTradeThreshold::get();
-        
-        ensure!(trade_count >= threshold, Error::<T>::QualityDataConditionNotMet);
+        if !Self::quality_data_reward_due(recipient, asset_id)? {
+            return Ok(());
+        }
         
         let reward_amount = T::QualityDataReward::get();
         
@@ -717,40 +1701,163 @@ impl<T: Config> Pallet<T> {
         Self::transfer_from_incentive_pool(recipient, reward_amount)?;
 
         let pool_account = incentive_pool_account::<T>();
-        Self::deposit_event(Event::QualityDataRewardDistributed {
+        Self::deposit_indexed_event(Event::QualityDataRewardDistributed {
             recipient: recipient.clone(),
             amount: reward_amount,
             asset_id: *asset_id,
             pool_account: pool_account.clone(),
-        });
+        }, &[Self::event_topic(recipient), Self::event_topic(asset_id)]);
+
+        Ok(())
+    }
+
+    /// 所有者账户不存在时托管优质数据奖励：条件与正常发放一致，只记入 PendingReward 不转账
+    fn do_escrow_quality_data_reward(owner: &T::AccountId, asset_id: &AssetId) -> DispatchResult {
+        if !Self::quality_data_reward_due(owner, asset_id)? {
+            return Ok(());
+        }
+
+        let amount = T::QualityDataReward::get();
+        let pending = Self::pending_reward(owner).saturating_add(amount);
+        PendingReward::<T>::insert(owner, pending);
+
+        Self::deposit_indexed_event(Event::RewardEscrowed {
+            recipient: owner.clone(),
+            amount,
+            asset_id: *asset_id,
+            pending,
+        }, &[Self::event_topic(owner), Self::event_topic(asset_id)]);
 
         Ok(())
     }
 
-    /// 5. 交易者：流动性奖励（供交易模块调用）
-    pub fn distribute_liquidity_reward(recipient: &T::AccountId, order_amount: BalanceOf<T>) -> DispatchResult {
+    /// 优质数据奖励条件：30天交易笔数达到阈值，且接收者不在禁止名单中（在名单中时返回 false）
+    fn quality_data_reward_due(recipient: &T::AccountId, asset_id: &AssetId) -> Result<bool, DispatchError> {
+        let trade_count = Self::asset_30d_trade_count(asset_id);
+        let threshold = T::QualityDataTradeThreshold::get();
+
+        ensure!(trade_count >= threshold, Error::<T>::QualityDataConditionNotMet);
+        Ok(!Self::reward_denied(recipient))
+    }
+
+    /// 5. 交易者：流动性奖励（供交易模块调用），订单所在的市场必须已注册且处于 Active 状态
+    pub fn distribute_market_liquidity_reward(
+        recipient: &T::AccountId,
+        market_contract: &T::AccountId,
+        order_amount: BalanceOf<T>,
+    ) -> DispatchResult {
+        ensure!(T::MarketProvider::is_active_market(market_contract), Error::<T>::UnregisteredMarket);
+        Self::distribute_liquidity_reward(recipient, order_amount)
+    }
+
+    /// 按订单金额发放流动性奖励，不校验市场；外部模块应调用 distribute_market_liquidity_reward
+    pub(crate) fn distribute_liquidity_reward(recipient: &T::AccountId, order_amount: BalanceOf<T>) -> DispatchResult {
+        // 小额订单不产生奖励，避免粉尘奖励和 PendingReward 存储膨胀
+        if order_amount < T::MinOrderForLiquidityReward::get() {
+            return Ok(());
+        }
         let reward_ratio = T::LiquidityRewardRatio::get();
         let reward_amount = reward_ratio * order_amount;
-        if reward_amount.is_zero() {
+        if reward_amount.is_zero() || Self::reward_denied(recipient) {
             return Ok(());
         }
 
-        // 使用内部转账函数
-        Self::transfer_from_incentive_pool(recipient, reward_amount)?;
+        // 小额奖励先累积，达到最小发放额后再转账
+        let paid_amount = Self::pay_or_accrue_reward(recipient, reward_amount)?;
+        if paid_amount.is_zero() {
+            return Ok(());
+        }
 
         let pool_account = incentive_pool_account::<T>();
-        Self::deposit_event(Event::LiquidityRewardDistributed {
+        Self::deposit_indexed_event(Event::LiquidityRewardDistributed {
             recipient: recipient.clone(),
-            amount: reward_amount,
+            amount: paid_amount,
             order_amount,
             pool_account: pool_account.clone(),
-        });
+        }, &[Self::event_topic(recipient)]);
+
+        Ok(())
+    }
+
+    /// 6. 治理参与者：提交提案时锁定保证金（供治理模块调用）
+    pub fn reserve_proposal_bond(proposer: &T::AccountId) -> DispatchResult {
+        let bond = T::GovernanceProposalBond::get();
+        if bond.is_zero() {
+            return Ok(());
+        }
+
+        T::Currency::reserve(proposer, bond).map_err(|_| Error::<T>::InsufficientProposalBond)?;
+        ProposalBonds::<T>::mutate(proposer, |total| *total = total.saturating_add(bond));
 
+        Self::deposit_event(Event::ProposalBondReserved { proposer: proposer.clone(), amount: bond });
         Ok(())
     }
 
-    /// 6. 治理参与者：提案通过奖励（供治理模块调用）
-    pub fn distribute_proposal_reward(recipient: &T::AccountId) -> DispatchResult {
+    /// 治理参与者：结算提案（供治理模块调用）
+    /// - 通过：退还保证金并发放 GovernanceProposalReward
+    /// - 否决且支持率低于 ProposalSupportThreshold：罚没保证金，转入激励池并计入追加注资
+    /// - 否决但支持率达到阈值：只退还保证金
+    /// 奖励发放失败时在任何写入之前返回错误，保证金保持锁定
+    pub fn distribute_proposal_outcome(proposer: &T::AccountId, passed: bool, support: Perbill) -> DispatchResult {
+        let bond = Self::settled_proposal_bond(proposer)?;
+        let pool_account = incentive_pool_account::<T>();
+
+        if !passed && support < T::ProposalSupportThreshold::get() {
+            let slashed = if bond.is_zero() {
+                bond
+            } else {
+                let missing = T::Currency::repatriate_reserved(
+                    proposer,
+                    &pool_account,
+                    bond,
+                    BalanceStatus::Free,
+                )?;
+                bond.saturating_sub(missing)
+            };
+            Self::remove_proposal_bond(proposer, bond);
+            IncentivePoolFunded::<T>::mutate(|funded| *funded = (*funded).saturating_add(slashed));
+            Self::deposit_event(Event::ProposalBondSlashed {
+                proposer: proposer.clone(),
+                amount: slashed,
+                support,
+                pool_account,
+            });
+            return Ok(());
+        }
+
+        if passed {
+            Self::distribute_proposal_reward(proposer)?;
+        }
+
+        Self::remove_proposal_bond(proposer, bond);
+        T::Currency::unreserve(proposer, bond);
+        Self::deposit_event(Event::ProposalBondReturned { proposer: proposer.clone(), amount: bond, support });
+        Ok(())
+    }
+
+    /// 本次结算的一份保证金（不超过提案人未结算的总额）；GovernanceProposalBond 为 0 时不要求事先锁定
+    fn settled_proposal_bond(proposer: &T::AccountId) -> Result<BalanceOf<T>, DispatchError> {
+        let bond = T::GovernanceProposalBond::get();
+        if bond.is_zero() {
+            return Ok(bond);
+        }
+
+        let outstanding = ProposalBonds::<T>::get(proposer);
+        ensure!(!outstanding.is_zero(), Error::<T>::ProposalBondNotFound);
+        Ok(bond.min(outstanding))
+    }
+
+    fn remove_proposal_bond(proposer: &T::AccountId, amount: BalanceOf<T>) {
+        ProposalBonds::<T>::mutate_exists(proposer, |stored| {
+            *stored = stored.map(|total| total.saturating_sub(amount)).filter(|total| !total.is_zero());
+        });
+    }
+
+    /// 提案通过奖励
+    fn distribute_proposal_reward(recipient: &T::AccountId) -> DispatchResult {
+        if Self::reward_denied(recipient) {
+            return Ok(());
+        }
         let reward_amount = T::GovernanceProposalReward::get();
         
         // 使用内部转账函数
@@ -766,6 +1873,27 @@ impl<T: Config> Pallet<T> {
         Ok(())
     }
 
+    /// 7. 市场运营者：代理发行权证奖励（供dataassets模块调用）
+    /// 奖励为 0 或代理账户不是启用中的注册市场时不发放（任何账户都能被所有者授权代理发行）；
+    /// 激励池可用余额不足时返回错误，由调用方决定是否忽略
+    pub fn reward_issuance_operator(market: &T::AccountId) -> DispatchResult {
+        let reward_amount = T::IssuanceOperatorReward::get();
+        if reward_amount.is_zero() || !T::MarketProvider::is_active_market(market) || Self::reward_denied(market) {
+            return Ok(());
+        }
+
+        Self::transfer_from_incentive_pool(market, reward_amount)?;
+
+        let pool_account = incentive_pool_account::<T>();
+        Self::deposit_event(Event::IssuanceOperatorRewardDistributed {
+            market: market.clone(),
+            amount: reward_amount,
+            pool_account: pool_account.clone(),
+        });
+
+        Ok(())
+    }
+
     /// 登记元证交易笔数（供dataassets模块调用，用于优质数据判定）
     pub fn register_asset_trade(asset_id: &AssetId) {
         Asset30dTradeCount::<T>::mutate(asset_id, |count| *count = count.saturating_add(1));
@@ -781,23 +1909,62 @@ impl<T: Config> Pallet<T> {
     }
 }
 
+impl<T: Config> Pallet<T> {
+    /// 将内部错误映射为对外的 IncentiveError，保留失败原因
+    pub fn incentive_error(error: DispatchError) -> IncentiveError {
+        if error == Error::<T>::InsufficientIncentivePoolBalance.into() {
+            IncentiveError::InsufficientPool
+        } else if error == Error::<T>::FirstCreateRewardAlreadyClaimed.into() {
+            IncentiveError::AlreadyClaimed
+        } else if error == Error::<T>::AssetNotFound.into() {
+            IncentiveError::AssetNotFound
+        } else if error == Error::<T>::UnregisteredMarket.into() {
+            IncentiveError::UnregisteredMarket
+        } else if error == Error::<T>::QualityDataConditionNotMet.into()
+            || error == Error::<T>::TraderRebateConditionNotMet.into()
+            || error == Error::<T>::ProposalBondNotFound.into()
+        {
+            IncentiveError::ConditionNotMet
+        } else if error == Error::<T>::InsufficientProposalBond.into() {
+            IncentiveError::InsufficientBond
+        } else {
+            // 其余错误都来自激励池向接收者转账
+            IncentiveError::TransferFailed
+        }
+    }
+}
+
 impl<T: Config> pallet_shared_traits::IncentiveHandler<T::AccountId, [u8; 32], BalanceOf<T>> for Pallet<T> {
-    fn distribute_first_create_reward(recipient: &T::AccountId, asset_id: &[u8; 32]) -> Result<(), &'static str> {
+    fn distribute_first_create_reward(recipient: &T::AccountId, asset_id: &[u8; 32]) -> Result<(), IncentiveError> {
         Self::distribute_first_create_reward(recipient, asset_id)
-            .map_err(|_| "Distribution failed")
+            .map_err(Self::incentive_error)
     }
     
     fn register_asset_trade(asset_id: &[u8; 32]) {
         Self::register_asset_trade(asset_id)
     }
     
-    fn distribute_liquidity_reward(recipient: &T::AccountId, order_amount: BalanceOf<T>) -> Result<(), &'static str> {
-        Self::distribute_liquidity_reward(recipient, order_amount)
-            .map_err(|_| "Liquidity reward failed")
+    fn distribute_market_liquidity_reward(
+        recipient: &T::AccountId,
+        market_contract: &T::AccountId,
+        order_amount: BalanceOf<T>,
+    ) -> Result<(), IncentiveError> {
+        Self::distribute_market_liquidity_reward(recipient, market_contract, order_amount)
+            .map_err(Self::incentive_error)
     }
     
-    fn distribute_proposal_reward(recipient: &T::AccountId) -> Result<(), &'static str> {
-        Self::distribute_proposal_reward(recipient)
-            .map_err(|_| "Proposal reward failed")
+    fn reserve_proposal_bond(proposer: &T::AccountId) -> Result<(), IncentiveError> {
+        Self::reserve_proposal_bond(proposer)
+            .map_err(Self::incentive_error)
+    }
+
+    fn distribute_proposal_outcome(proposer: &T::AccountId, passed: bool, support: Perbill) -> Result<(), IncentiveError> {
+        Self::distribute_proposal_outcome(proposer, passed, support)
+            .map_err(Self::incentive_error)
+    }
+
+    fn reward_issuance_operator(market: &T::AccountId) -> Result<(), IncentiveError> {
+        Self::reward_issuance_operator(market)
+            .map_err(Self::incentive_error)
     }
 }
\ No newline at end of file
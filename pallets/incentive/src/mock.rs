@@ -0,0 +1,311 @@
+use crate as pallet_incentive;
+use core::cell::RefCell;
+use frame_support::{
+    parameter_types,
+    derive_impl,
+    traits::{ConstU128, ConstU32, Currency, VestingSchedule},
+    dispatch::DispatchResult,
+    PalletId,
+};
+use pallet_shared_traits::{AssetQueryError, DataAssetProvider, MarketProvider, PledgeStatusProvider};
+use sp_core::H256;
+use sp_runtime::{
+    BuildStorage,
+    Perbill,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Incentive: pallet_incentive,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<10>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+thread_local! {
+    // 模拟 dataassets 中的资产所有者：asset_id -> owner
+    static ASSET_OWNERS: RefCell<BTreeMap<[u8; 32], u64>> = RefCell::new(BTreeMap::new());
+    // 为 true 时与 dataassets 一样检查所有者账户是否存在
+    static CHECK_OWNER_EXISTS: RefCell<bool> = RefCell::new(false);
+}
+
+/// 模拟的数据资产提供者，只维护资产ID到所有者的映射
+pub struct MockDataAssetProvider;
+
+impl MockDataAssetProvider {
+    pub fn set_owner(asset_id: [u8; 32], owner: u64) {
+        ASSET_OWNERS.with(|owners| owners.borrow_mut().insert(asset_id, owner));
+    }
+
+    pub fn set_check_owner_exists(check: bool) {
+        CHECK_OWNER_EXISTS.with(|flag| *flag.borrow_mut() = check);
+    }
+}
+
+impl DataAssetProvider<u64, [u8; 32]> for MockDataAssetProvider {
+    fn get_asset_owner(asset_id: &[u8; 32]) -> Result<u64, AssetQueryError> {
+        let owner = Self::get_recorded_owner(asset_id).ok_or(AssetQueryError::AssetNotFound)?;
+        if CHECK_OWNER_EXISTS.with(|flag| *flag.borrow()) && !System::account_exists(&owner) {
+            return Err(AssetQueryError::OwnerAccountDoesNotExist);
+        }
+        Ok(owner)
+    }
+
+    fn get_recorded_owner(asset_id: &[u8; 32]) -> Option<u64> {
+        ASSET_OWNERS.with(|owners| owners.borrow().get(asset_id).copied())
+    }
+
+    fn get_raw_data_hash(asset_id: &[u8; 32]) -> Result<H256, AssetQueryError> {
+        Self::get_asset_owner(asset_id).map(|_| H256::from(*asset_id))
+    }
+
+    fn get_data_size(asset_id: &[u8; 32]) -> Result<u64, AssetQueryError> {
+        Self::get_asset_owner(asset_id).map(|_| 0)
+    }
+}
+
+thread_local! {
+    // 模拟 markets 中已注册且启用的市场合约
+    static ACTIVE_MARKETS: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+}
+
+/// 模拟的市场提供者，只维护启用的市场集合
+pub struct MockMarketProvider;
+
+impl MockMarketProvider {
+    pub fn register(market: u64) {
+        ACTIVE_MARKETS.with(|markets| markets.borrow_mut().insert(market));
+    }
+
+    pub fn unregister(market: u64) {
+        ACTIVE_MARKETS.with(|markets| markets.borrow_mut().remove(&market));
+    }
+}
+
+impl MarketProvider<u64> for MockMarketProvider {
+    fn is_active_market(market: &u64) -> bool {
+        ACTIVE_MARKETS.with(|markets| markets.borrow().contains(market))
+    }
+}
+
+thread_local! {
+    // 治理质押尚未生效的投票者
+    static PENDING_PLEDGES: RefCell<BTreeSet<u64>> = RefCell::new(BTreeSet::new());
+}
+
+/// 模拟的质押状态，默认所有治理质押都已生效
+pub struct MockPledgeStatus;
+
+impl MockPledgeStatus {
+    pub fn set_pending(who: u64, pending: bool) {
+        PENDING_PLEDGES.with(|pending_set| {
+            if pending {
+                pending_set.borrow_mut().insert(who);
+            } else {
+                pending_set.borrow_mut().remove(&who);
+            }
+        });
+    }
+}
+
+impl PledgeStatusProvider<u64> for MockPledgeStatus {
+    fn is_governance_pledge_active(who: &u64) -> bool {
+        !PENDING_PLEDGES.with(|pending_set| pending_set.borrow().contains(who))
+    }
+}
+
+/// 基金会账户及其解锁总额
+pub const FOUNDATION: u64 = 77;
+pub const FOUNDATION_TOTAL: u128 = 1_000_000;
+/// 无法送达的奖励暂存账户
+pub const UNDISTRIBUTED: u64 = 88;
+
+thread_local! {
+    // 模拟 pallet_vesting 中各账户仍锁定的金额
+    static VESTING_LOCKED: RefCell<BTreeMap<u64, u128>> = RefCell::new(BTreeMap::new());
+}
+
+/// 模拟的解锁计划，只维护账户的剩余锁定金额
+pub struct MockVesting;
+
+impl MockVesting {
+    pub fn set_locked(who: u64, locked: u128) {
+        VESTING_LOCKED.with(|locked_map| locked_map.borrow_mut().insert(who, locked));
+    }
+}
+
+impl VestingSchedule<u64> for MockVesting {
+    type Moment = u64;
+    type Currency = Balances;
+
+    fn vesting_balance(who: &u64) -> Option<<Balances as Currency<u64>>::Balance> {
+        VESTING_LOCKED.with(|locked_map| locked_map.borrow().get(who).copied())
+    }
+
+    fn add_vesting_schedule(_who: &u64, _locked: u128, _per_block: u128, _starting_block: u64) -> DispatchResult {
+        Ok(())
+    }
+
+    fn can_add_vesting_schedule(_who: &u64, _locked: u128, _per_block: u128, _starting_block: u64) -> DispatchResult {
+        Ok(())
+    }
+
+    fn remove_vesting_schedule(_who: &u64, _schedule_index: u32) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// 激励池初始余额
+pub const INITIAL_POOL: u128 = 10_000_000;
+/// 测试开始时激励池已释放（可用于发放）的额度
+pub const INITIAL_RELEASED: u128 = 1_000_000;
+
+parameter_types! {
+    pub const InitialIncentivePool: u128 = INITIAL_POOL;
+    pub const DynamicReleaseRatio: Perbill = Perbill::from_percent(1);
+    pub const MinReleaseRatio: Perbill = Perbill::from_perthousand(5);
+    pub const MaxReleaseRatio: Perbill = Perbill::from_percent(2);
+    pub const FinalReleaseThreshold: u128 = 1_000;
+    pub const TargetMonthlyTradeVolume: u128 = 1_000_000;
+    pub const FirstCreateReward: u128 = 1_000;
+    pub const QualityDataReward: u128 = 3_000;
+    pub LongTermShareRatio: Perbill = Perbill::from_rational(5u32, 1_000u32);
+    pub const QualityDataTradeThreshold: u32 = 10;
+    pub const TopMarketMonthlyReward: u128 = 50_000;
+    // 默认关闭，测试中按需设置
+    pub static IssuanceOperatorReward: u128 = 0;
+    // 默认关闭，测试中按需设置
+    pub static OperatorVolumeRewardRatio: Perbill = Perbill::zero();
+    pub const MaxOperatorRewardsPerBlock: u32 = 2;
+    pub static MaxOperatorVolumeReward: u128 = 1_000_000;
+    pub const TraderRebateThreshold: u128 = 100_000;
+    pub const TraderRebateRatio: Perbill = Perbill::from_percent(10);
+    pub LiquidityRewardRatio: Perbill = Perbill::from_rational(5u32, 10_000u32);
+    pub const MinOrderForLiquidityReward: u128 = 10_000;
+    pub const GovernanceVotingRewardTotal: u128 = 5_000;
+    pub const GovernanceProposalReward: u128 = 2_000;
+    // 默认不收提案保证金，测试中按需设置
+    pub static GovernanceProposalBond: u128 = 0;
+    pub const ProposalSupportThreshold: Perbill = Perbill::from_percent(20);
+    pub const ValidatorVerificationReward: u128 = 50;
+    // 与 ExistentialDeposit 一致
+    pub const MinRewardPayout: u128 = 10;
+    pub static EscrowRewardsForMissingOwner: bool = true;
+    // 测试中奖励以最小单位计，默认不触发精度警告
+    pub static Unit: u128 = 1;
+    pub const FoundationAccount: u64 = FOUNDATION;
+    pub const FoundationVestingTotal: u128 = FOUNDATION_TOTAL;
+    pub const VestingMilestoneStep: Perbill = Perbill::from_percent(10);
+    pub const UndistributedRewardAccount: u64 = UNDISTRIBUTED;
+    pub const IncentivePalletId: PalletId = PalletId(*b"da/incnt");
+    pub const MaxDistributionHistory: u32 = 2;
+    pub const MaxPayoutRetries: u32 = 2;
+    pub const MaxBatchSize: u32 = 4;
+}
+
+impl pallet_incentive::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type DataAssetProvider = MockDataAssetProvider;
+    type MarketProvider = MockMarketProvider;
+    type PledgeStatusProvider = MockPledgeStatus;
+    type InitialIncentivePool = InitialIncentivePool;
+    type DynamicReleaseRatio = DynamicReleaseRatio;
+    type MinReleaseRatio = MinReleaseRatio;
+    type MaxReleaseRatio = MaxReleaseRatio;
+    type FinalReleaseThreshold = FinalReleaseThreshold;
+    type TargetMonthlyTradeVolume = TargetMonthlyTradeVolume;
+    type FirstCreateReward = FirstCreateReward;
+    type QualityDataReward = QualityDataReward;
+    type LongTermShareRatio = LongTermShareRatio;
+    type QualityDataTradeThreshold = QualityDataTradeThreshold;
+    type TopMarketMonthlyReward = TopMarketMonthlyReward;
+    type IssuanceOperatorReward = IssuanceOperatorReward;
+    type OperatorVolumeRewardRatio = OperatorVolumeRewardRatio;
+    type MaxOperatorVolumeReward = MaxOperatorVolumeReward;
+    type MaxOperatorRewardsPerBlock = MaxOperatorRewardsPerBlock;
+    type TraderRebateThreshold = TraderRebateThreshold;
+    type TraderRebateRatio = TraderRebateRatio;
+    type LiquidityRewardRatio = LiquidityRewardRatio;
+    type MinOrderForLiquidityReward = MinOrderForLiquidityReward;
+    type GovernanceVotingRewardTotal = GovernanceVotingRewardTotal;
+    type GovernanceProposalReward = GovernanceProposalReward;
+    type GovernanceProposalBond = GovernanceProposalBond;
+    type ProposalSupportThreshold = ProposalSupportThreshold;
+    type ValidatorVerificationReward = ValidatorVerificationReward;
+    type MinRewardPayout = MinRewardPayout;
+    type EscrowRewardsForMissingOwner = EscrowRewardsForMissingOwner;
+    type Unit = Unit;
+    type FoundationVesting = MockVesting;
+    type FoundationAccount = FoundationAccount;
+    type FoundationVestingTotal = FoundationVestingTotal;
+    type VestingMilestoneStep = VestingMilestoneStep;
+    type UndistributedRewardAccount = UndistributedRewardAccount;
+    type MaxDistributionHistory = MaxDistributionHistory;
+    type MaxPayoutRetries = MaxPayoutRetries;
+    type MaxBatchSize = MaxBatchSize;
+    type PalletId = IncentivePalletId;
+    type WeightInfo = crate::weights::WeightInfo<Test>;
+}
+
+/// 激励池账户（由 IncentivePalletId 派生）
+pub fn pool_account() -> u64 {
+    crate::incentive_pool_account::<Test>()
+}
+
+/// v2 之前的固定地址激励池账户（u64 账户从固定地址的前8字节解码）
+pub fn legacy_pool_account() -> u64 {
+    crate::legacy_incentive_pool_account::<Test>()
+}
+
+/// 构造市场ID，使其前8字节解码后即为市场运营者账户
+pub fn market_id(operator: u64) -> [u8; 32] {
+    let mut id = [0u8; 32];
+    id[..8].copy_from_slice(&operator.to_le_bytes());
+    id
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(pool_account(), INITIAL_POOL)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        System::set_block_number(1);
+        // 创世时 on_runtime_upgrade 不会执行，这里直接设置已释放额度
+        pallet_incentive::IncentivePoolReleased::<Test>::put(INITIAL_RELEASED);
+    });
+    ext
+}
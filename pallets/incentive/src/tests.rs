@@ -0,0 +1,1177 @@
+use crate::{
+    mock::*, DistributionSummary, Error, Event, Asset30dTradeCount, GovernanceVotingWeight, HasFirstCreateReward,
+    IncentivePoolFunded, IncentivePoolReleased, IncentivePoolReserved, IncentivePoolUsed, MarketMonthlyVolume, MonthlyDistributionLog, PendingOperatorVolumeRewards, PendingReward, TraderMonthlyVolume,
+    RewardDenylist, VestingMilestonesReached, MONTH_BLOCKS, VESTING_CHECK_BLOCKS,
+};
+use frame_support::{
+    assert_noop, assert_ok,
+    traits::{Currency, GetStorageVersion, Hooks, ReservableCurrency, StorageVersion},
+};
+use pallet_shared_traits::{IncentiveError, IncentiveHandler};
+use sp_runtime::{DispatchError, Perbill};
+
+const ASSET: [u8; 32] = [7u8; 32];
+
+#[test]
+fn first_create_reward_is_paid_only_once() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        assert_eq!(Balances::free_balance(1), FirstCreateReward::get());
+        assert!(HasFirstCreateReward::<Test>::get(1));
+        assert_eq!(IncentivePoolUsed::<Test>::get(), FirstCreateReward::get());
+        System::assert_last_event(
+            Event::FirstCreateRewardDistributed {
+                recipient: 1,
+                amount: FirstCreateReward::get(),
+                asset_id: ASSET,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+
+        // 同一账户再次创建不再发放
+        assert_noop!(
+            Incentive::distribute_first_create_reward(&1, &[8u8; 32]),
+            Error::<Test>::FirstCreateRewardAlreadyClaimed
+        );
+        assert_eq!(Balances::free_balance(1), FirstCreateReward::get());
+    });
+}
+
+#[test]
+fn incentive_handler_reports_typed_errors() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<Incentive as IncentiveHandler<u64, [u8; 32], u128>>::distribute_first_create_reward(&1, &ASSET));
+        assert_eq!(
+            <Incentive as IncentiveHandler<u64, [u8; 32], u128>>::distribute_first_create_reward(&1, &ASSET),
+            Err(IncentiveError::AlreadyClaimed)
+        );
+
+        // 已释放额度用尽后，提案奖励因激励池不足而失败
+        IncentivePoolUsed::<Test>::put(INITIAL_RELEASED);
+        assert_eq!(
+            <Incentive as IncentiveHandler<u64, [u8; 32], u128>>::distribute_proposal_outcome(&2, true, Perbill::one()),
+            Err(IncentiveError::InsufficientPool)
+        );
+
+        // 保证金不足时无法提交提案
+        GovernanceProposalBond::set(500);
+        assert_eq!(
+            <Incentive as IncentiveHandler<u64, [u8; 32], u128>>::reserve_proposal_bond(&2),
+            Err(IncentiveError::InsufficientBond)
+        );
+    });
+}
+
+#[test]
+fn reward_events_are_indexed_by_recipient() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+
+        let record = System::events().pop().unwrap();
+        assert_eq!(record.topics, vec![Incentive::event_topic(&1u64), Incentive::event_topic(&ASSET)]);
+        assert_eq!(System::event_topics(Incentive::event_topic(&1u64)).len(), 1);
+    });
+}
+
+#[test]
+fn quality_data_reward_requires_trade_threshold() {
+    new_test_ext().execute_with(|| {
+        MockDataAssetProvider::set_owner(ASSET, 2);
+        for _ in 1..QualityDataTradeThreshold::get() {
+            Incentive::register_asset_trade(&ASSET);
+        }
+
+        // 差一笔交易，不满足优质数据条件
+        assert_noop!(
+            Incentive::distribute_quality_data_reward(RuntimeOrigin::root(), ASSET),
+            Error::<Test>::QualityDataConditionNotMet
+        );
+
+        Incentive::register_asset_trade(&ASSET);
+        assert_ok!(Incentive::distribute_quality_data_reward(RuntimeOrigin::root(), ASSET));
+        assert_eq!(Balances::free_balance(2), QualityDataReward::get());
+    });
+}
+
+#[test]
+fn quality_data_reward_fails_for_unknown_asset() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Incentive::distribute_quality_data_reward(RuntimeOrigin::root(), ASSET),
+            Error::<Test>::AssetNotFound
+        );
+    });
+}
+
+#[test]
+fn quality_data_reward_is_escrowed_for_reaped_owner() {
+    new_test_ext().execute_with(|| {
+        MockDataAssetProvider::set_owner(ASSET, 2);
+        MockDataAssetProvider::set_check_owner_exists(true);
+        for _ in 0..QualityDataTradeThreshold::get() {
+            Incentive::register_asset_trade(&ASSET);
+        }
+
+        // 所有者转出全部余额，账户被回收
+        Balances::make_free_balance_be(&2, 100);
+        assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(2), 3, 100));
+        assert!(!System::account_exists(&2));
+
+        // 关闭托管时保持原有行为
+        EscrowRewardsForMissingOwner::set(false);
+        assert_noop!(
+            Incentive::distribute_quality_data_reward(RuntimeOrigin::root(), ASSET),
+            Error::<Test>::OwnerAccountDoesNotExist
+        );
+
+        EscrowRewardsForMissingOwner::set(true);
+        let used_before = IncentivePoolUsed::<Test>::get();
+        assert_ok!(Incentive::distribute_quality_data_reward(RuntimeOrigin::root(), ASSET));
+        assert_eq!(PendingReward::<Test>::get(2), QualityDataReward::get());
+        assert_eq!(IncentivePoolUsed::<Test>::get(), used_before);
+        System::assert_has_event(Event::RewardEscrowed {
+            recipient: 2,
+            amount: QualityDataReward::get(),
+            asset_id: ASSET,
+            pending: QualityDataReward::get(),
+        }.into());
+        assert_ok!(Incentive::do_try_state());
+
+        // 账户重新创建后领取托管的奖励
+        Balances::make_free_balance_be(&2, 100);
+        assert_ok!(Incentive::claim_pending_reward(RuntimeOrigin::signed(2)));
+        assert_eq!(Balances::free_balance(2), 100 + QualityDataReward::get());
+        assert_eq!(PendingReward::<Test>::get(2), 0);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), used_before + QualityDataReward::get());
+
+        assert_noop!(
+            Incentive::claim_pending_reward(RuntimeOrigin::signed(2)),
+            Error::<Test>::NoPendingReward
+        );
+    });
+}
+
+#[test]
+fn top_market_reward_goes_to_single_leader_below_ten_markets() {
+    new_test_ext().execute_with(|| {
+        for operator in 1..=5u64 {
+            Incentive::register_market_volume_internal(&market_id(operator), (operator * 1_000) as u128);
+        }
+
+        Incentive::distribute_top_market_rewards(&Incentive::take_monthly_statistics().0);
+
+        assert_eq!(Balances::free_balance(5), TopMarketMonthlyReward::get());
+        for operator in 1..5u64 {
+            assert_eq!(Balances::free_balance(operator), 0);
+        }
+        System::assert_last_event(
+            Event::TopMarketRewardDistributed {
+                recipient: 5,
+                amount: TopMarketMonthlyReward::get(),
+                market_id: market_id(5),
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn operator_volume_reward_is_paid_to_every_market_in_pages() {
+    new_test_ext().execute_with(|| {
+        OperatorVolumeRewardRatio::set(Perbill::from_percent(1));
+        for operator in 1..=3u64 {
+            MockMarketProvider::register(operator);
+            Incentive::register_market_volume_internal(&market_id(operator), (operator * 100_000) as u128);
+        }
+
+        // 每个区块最多发放 MaxOperatorRewardsPerBlock 笔，剩余的留到下一个区块
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+        assert_eq!(PendingOperatorVolumeRewards::<Test>::iter().count(), 1);
+        Incentive::on_initialize((MONTH_BLOCKS + 1).into());
+        assert_eq!(PendingOperatorVolumeRewards::<Test>::iter().count(), 0);
+
+        // 非优质市场只获得交易额奖励，优质市场同时获得两种奖励
+        assert_eq!(Balances::free_balance(1), 1_000);
+        assert_eq!(Balances::free_balance(2), 2_000);
+        assert_eq!(Balances::free_balance(3), TopMarketMonthlyReward::get() + 3_000);
+        System::assert_has_event(
+            Event::OperatorVolumeRewardDistributed {
+                recipient: 1,
+                amount: 1_000,
+                market_id: market_id(1),
+                monthly_volume: 100_000,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+        assert_ok!(Incentive::do_try_state());
+    });
+}
+
+#[test]
+fn operator_volume_reward_is_capped_and_only_paid_to_active_markets() {
+    new_test_ext().execute_with(|| {
+        OperatorVolumeRewardRatio::set(Perbill::from_percent(1));
+        MaxOperatorVolumeReward::set(1_500);
+        MockMarketProvider::register(1);
+        MockMarketProvider::register(2);
+        Incentive::register_market_volume_internal(&market_id(1), 100_000);
+        Incentive::register_market_volume_internal(&market_id(2), 1_000_000);
+        // 从未注册的市场 3 和登记交易额后已注销的市场 4 都不发放
+        Incentive::register_market_volume_internal(&market_id(3), 100_000);
+        MockMarketProvider::register(4);
+        Incentive::register_market_volume_internal(&market_id(4), 100_000);
+        MockMarketProvider::unregister(4);
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+        Incentive::on_initialize((MONTH_BLOCKS + 1).into());
+        assert_eq!(PendingOperatorVolumeRewards::<Test>::iter().count(), 0);
+
+        assert_eq!(Balances::free_balance(1), 1_000);
+        // 1% × 1_000_000 = 10_000，超过上限只发放 MaxOperatorVolumeReward（另加优质市场奖励）
+        assert_eq!(Balances::free_balance(2), TopMarketMonthlyReward::get() + 1_500);
+        assert_eq!(Balances::free_balance(3), 0);
+        assert_eq!(Balances::free_balance(4), 0);
+        assert_ok!(Incentive::do_try_state());
+    });
+}
+
+#[test]
+fn market_volume_is_registered_only_by_the_market_or_root() {
+    new_test_ext().execute_with(|| {
+        MockMarketProvider::register(1);
+
+        // 普通账户不能登记交易额，市场也不能替其他市场登记
+        assert_noop!(
+            Incentive::register_market_monthly_volume(RuntimeOrigin::signed(7), market_id(7), 1_000),
+            Error::<Test>::UnregisteredMarket
+        );
+        assert_noop!(
+            Incentive::register_market_monthly_volume(RuntimeOrigin::signed(1), market_id(2), 1_000),
+            Error::<Test>::UnregisteredMarket
+        );
+
+        assert_ok!(Incentive::register_market_monthly_volume(RuntimeOrigin::signed(1), market_id(1), 1_000));
+        assert_eq!(MarketMonthlyVolume::<Test>::get(market_id(1)), 1_000);
+        assert_ok!(Incentive::register_market_monthly_volume(RuntimeOrigin::root(), market_id(2), 2_000));
+        assert_eq!(MarketMonthlyVolume::<Test>::get(market_id(2)), 2_000);
+
+        // 注销后不能再登记
+        MockMarketProvider::unregister(1);
+        assert_noop!(
+            Incentive::register_market_monthly_volume(RuntimeOrigin::signed(1), market_id(1), 5_000),
+            Error::<Test>::UnregisteredMarket
+        );
+    });
+}
+
+#[test]
+fn operator_volume_reward_is_disabled_by_default() {
+    new_test_ext().execute_with(|| {
+        Incentive::register_market_volume_internal(&market_id(1), 100_000);
+        Incentive::register_market_volume_internal(&market_id(2), 200_000);
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        assert_eq!(PendingOperatorVolumeRewards::<Test>::iter().count(), 0);
+        assert_eq!(Balances::free_balance(1), 0);
+    });
+}
+
+#[test]
+fn top_market_ties_are_broken_by_market_id() {
+    new_test_ext().execute_with(|| {
+        // 按逆序登记相同交易额的市场，获奖者只取决于 market_id
+        for operator in (1..=4u64).rev() {
+            Incentive::register_market_volume_internal(&market_id(operator), 1_000);
+        }
+
+        Incentive::distribute_top_market_rewards(&Incentive::take_monthly_statistics().0);
+
+        assert_eq!(Balances::free_balance(1), TopMarketMonthlyReward::get());
+        for operator in 2..=4u64 {
+            assert_eq!(Balances::free_balance(operator), 0);
+        }
+    });
+}
+
+#[test]
+fn top_market_reward_selects_top_ten_percent_above_ten_markets() {
+    new_test_ext().execute_with(|| {
+        // 11个市场，前10%向上取整为2个
+        for operator in 1..=11u64 {
+            Incentive::register_market_volume_internal(&market_id(operator), (operator * 1_000) as u128);
+        }
+
+        Incentive::distribute_top_market_rewards(&Incentive::take_monthly_statistics().0);
+
+        assert_eq!(Balances::free_balance(11), TopMarketMonthlyReward::get());
+        assert_eq!(Balances::free_balance(10), TopMarketMonthlyReward::get());
+        for operator in 1..10u64 {
+            assert_eq!(Balances::free_balance(operator), 0);
+        }
+        assert_eq!(IncentivePoolUsed::<Test>::get(), 2 * TopMarketMonthlyReward::get());
+    });
+}
+
+#[test]
+fn undeliverable_top_market_reward_is_parked() {
+    new_test_ext().execute_with(|| {
+        // 市场ID解析为激励池自身时不能自我转账
+        Incentive::register_market_volume_internal(&market_id(pool_account()), 1_000);
+
+        Incentive::distribute_top_market_rewards(&Incentive::take_monthly_statistics().0);
+
+        assert_eq!(Balances::free_balance(UNDISTRIBUTED), TopMarketMonthlyReward::get());
+        assert_eq!(Balances::free_balance(pool_account()), INITIAL_POOL - TopMarketMonthlyReward::get());
+        System::assert_last_event(
+            Event::RewardUndeliverable {
+                market_id: market_id(pool_account()),
+                amount: TopMarketMonthlyReward::get(),
+                parked_at: UNDISTRIBUTED,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn monthly_distribution_is_logged_per_category() {
+    new_test_ext().execute_with(|| {
+        Incentive::register_market_volume_internal(&market_id(1), 1_000);
+        let threshold = TraderRebateThreshold::get();
+        Incentive::register_trader_monthly_volume(&2, threshold);
+
+        Incentive::distribute_monthly_rewards();
+
+        let rebate = TraderRebateRatio::get() * threshold;
+        assert_eq!(
+            Incentive::monthly_distribution(0),
+            Some(DistributionSummary {
+                block: 1,
+                top_market: TopMarketMonthlyReward::get(),
+                trader_rebate: rebate,
+                governance_voting: 0,
+                total: TopMarketMonthlyReward::get() + rebate,
+                pool_balance: INITIAL_POOL,
+            })
+        );
+        assert_eq!(Incentive::distribution_month_count(), 1);
+    });
+}
+
+#[test]
+fn monthly_distribution_log_keeps_bounded_history() {
+    new_test_ext().execute_with(|| {
+        for month in 0..3u64 {
+            System::set_block_number(1 + month);
+            Incentive::distribute_monthly_rewards();
+        }
+
+        // MaxDistributionHistory = 2，最早的一个月被删除
+        assert!(MonthlyDistributionLog::<Test>::get(0).is_none());
+        assert_eq!(Incentive::monthly_distribution(1).map(|summary| summary.block), Some(2));
+        assert_eq!(Incentive::monthly_distribution(2).map(|summary| summary.block), Some(3));
+        assert_eq!(Incentive::distribution_month_count(), 3);
+    });
+}
+
+#[test]
+fn trader_rebate_respects_threshold_boundary() {
+    new_test_ext().execute_with(|| {
+        let threshold = TraderRebateThreshold::get();
+        Incentive::register_trader_monthly_volume(&1, threshold);
+        Incentive::register_trader_monthly_volume(&2, threshold - 1);
+
+        Incentive::distribute_trader_rebates(&Incentive::take_monthly_statistics().0);
+
+        // 恰好达到阈值的交易者获得返还，低于阈值的不获得
+        assert_eq!(Balances::free_balance(1), TraderRebateRatio::get() * threshold);
+        assert_eq!(Balances::free_balance(2), 0);
+        System::assert_has_event(
+            Event::TraderRebateDistributed {
+                recipient: 1,
+                amount: TraderRebateRatio::get() * threshold,
+                monthly_volume: threshold,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn failed_rebate_is_retried_in_the_next_distribution() {
+    new_test_ext().execute_with(|| {
+        let threshold = TraderRebateThreshold::get();
+        let rebate = TraderRebateRatio::get() * threshold;
+        Incentive::register_trader_monthly_volume(&1, threshold);
+
+        // 激励池账户的可用余额被临时锁定，记账额度充足但实际转账失败
+        let locked = Balances::free_balance(pool_account()) - rebate + 1;
+        assert_ok!(Balances::reserve(&pool_account(), locked));
+        Incentive::distribute_monthly_rewards();
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(Incentive::failed_payout(1), Some(rebate));
+
+        // 下一轮发放开始时先重试，成功后清除记录
+        Balances::unreserve(&pool_account(), locked);
+        Incentive::distribute_monthly_rewards();
+        assert_eq!(Balances::free_balance(1), rebate);
+        assert_eq!(Incentive::failed_payout(1), None);
+        assert_eq!(Incentive::failed_payout_attempts(1), 0);
+        System::assert_has_event(
+            Event::FailedPayoutRetried { recipient: 1, amount: rebate, pool_account: pool_account() }.into(),
+        );
+        // 重试发放的金额不计入本月的返还统计
+        assert_eq!(Incentive::monthly_distribution(1).map(|summary| summary.trader_rebate), Some(0));
+    });
+}
+
+#[test]
+fn failed_payout_is_abandoned_after_max_retries() {
+    new_test_ext().execute_with(|| {
+        crate::FailedPayouts::<Test>::insert(1, 5);
+
+        // 低于 ExistentialDeposit 的转账无法创建账户，每次重试都失败
+        Incentive::retry_failed_payouts();
+        assert_eq!(Incentive::failed_payout_attempts(1), 1);
+        Incentive::retry_failed_payouts();
+
+        assert_eq!(Incentive::failed_payout(1), None);
+        assert_eq!(Incentive::failed_payout_attempts(1), 0);
+        System::assert_last_event(Event::PayoutAbandoned { recipient: 1, amount: 5, attempts: MaxPayoutRetries::get() }.into());
+    });
+}
+
+#[test]
+fn insufficient_pool_balance_emits_event() {
+    new_test_ext().execute_with(|| {
+        IncentivePoolReleased::<Test>::put(10);
+        Incentive::register_market_volume_internal(&market_id(1), 1_000);
+
+        Incentive::distribute_top_market_rewards(&Incentive::take_monthly_statistics().0);
+
+        assert_eq!(Balances::free_balance(1), 0);
+        System::assert_last_event(
+            Event::IncentivePoolInsufficientBalance {
+                required: TopMarketMonthlyReward::get(),
+                available: 10,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn monthly_distribution_resets_statistics() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        Incentive::register_asset_trade(&ASSET);
+        Incentive::register_market_volume_internal(&market_id(2), 1_000);
+        Incentive::register_trader_monthly_volume(&3, 1_000);
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 4, 10));
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        assert_eq!(MarketMonthlyVolume::<Test>::iter().count(), 0);
+        assert_eq!(TraderMonthlyVolume::<Test>::iter().count(), 0);
+        assert_eq!(GovernanceVotingWeight::<Test>::iter().count(), 0);
+        assert_eq!(Asset30dTradeCount::<Test>::iter().count(), 0);
+        // 首次创建奖励记录是永久的，不随月度统计重置
+        assert!(HasFirstCreateReward::<Test>::get(1));
+        assert_eq!(Incentive::last_monthly_reward_block(), MONTH_BLOCKS as u64);
+    });
+}
+
+#[test]
+fn boundary_block_volume_is_counted_once() {
+    new_test_ext().execute_with(|| {
+        let threshold = TraderRebateThreshold::get();
+        let rebate = TraderRebateRatio::get() * threshold;
+        let boundary: u64 = MONTH_BLOCKS.into();
+        System::set_block_number(boundary);
+
+        // 边界区块中先于发放登记的交易额计入本月的发放
+        Incentive::register_trader_monthly_volume(&1, threshold);
+        Incentive::on_initialize(boundary);
+        assert_eq!(Balances::free_balance(1), rebate);
+        assert_eq!(Incentive::monthly_distribution(0).map(|summary| summary.trader_rebate), Some(rebate));
+
+        // 同一区块内发放之后登记的交易额留给下一个窗口，不会被重置清掉
+        Incentive::register_trader_monthly_volume(&2, threshold);
+        assert_eq!(TraderMonthlyVolume::<Test>::get(2), threshold);
+        assert_eq!(TraderMonthlyVolume::<Test>::get(1), 0);
+
+        Incentive::on_initialize(2 * boundary);
+        assert_eq!(Balances::free_balance(2), rebate);
+        // 上个月已发放的交易额不会被重复计算
+        assert_eq!(Balances::free_balance(1), rebate);
+        assert_eq!(TraderMonthlyVolume::<Test>::iter().count(), 0);
+    });
+}
+
+#[test]
+fn next_distribution_block_follows_last_distribution() {
+    new_test_ext().execute_with(|| {
+        let month: u64 = MONTH_BLOCKS.into();
+        assert_eq!(Incentive::next_distribution_block(), month);
+
+        System::set_block_number(month);
+        Incentive::on_initialize(month);
+        assert_eq!(Incentive::next_distribution_block(), 2 * month);
+
+        // 间隔调小后已经到期：发放在下一个区块执行
+        System::set_block_number(month + 500);
+        assert_ok!(Incentive::set_distribution_interval(RuntimeOrigin::root(), 100));
+        assert_eq!(Incentive::next_distribution_block(), month + 501);
+
+        System::set_block_number(month + 501);
+        Incentive::on_initialize(month + 501);
+        assert_eq!(Incentive::last_monthly_reward_block(), month + 501);
+        assert_eq!(Incentive::next_distribution_block(), month + 601);
+    });
+}
+
+#[test]
+fn zero_voting_weight_is_not_stored() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 4, 0));
+        assert!(!GovernanceVotingWeight::<Test>::contains_key(4));
+        assert_ok!(Incentive::register_voting_weights_batch(RuntimeOrigin::root(), vec![(5, 0), (6, 10)]));
+        assert!(!GovernanceVotingWeight::<Test>::contains_key(5));
+
+        // 登记 0 会删除已有的权重
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 7, 10));
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 7, 0));
+        assert!(!GovernanceVotingWeight::<Test>::contains_key(7));
+        assert_eq!(GovernanceVotingWeight::<Test>::iter().count(), 1);
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        // 只有账户 6 参与分配
+        assert_eq!(Balances::free_balance(6), GovernanceVotingRewardTotal::get());
+        for voter in [4, 5, 7] {
+            assert_eq!(Balances::free_balance(voter), 0);
+            assert!(!System::events().iter().any(|record| matches!(
+                record.event,
+                RuntimeEvent::Incentive(Event::GovernanceVotingRewardDistributed { recipient, .. }) if recipient == voter
+            )));
+        }
+    });
+}
+
+#[test]
+fn voting_weights_are_registered_in_batches() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 4, 10));
+        assert_noop!(
+            Incentive::register_voting_weights_batch(RuntimeOrigin::signed(1), vec![(4, 1)]),
+            DispatchError::BadOrigin
+        );
+
+        // 同一投票者出现多次时累加，已有权重也会被累加而不是覆盖
+        assert_ok!(Incentive::register_voting_weights_batch(
+            RuntimeOrigin::root(),
+            vec![(4, 5), (5, 30), (6, 20), (5, 10)],
+        ));
+        assert_eq!(GovernanceVotingWeight::<Test>::get(4), 15);
+        assert_eq!(GovernanceVotingWeight::<Test>::get(5), 40);
+        assert_eq!(GovernanceVotingWeight::<Test>::get(6), 20);
+        System::assert_last_event(Event::VotingWeightsRegistered { count: 4 }.into());
+
+        assert_noop!(
+            Incentive::register_voting_weights_batch(RuntimeOrigin::root(), vec![]),
+            Error::<Test>::BatchTooLarge
+        );
+        let oversized = (0..=MaxBatchSize::get() as u64).map(|voter| (voter, 1)).collect();
+        assert_noop!(
+            Incentive::register_voting_weights_batch(RuntimeOrigin::root(), oversized),
+            Error::<Test>::BatchTooLarge
+        );
+    });
+}
+
+#[test]
+fn distribution_triggers_at_governance_set_interval() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Incentive::distribution_interval(), MONTH_BLOCKS as u64);
+        assert_noop!(
+            Incentive::set_distribution_interval(RuntimeOrigin::signed(1), 100),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Incentive::set_distribution_interval(RuntimeOrigin::root(), 0),
+            Error::<Test>::InvalidParameterValue
+        );
+
+        // 改为每 100 个区块发放一次
+        assert_ok!(Incentive::set_distribution_interval(RuntimeOrigin::root(), 100));
+        assert_eq!(Incentive::distribution_interval(), 100);
+
+        Incentive::on_initialize(99);
+        assert_eq!(Incentive::last_monthly_reward_block(), 0);
+        Incentive::on_initialize(100);
+        assert_eq!(Incentive::last_monthly_reward_block(), 100);
+
+        // 下一次发放按新的间隔计算
+        Incentive::on_initialize(199);
+        assert_eq!(Incentive::last_monthly_reward_block(), 100);
+        Incentive::on_initialize(200);
+        assert_eq!(Incentive::last_monthly_reward_block(), 200);
+    });
+}
+
+#[test]
+fn denylist_is_managed_by_governance() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(Incentive::add_to_denylist(RuntimeOrigin::signed(1), 2), DispatchError::BadOrigin);
+
+        assert_ok!(Incentive::add_to_denylist(RuntimeOrigin::root(), 2));
+        assert!(Incentive::is_reward_denied(2));
+        System::assert_last_event(Event::AddedToDenylist { account: 2 }.into());
+
+        assert_ok!(Incentive::remove_from_denylist(RuntimeOrigin::root(), 2));
+        assert!(!RewardDenylist::<Test>::contains_key(2));
+        System::assert_last_event(Event::RemovedFromDenylist { account: 2 }.into());
+    });
+}
+
+#[test]
+fn passed_proposal_returns_bond_and_pays_reward() {
+    new_test_ext().execute_with(|| {
+        GovernanceProposalBond::set(500);
+        Balances::make_free_balance_be(&1, 1_000);
+        assert_ok!(Incentive::reserve_proposal_bond(&1));
+        assert_eq!(Balances::reserved_balance(1), 500);
+        assert_eq!(Incentive::proposal_bond(1), 500);
+
+        assert_ok!(Incentive::distribute_proposal_outcome(&1, true, Perbill::from_percent(60)));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_000 + GovernanceProposalReward::get());
+        assert_eq!(Incentive::proposal_bond(1), 0);
+        System::assert_has_event(
+            Event::ProposalBondReturned { proposer: 1, amount: 500, support: Perbill::from_percent(60) }.into(),
+        );
+
+        // 保证金已结算，不能重复结算
+        assert_noop!(
+            Incentive::distribute_proposal_outcome(&1, true, Perbill::one()),
+            Error::<Test>::ProposalBondNotFound
+        );
+    });
+}
+
+#[test]
+fn rejected_proposal_below_threshold_slashes_bond() {
+    new_test_ext().execute_with(|| {
+        GovernanceProposalBond::set(500);
+        Balances::make_free_balance_be(&1, 2_000);
+        assert_ok!(Incentive::reserve_proposal_bond(&1));
+        assert_ok!(Incentive::reserve_proposal_bond(&1));
+        let pool_before = Balances::free_balance(pool_account());
+
+        // 支持率低于 20%：罚没一份保证金，转入激励池且可用于发放
+        assert_ok!(Incentive::distribute_proposal_outcome(&1, false, Perbill::from_percent(5)));
+        assert_eq!(Balances::reserved_balance(1), 500);
+        assert_eq!(Balances::free_balance(1), 1_000);
+        assert_eq!(Balances::free_balance(pool_account()), pool_before + 500);
+        assert_eq!(IncentivePoolFunded::<Test>::get(), 500);
+        System::assert_has_event(
+            Event::ProposalBondSlashed {
+                proposer: 1,
+                amount: 500,
+                support: Perbill::from_percent(5),
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+
+        // 支持率达到阈值的否决只退还保证金，不发奖励
+        assert_ok!(Incentive::distribute_proposal_outcome(&1, false, Perbill::from_percent(30)));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), 1_500);
+        assert_eq!(Incentive::proposal_bond(1), 0);
+    });
+}
+
+#[test]
+fn denied_recipient_is_skipped() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::add_to_denylist(RuntimeOrigin::root(), 1));
+
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        assert_ok!(Incentive::distribute_liquidity_reward(&1, 100_000));
+        assert_ok!(Incentive::distribute_proposal_outcome(&1, true, Perbill::one()));
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), 0);
+        // 未标记为已领取，移出禁止名单后仍可领取首次创建奖励
+        assert!(!HasFirstCreateReward::<Test>::get(1));
+        System::assert_has_event(Event::RewardDenied { recipient: 1 }.into());
+
+        assert_ok!(Incentive::remove_from_denylist(RuntimeOrigin::root(), 1));
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        assert_eq!(Balances::free_balance(1), FirstCreateReward::get());
+    });
+}
+
+#[test]
+fn denied_voters_do_not_dilute_voting_rewards() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 4, 10));
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 5, 30));
+        assert_ok!(Incentive::add_to_denylist(RuntimeOrigin::root(), 5));
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        // 总权重只算未被禁止的投票者，账户 4 拿到全部投票奖励
+        assert_eq!(Balances::free_balance(4), GovernanceVotingRewardTotal::get());
+        assert_eq!(Balances::free_balance(5), 0);
+        System::assert_has_event(Event::RewardDenied { recipient: 5 }.into());
+    });
+}
+
+#[test]
+fn pending_pledges_are_excluded_from_voting_rewards() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 4, 10));
+        assert_ok!(Incentive::register_voting_weight(RuntimeOrigin::root(), 5, 30));
+        // 账户 5 刚质押，尚未过生效期
+        MockPledgeStatus::set_pending(5, true);
+
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        // 未生效的权重不计入总权重，账户 4 拿到全部投票奖励
+        assert_eq!(Balances::free_balance(4), GovernanceVotingRewardTotal::get());
+        assert_eq!(Balances::free_balance(5), 0);
+        assert_eq!(PendingReward::<Test>::get(5), 0);
+    });
+}
+
+#[test]
+fn liquidity_reward_requires_a_registered_market() {
+    new_test_ext().execute_with(|| {
+        let market = 50;
+        assert_noop!(
+            Incentive::distribute_market_liquidity_reward(&1, &market, 100_000),
+            Error::<Test>::UnregisteredMarket
+        );
+        assert_eq!(
+            <Incentive as IncentiveHandler<u64, [u8; 32], u128>>::distribute_market_liquidity_reward(&1, &market, 100_000),
+            Err(IncentiveError::UnregisteredMarket)
+        );
+
+        MockMarketProvider::register(market);
+        assert_ok!(Incentive::distribute_market_liquidity_reward(&1, &market, 100_000));
+        assert_eq!(Balances::free_balance(1), LiquidityRewardRatio::get() * 100_000);
+    });
+}
+
+#[test]
+fn orders_below_minimum_earn_no_liquidity_reward() {
+    new_test_ext().execute_with(|| {
+        let min_order = MinOrderForLiquidityReward::get();
+
+        // 低于最小订单金额：按比例本应有奖励，但不发放、不累积、不发事件
+        assert!(LiquidityRewardRatio::get() * (min_order - 1) > 0);
+        assert_ok!(Incentive::distribute_liquidity_reward(&1, min_order - 1));
+        assert_eq!(PendingReward::<Test>::get(1), 0);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), 0);
+        assert!(System::events().is_empty());
+
+        // 恰好达到最小订单金额时按比例计算奖励（低于 ED 先累积）
+        assert_ok!(Incentive::distribute_liquidity_reward(&1, min_order));
+        assert_eq!(PendingReward::<Test>::get(1), LiquidityRewardRatio::get() * min_order);
+        assert_ok!(Incentive::distribute_liquidity_reward(&2, 10 * min_order));
+        assert_eq!(Balances::free_balance(2), LiquidityRewardRatio::get() * (10 * min_order));
+    });
+}
+
+#[test]
+fn sub_minimum_liquidity_reward_accrues_until_threshold() {
+    new_test_ext().execute_with(|| {
+        // 0.05% * 10_000 = 5，低于 ED（10），只累积不转账
+        assert_ok!(Incentive::distribute_liquidity_reward(&1, 10_000));
+        assert_eq!(Balances::free_balance(1), 0);
+        assert_eq!(PendingReward::<Test>::get(1), 5);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), 0);
+        System::assert_last_event(
+            Event::RewardBelowMinimum {
+                recipient: 1,
+                amount: 5,
+                pending: 5,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+
+        // 第二笔累积后达到最小发放额，一次性发放
+        assert_ok!(Incentive::distribute_liquidity_reward(&1, 10_000));
+        assert_eq!(Balances::free_balance(1), 10);
+        assert_eq!(PendingReward::<Test>::get(1), 0);
+        System::assert_last_event(
+            Event::LiquidityRewardDistributed {
+                recipient: 1,
+                amount: 10,
+                order_amount: 10_000,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn runtime_upgrade_reports_misconfigured_pool_balance() {
+    new_test_ext().execute_with(|| {
+        // 模拟创世余额与 InitialIncentivePool 不一致
+        Balances::make_free_balance_be(&pool_account(), INITIAL_POOL / 2);
+        StorageVersion::new(0).put::<Incentive>();
+
+        Incentive::on_runtime_upgrade();
+
+        System::assert_has_event(
+            Event::IncentivePoolMisconfigured {
+                expected: INITIAL_POOL,
+                actual: INITIAL_POOL / 2,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+        assert_eq!(Incentive::on_chain_storage_version(), StorageVersion::new(2));
+    });
+}
+
+#[test]
+fn runtime_upgrade_clamps_initial_release_to_empty_pool() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&pool_account(), 0);
+        StorageVersion::new(0).put::<Incentive>();
+
+        Incentive::on_runtime_upgrade();
+
+        // 账户没有资金时不记录任何释放额或锁定额
+        assert_eq!(IncentivePoolReleased::<Test>::get(), 0);
+        assert_eq!(IncentivePoolReserved::<Test>::get(), 0);
+        System::assert_has_event(
+            Event::IncentivePoolReleased {
+                amount: 0,
+                new_balance: 0,
+                ratio: DynamicReleaseRatio::get(),
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+        assert_eq!(Incentive::on_chain_storage_version(), StorageVersion::new(2));
+    });
+}
+
+#[test]
+fn runtime_upgrade_moves_legacy_pool_balance_to_derived_account() {
+    new_test_ext().execute_with(|| {
+        // 模拟 v1 链：资金在固定地址账户上，其中一部分处于锁定状态
+        let legacy = legacy_pool_account();
+        assert_ne!(legacy, pool_account());
+        Balances::make_free_balance_be(&pool_account(), 0);
+        Balances::make_free_balance_be(&legacy, INITIAL_POOL);
+        assert_ok!(Balances::reserve(&legacy, INITIAL_POOL / 2));
+        IncentivePoolReserved::<Test>::put(INITIAL_POOL / 2);
+        IncentivePoolUsed::<Test>::put(1_000);
+        StorageVersion::new(1).put::<Incentive>();
+        let available = Incentive::get_available_balance();
+
+        Incentive::on_runtime_upgrade();
+
+        assert_eq!(Balances::total_balance(&legacy), 0);
+        assert_eq!(Balances::free_balance(pool_account()), INITIAL_POOL / 2);
+        assert_eq!(Balances::reserved_balance(pool_account()), INITIAL_POOL / 2);
+        // 记账金额不变，v1 的初始化不会重新执行
+        assert_eq!(IncentivePoolReleased::<Test>::get(), INITIAL_RELEASED);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), 1_000);
+        assert_eq!(IncentivePoolReserved::<Test>::get(), INITIAL_POOL / 2);
+        assert_eq!(Incentive::get_available_balance(), available);
+        System::assert_last_event(
+            Event::PoolAccountMigrated { from: legacy, to: pool_account(), balance: INITIAL_POOL }.into(),
+        );
+        assert_eq!(Incentive::on_chain_storage_version(), StorageVersion::new(2));
+
+        // 之后的奖励从派生账户发放
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        assert_eq!(Balances::free_balance(pool_account()), INITIAL_POOL / 2 - FirstCreateReward::get());
+
+        // 已是最新版本时不再迁移
+        System::reset_events();
+        Incentive::on_runtime_upgrade();
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn fund_incentive_pool_increases_available_balance() {
+    new_test_ext().execute_with(|| {
+        Balances::make_free_balance_be(&1, 200_000);
+        assert_noop!(
+            Incentive::fund_incentive_pool(RuntimeOrigin::signed(1), 1, 100_000),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            Incentive::fund_incentive_pool(RuntimeOrigin::root(), 1, 0),
+            Error::<Test>::InvalidParameterValue
+        );
+
+        assert_ok!(Incentive::fund_incentive_pool(RuntimeOrigin::root(), 1, 100_000));
+        assert_eq!(Balances::free_balance(1), 100_000);
+        assert_eq!(Balances::free_balance(pool_account()), INITIAL_POOL + 100_000);
+        assert_eq!(IncentivePoolFunded::<Test>::get(), 100_000);
+        System::assert_last_event(
+            Event::IncentivePoolFunded { source: 1, amount: 100_000, pool_account: pool_account() }.into(),
+        );
+
+        // 注资部分立即可用：已释放额度耗尽后仍能用注资发放奖励
+        IncentivePoolReleased::<Test>::put(0);
+        assert_ok!(Incentive::distribute_first_create_reward(&2, &ASSET));
+        assert_eq!(Balances::free_balance(2), FirstCreateReward::get());
+    });
+}
+
+#[test]
+fn try_state_holds_after_reward_flows() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Incentive::do_try_state());
+
+        assert_ok!(Incentive::distribute_first_create_reward(&1, &ASSET));
+        assert_ok!(Incentive::distribute_liquidity_reward(&2, 10_000));
+        Incentive::register_market_volume_internal(&market_id(3), 1_000);
+        Incentive::on_initialize(MONTH_BLOCKS.into());
+
+        assert_ok!(Incentive::do_try_state());
+    });
+}
+
+#[test]
+fn try_state_detects_accounting_drift() {
+    new_test_ext().execute_with(|| {
+        IncentivePoolUsed::<Test>::put(INITIAL_RELEASED + 1);
+        assert!(Incentive::do_try_state().is_err());
+
+        IncentivePoolUsed::<Test>::put(0);
+        // 记账锁定了资金，但激励池账户实际并未 reserve
+        IncentivePoolReserved::<Test>::put(1_000);
+        assert!(Incentive::do_try_state().is_err());
+    });
+}
+
+/// 锁定激励池中尚未释放的部分，模拟链上初始化后的状态
+fn reserve_unreleased_pool() -> u128 {
+    let locked = INITIAL_POOL - INITIAL_RELEASED;
+    assert_ok!(Balances::reserve(&pool_account(), locked));
+    IncentivePoolReserved::<Test>::put(locked);
+    locked
+}
+
+#[test]
+fn release_uses_fixed_ratio_without_activity() {
+    new_test_ext().execute_with(|| {
+        let locked = reserve_unreleased_pool();
+
+        assert_ok!(Incentive::trigger_dynamic_release(RuntimeOrigin::root()));
+
+        let amount = DynamicReleaseRatio::get() * locked;
+        assert_eq!(IncentivePoolReleased::<Test>::get(), INITIAL_RELEASED + amount);
+        System::assert_last_event(
+            Event::IncentivePoolReleased {
+                amount,
+                new_balance: INITIAL_RELEASED + amount,
+                ratio: DynamicReleaseRatio::get(),
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn release_ratio_scales_with_market_activity() {
+    new_test_ext().execute_with(|| {
+        let locked = reserve_unreleased_pool();
+
+        // 交易总额为目标的一半：0.5% + 50% * (2% - 0.5%) = 1.25%
+        Incentive::register_market_volume_internal(&market_id(1), 300_000);
+        Incentive::register_market_volume_internal(&market_id(2), 200_000);
+        let ratio = Perbill::from_parts(12_500_000);
+        assert_eq!(Incentive::effective_release_ratio(), ratio);
+
+        assert_ok!(Incentive::trigger_dynamic_release(RuntimeOrigin::root()));
+        System::assert_last_event(
+            Event::IncentivePoolReleased {
+                amount: ratio * locked,
+                new_balance: INITIAL_RELEASED + ratio * locked,
+                ratio,
+                pool_account: pool_account(),
+            }
+            .into(),
+        );
+
+        // 超过目标后封顶为最高比例
+        Incentive::register_market_volume_internal(&market_id(3), 10 * TargetMonthlyTradeVolume::get());
+        assert_eq!(Incentive::effective_release_ratio(), MaxReleaseRatio::get());
+    });
+}
+
+#[test]
+fn release_runs_to_completion_at_exact_pool_total() {
+    new_test_ext().execute_with(|| {
+        reserve_unreleased_pool();
+
+        let mut rounds = 0;
+        while IncentivePoolReleased::<Test>::get() < INITIAL_POOL {
+            assert_ok!(Incentive::trigger_dynamic_release(RuntimeOrigin::root()));
+            rounds += 1;
+            assert!(rounds < 2_000, "release never reached the pool total");
+        }
+
+        // 最后一次释放剩余全部额度，累计释放量精确等于初始总额
+        assert_eq!(IncentivePoolReleased::<Test>::get(), INITIAL_POOL);
+        assert_eq!(IncentivePoolReserved::<Test>::get(), 0);
+        assert_eq!(Balances::reserved_balance(pool_account()), 0);
+
+        // 释放完成后只发出跳过事件，不再释放
+        System::reset_events();
+        assert_ok!(Incentive::trigger_dynamic_release(RuntimeOrigin::root()));
+        assert_eq!(
+            System::events().into_iter().map(|record| record.event).collect::<Vec<_>>(),
+            vec![RuntimeEvent::from(Event::ReleaseSkippedExhausted { released: INITIAL_POOL, pool_account: pool_account() })]
+        );
+        assert_eq!(IncentivePoolReleased::<Test>::get(), INITIAL_POOL);
+    });
+}
+
+#[test]
+fn release_without_reserved_funds_is_skipped_with_event() {
+    new_test_ext().execute_with(|| {
+        // 激励池账户没有任何锁定余额，按比例计算的释放额度无法解锁
+        let required = DynamicReleaseRatio::get() * (INITIAL_POOL - INITIAL_RELEASED);
+
+        assert_ok!(Incentive::trigger_dynamic_release(RuntimeOrigin::root()));
+
+        assert_eq!(IncentivePoolReleased::<Test>::get(), INITIAL_RELEASED);
+        System::assert_last_event(
+            Event::ReleaseSkippedInsufficientBalance { required, reserved: 0, pool_account: pool_account() }.into(),
+        );
+    });
+}
+
+#[test]
+fn vesting_remaining_wraps_vesting_schedule() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(Incentive::vesting_remaining(&FOUNDATION), 0);
+        MockVesting::set_locked(FOUNDATION, 400_000);
+        assert_eq!(Incentive::vesting_remaining(&FOUNDATION), 400_000);
+    });
+}
+
+#[test]
+fn vesting_milestone_emitted_once_per_step() {
+    new_test_ext().execute_with(|| {
+        MockVesting::set_locked(FOUNDATION, FOUNDATION_TOTAL);
+        Incentive::check_vesting_milestone();
+        assert_eq!(VestingMilestonesReached::<Test>::get(), 0);
+        assert!(System::events().is_empty());
+
+        // 解锁 25%，跨过两个 10% 里程碑，只触发一次事件
+        MockVesting::set_locked(FOUNDATION, 750_000);
+        Incentive::check_vesting_milestone();
+        assert_eq!(VestingMilestonesReached::<Test>::get(), 2);
+        System::assert_last_event(
+            Event::VestingMilestone {
+                account: FOUNDATION,
+                released: 250_000,
+                remaining: 750_000,
+                milestone: Perbill::from_percent(20),
+            }
+            .into(),
+        );
+
+        // 未跨过新的里程碑时不重复通知
+        System::reset_events();
+        MockVesting::set_locked(FOUNDATION, 710_000);
+        Incentive::check_vesting_milestone();
+        assert!(System::events().is_empty());
+
+        // 在检查间隔的区块由 on_initialize 触发
+        MockVesting::set_locked(FOUNDATION, 0);
+        let check_block = VESTING_CHECK_BLOCKS as u64;
+        System::set_block_number(check_block);
+        Incentive::on_initialize(check_block);
+        assert_eq!(VestingMilestonesReached::<Test>::get(), 10);
+        System::assert_last_event(
+            Event::VestingMilestone {
+                account: FOUNDATION,
+                released: FOUNDATION_TOTAL,
+                remaining: 0,
+                milestone: Perbill::one(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn rewards_below_one_unit_are_flagged() {
+    new_test_ext().execute_with(|| {
+        assert!(Incentive::rewards_below_unit().is_empty());
+
+        // 按 100 个最小单位为 1 DAT 计，50 的验证奖励明显漏乘了精度
+        Unit::set(100);
+        assert_eq!(Incentive::rewards_below_unit(), vec!["ValidatorVerificationReward"]);
+
+        Unit::set(1_000_000);
+        assert_eq!(Incentive::rewards_below_unit().len(), 6);
+        assert_ok!(Incentive::do_try_state());
+    });
+}
+
+#[test]
+fn issuance_reward_skips_approvers_that_are_not_markets() {
+    new_test_ext().execute_with(|| {
+        IssuanceOperatorReward::set(100);
+        let used = IncentivePoolUsed::<Test>::get();
+
+        // 被授权代理发行的普通账户不是注册市场，不发放奖励
+        assert_ok!(<Incentive as IncentiveHandler<u64, [u8; 32], u128>>::reward_issuance_operator(&6));
+        assert_eq!(Balances::free_balance(6), 0);
+        assert_eq!(IncentivePoolUsed::<Test>::get(), used);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::Incentive(Event::IssuanceOperatorRewardDistributed { .. })
+        )));
+    });
+}
+
+#[test]
+fn issuance_operator_reward_is_optional_and_pool_guarded() {
+    new_test_ext().execute_with(|| {
+        // 默认奖励为 0，不发放
+        assert_ok!(Incentive::reward_issuance_operator(&5));
+        assert_eq!(Balances::free_balance(5), 0);
+
+        IssuanceOperatorReward::set(100);
+        MockMarketProvider::register(5);
+        assert_ok!(<Incentive as IncentiveHandler<u64, [u8; 32], u128>>::reward_issuance_operator(&5));
+        assert_eq!(Balances::free_balance(5), 100);
+        System::assert_last_event(
+            Event::IssuanceOperatorRewardDistributed { market: 5, amount: 100, pool_account: pool_account() }.into(),
+        );
+
+        // 激励池可用额度不足时不发放
+        IncentivePoolUsed::<Test>::put(INITIAL_RELEASED);
+        assert_eq!(
+            <Incentive as IncentiveHandler<u64, [u8; 32], u128>>::reward_issuance_operator(&5),
+            Err(IncentiveError::InsufficientPool)
+        );
+        assert_eq!(Balances::free_balance(5), 100);
+    });
+}
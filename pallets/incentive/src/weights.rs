@@ -71,6 +71,7 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(T::DbWeight::get().reads(6))
 			.saturating_add(T::DbWeight::get().writes(3))
 	}
+	/// Storage: `Markets::Markets` (r:1 w:0)
 	/// Storage: `Incentive::MarketMonthlyVolume` (r:0 w:1)
 	/// Proof: `Incentive::MarketMonthlyVolume` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
 	fn register_market_monthly_volume() -> Weight {
@@ -79,7 +80,8 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 		//  Estimated: `0`
 		// Minimum execution time: 2_820_000 picoseconds.
 		Weight::from_parts(3_158_000, 0)
-			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(0, 3_500))
+			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
 	/// Storage: `Incentive::GovernanceVotingWeight` (r:0 w:1)
@@ -93,4 +95,86 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Incentive::IncentivePoolFunded` (r:1 w:1)
+	/// Proof: `Incentive::IncentivePoolFunded` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn fund_incentive_pool() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `206`
+		//  Estimated: `6196`
+		// Minimum execution time: 41_870_000 picoseconds.
+		Weight::from_parts(43_512_000, 0)
+			.saturating_add(Weight::from_parts(0, 6196))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Incentive::RewardDenylist` (r:0 w:1)
+	/// Proof: `Incentive::RewardDenylist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn add_to_denylist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_870_000 picoseconds.
+		Weight::from_parts(5_213_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Incentive::RewardDenylist` (r:0 w:1)
+	/// Proof: `Incentive::RewardDenylist` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	fn remove_from_denylist() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 4_612_000 picoseconds.
+		Weight::from_parts(4_958_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Incentive::GovernanceVotingWeight` (r:100 w:100)
+	/// Proof: `Incentive::GovernanceVotingWeight` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 100]`.
+	fn register_voting_weights_batch(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0 + n * (73 ±0)`
+		//  Estimated: `990 + n * (2539 ±0)`
+		// Minimum execution time: 3_418_000 picoseconds.
+		Weight::from_parts(3_702_000, 990)
+			// Standard Error: 2_145
+			.saturating_add(Weight::from_parts(2_861_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2539).saturating_mul(n.into()))
+	}
+	/// Storage: `Incentive::DistributionInterval` (r:1 w:1)
+	/// Proof: `Incentive::DistributionInterval` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_distribution_interval() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1489`
+		// Minimum execution time: 5_102_000 picoseconds.
+		Weight::from_parts(5_437_000, 0)
+			.saturating_add(Weight::from_parts(0, 1489))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `Incentive::PendingReward` (r:1 w:1)
+	/// Proof: `Incentive::PendingReward` (`max_values`: None, `max_size`: Some(64), added: 2539, mode: `MaxEncodedLen`)
+	/// Storage: `Incentive::IncentivePoolReleased` (r:1 w:0)
+	/// Proof: `Incentive::IncentivePoolReleased` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Incentive::IncentivePoolFunded` (r:1 w:0)
+	/// Proof: `Incentive::IncentivePoolFunded` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `Incentive::IncentivePoolUsed` (r:1 w:1)
+	/// Proof: `Incentive::IncentivePoolUsed` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn claim_pending_reward() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `341`
+		//  Estimated: `6196`
+		// Minimum execution time: 58_214_000 picoseconds.
+		Weight::from_parts(59_870_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
 }
@@ -6,7 +6,7 @@ use codec::Encode;
 use frame_benchmarking::{benchmarks, account, whitelisted_caller};
 use frame_system::RawOrigin;
 use frame_support::{
-	pallet_prelude::Weight,
+	pallet_prelude::{BoundedVec, Weight},
 	traits::{Currency, Get, ReservableCurrency},
 };
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -65,6 +65,10 @@ benchmarks! {
         let contract_address = instantiate_res.account_id;
         let asset_type = MarketAssetType::DataAsset;
 
+        // 注册前需先以 MarketOperator 角色质押最小金额
+        let min_collateral = <T as pallet_collaterals::Config>::MinMarketOperatorCollateral::get();
+        pallet_collaterals::Pallet::<T>::internal_pledge(&caller, CollateralRole::MarketOperator, min_collateral)?;
+
     }: _(RawOrigin::Signed(caller.clone()), contract_address.clone(), asset_type)
     verify {
         assert!(RegisteredMarkets::<T>::contains_key(&contract_address));
@@ -82,10 +86,54 @@ benchmarks! {
 			status: MarketStatus::Active,
 		};
 		RegisteredMarkets::<T>::insert(&contract_address, info);
+		MarketCount::<T>::put(1);
+		OperatorMarketCount::<T>::insert(&caller, 1);
+		ActiveMarkets::<T>::put(BoundedVec::truncate_from(vec![contract_address.clone()]));
+
+		// 最坏情况：注销最后一个市场并解押，质押需已满 2 年锁定期
+		let total_funding = <<T as pallet::Config>::Currency as Currency<T::AccountId>>::minimum_balance() * 1000000u32.into();
+		<<T as pallet::Config>::Currency as Currency<T::AccountId>>::make_free_balance_be(&caller, total_funding);
+		let min_collateral = <T as pallet_collaterals::Config>::MinMarketOperatorCollateral::get();
+		pallet_collaterals::Pallet::<T>::internal_pledge(&caller, CollateralRole::MarketOperator, min_collateral)?;
+		frame_system::Pallet::<T>::set_block_number((365 * 2 * 24 * 60u32 + 2).into());
 
 	}: _(RawOrigin::Signed(caller), contract_address.clone())
 	verify {
 		assert!(!RegisteredMarkets::<T>::contains_key(&contract_address));
+		assert!(ActiveMarkets::<T>::get().is_empty());
+	}
+
+	set_market_type {
+		let spec = MarketTypeSpec {
+			name: b"OrderBook".to_vec().try_into().unwrap(),
+			required_selectors: vec![[0x26, 0x3e, 0x53, 0x34]; 16].try_into().unwrap(),
+		};
+	}: _(RawOrigin::Root, 0u8, Some(spec))
+	verify {
+		assert!(MarketTypeRegistry::<T>::contains_key(0u8));
+	}
+
+	set_market_status {
+		let caller: T::AccountId = whitelisted_caller();
+		let contract_address: T::AccountId = account("contract", 0, 0);
+
+		// 最坏情况：索引已满，需要遍历整个 ActiveMarkets
+		let mut markets: Vec<T::AccountId> = (1..T::MaxMarkets::get())
+			.map(|i| account("market", i, 0))
+			.collect();
+		markets.push(contract_address.clone());
+		ActiveMarkets::<T>::put(BoundedVec::truncate_from(markets));
+
+		let info = MarketRegistryInfo {
+			creator: caller,
+			contract_address: contract_address.clone(),
+			asset_type: MarketAssetType::DataAsset,
+			status: MarketStatus::Active,
+		};
+		RegisteredMarkets::<T>::insert(&contract_address, info);
+	}: _(RawOrigin::Root, contract_address.clone(), MarketStatus::Inactive)
+	verify {
+		assert!(!ActiveMarkets::<T>::get().contains(&contract_address));
 	}
 
 	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
@@ -13,33 +13,57 @@ pub use pallet::*;
 use frame_support::traits::{Currency, ReservableCurrency};
 
 use pallet_collaterals::{CollateralRole};
+use pallet_shared_traits::{CollateralManager, MarketProvider};
 
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
 
 pub mod weights;
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
 
 mod original_lib;
 
+// 存储版本，存储结构变更时递增并在 migrations 中添加对应的迁移
+const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::{pallet_prelude::*};
+    use frame_support::{
+        pallet_prelude::*,
+        dispatch::{DispatchErrorWithPostInfo, PostDispatchInfo},
+        storage::{with_transaction, TransactionOutcome},
+    };
+    use sp_std::vec::Vec;
+    use sp_runtime::traits::Saturating;
     use frame_system::{pallet_prelude::*};
 
     use pallet_contracts::{CollectEvents, DebugInfo, Determinism, chain_extension::ReturnFlags};
 
-    use codec::{Encode, Decode, MaxEncodedLen, DecodeWithMemTracking};
+    use codec::{Encode, Decode, DecodeAll, MaxEncodedLen, DecodeWithMemTracking};
     
     /// 函数选择器：对应ink!合约的is_assetx_market()方法
     const SELECTOR_IS_MARKET: [u8; 4] = [0x26, 0x3e, 0x53, 0x34];
+    /// 函数选择器：对应ink!合约的get_market_type()方法
+    const SELECTOR_GET_MARKET_TYPE: [u8; 4] = [0xee, 0x71, 0x87, 0xef];
+    /// 函数选择器：对应ink!合约的get_fee_ratio()方法
+    const SELECTOR_GET_FEE_RATIO: [u8; 4] = [0xdc, 0x93, 0xda, 0xb1];
 
     pub trait WeightInfo {
         fn register_market() -> Weight;
         fn unregister_market() -> Weight;
+        fn set_market_type() -> Weight;
+        fn set_market_status() -> Weight;
     }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
     
     // 市场资产类型
@@ -58,6 +82,24 @@ pub mod pallet {
         pub status: MarketStatus,       // Active, Suspended
     }
 
+    // 市场类型规范：get_market_type 返回值对应的名称和必须实现的方法
+    // required_selectors 只能是无参数的查询方法，注册时会逐个调用验证；有参数的方法通过 ABI 在链下检查
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    pub struct MarketTypeSpec {
+        pub name: BoundedVec<u8, ConstU32<32>>,
+        pub required_selectors: BoundedVec<[u8; 4], ConstU32<16>>,
+    }
+
+    // 市场合约检查结果：一次调用解码市场标准的查询方法，供前端接入新市场
+    // 合约未实现或调用失败的方法对应字段为 None
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
+    pub struct MarketInspection<BlockNumber> {
+        pub is_market: bool,            // is_assetx_market 是否返回 true
+        pub market_type: Option<u8>,    // get_market_type 返回值
+        pub fee_ratio: Option<u32>,     // get_fee_ratio 返回值 (Basis Points)
+        pub verified_at: BlockNumber,   // 检查时的区块号
+    }
+
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, DecodeWithMemTracking)]
     pub enum MarketStatus {
         Active,
@@ -70,6 +112,22 @@ pub mod pallet {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         type Currency: Currency<Self::AccountId> + ReservableCurrency<Self::AccountId>;
         type MarketWeightInfo: WeightInfo;
+
+        /// 查询与解除市场运营者质押（pallet-collaterals）
+        type CollateralManager: CollateralManager<Self::AccountId, CollateralRole, pallet_collaterals::BalanceOf<Self>>;
+
+        /// 注册市场时验证合约的 gas 上限，实际消耗由注册者承担
+        #[pallet::constant]
+        type MarketVerificationGasLimit: Get<Weight>;
+
+        /// 市场合约 get_fee_ratio 允许声明的最高手续费比例 (Basis Points)，超过时拒绝注册
+        #[pallet::constant]
+        type MaxMarketFeeRatio: Get<u32>;
+
+        /// 可注册的市场数量上限，同时是 ActiveMarkets 索引和 list_markets 返回数量的上界
+        /// 达到上限后需先注销市场才能注册新市场
+        #[pallet::constant]
+        type MaxMarkets: Get<u32>;
     }
 
     #[pallet::event]
@@ -79,10 +137,21 @@ pub mod pallet {
             creator: T::AccountId,
             contract_address: T::AccountId,
             asset_type: MarketAssetType,
+            /// 合约声明并通过验证的市场类型，注册表为空时为 None
+            market_type: Option<u8>,
         },
+        /// 治理设置市场类型规范
+        MarketTypeSet { market_type: u8, spec: MarketTypeSpec },
+        /// 治理移除市场类型
+        MarketTypeRemoved { market_type: u8 },
         MarketUnregistered {
             contract_address: T::AccountId,
         },
+        /// 治理修改市场状态，Inactive 的市场不出现在 ActiveMarkets 中
+        MarketStatusChanged {
+            contract_address: T::AccountId,
+            status: MarketStatus,
+        },
     }
 
     #[pallet::error]
@@ -93,8 +162,22 @@ pub mod pallet {
         MarketNotFound,
         /// 不是市场所有者
         NotOwner,
-        /// 市场验证失败
+        /// 市场验证失败（合约回退、未实现方法或返回值无法解码）
         MarketVerificationFailed,
+        /// 合约的 is_assetx_market 明确返回 false
+        MarketRejectedStandard,
+        /// 验证合约时 gas 耗尽
+        VerificationOutOfGas,
+        /// 合约声明的市场类型未在注册表中
+        UnknownMarketType,
+        /// 合约声明的手续费比例超过 MaxMarketFeeRatio
+        MarketFeeTooHigh,
+        /// 合约未实现市场类型要求的方法
+        MissingRequiredMethod,
+        /// 创建者以 MarketOperator 角色质押的金额低于 MinMarketOperatorCollateral × (名下市场数 + 1)
+        InsufficientOperatorBond,
+        /// 已注册的市场数量达到 MaxMarkets 上限
+        TooManyMarkets,
     }
 
     #[pallet::storage]
@@ -106,67 +189,77 @@ pub mod pallet {
         MarketRegistryInfo<T::AccountId>
     >;
 
+    /// 已注册的市场数量（含 Inactive），不超过 MaxMarkets
+    #[pallet::storage]
+    #[pallet::getter(fn market_count)]
+    pub type MarketCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// 运营者名下已注册的市场数量（含 Inactive），每个市场需要 MinMarketOperatorCollateral 的质押
+    #[pallet::storage]
+    #[pallet::getter(fn operator_market_count)]
+    pub type OperatorMarketCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    /// 状态为 Active 的市场合约地址索引，供 list_markets 运行时 API 枚举，无需遍历 RegisteredMarkets
+    #[pallet::storage]
+    #[pallet::getter(fn active_markets)]
+    pub type ActiveMarkets<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxMarkets>, ValueQuery>;
+
+    /// 市场类型注册表：get_market_type 返回值 -> 类型规范（由治理设置）
+    #[pallet::storage]
+    #[pallet::getter(fn market_type_registry)]
+    pub type MarketTypeRegistry<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u8,
+        MarketTypeSpec,
+    >;
+
+    /// 市场合约检查结果缓存：合约地址 -> 最近一次 inspect_market 的结果（含 verified_at）
+    #[pallet::storage]
+    #[pallet::getter(fn market_inspections)]
+    pub type MarketInspections<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        MarketInspection<BlockNumberFor<T>>,
+    >;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// 注册一个新市场
         /// 用户先部署智能合约，获得 ontract_address，然后调用此函数进行注册
         #[pallet::call_index(0)]
-        #[pallet::weight(T::MarketWeightInfo::register_market())]
+        #[pallet::weight(T::MarketWeightInfo::register_market().saturating_add(T::MarketVerificationGasLimit::get()))]
         pub fn register_market(
             origin: OriginFor<T>,
             contract_address: T::AccountId,
             asset_type: MarketAssetType,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let creator = ensure_signed(origin)?;
 
             // 1. 基础检查
             ensure!(!RegisteredMarkets::<T>::contains_key(&contract_address), Error::<T>::MarketAlreadyExists);
+            ensure!(MarketCount::<T>::get() < T::MaxMarkets::get(), Error::<T>::TooManyMarkets);
             let asset_type_for_event = asset_type.clone();
 
-            // 2.质押
-            // 获取配置中定义的市场运营者最小质押金额
-            let min_collateral = <T as pallet_collaterals::Config>::MinMarketOperatorCollateral::get();
-            
-            // 调用pallet-collaterals的内部质押函数
-            // 检查余额并锁定资金，余额不足会返回Error
-            pallet_collaterals::Pallet::<T>::internal_pledge(
-                &creator,
-                CollateralRole::MarketOperator,
-                min_collateral
-            )?;
-            
-            // 3. 验证合约逻辑
-            let input_data = SELECTOR_IS_MARKET.to_vec();
-            let gas_limit = Weight::from_parts(5_000_000_000, 256 * 1024);
-
-            let result = pallet_contracts::Pallet::<T>::bare_call(
-                creator.clone(),
-                contract_address.clone(),
-                0u32.into(),
-                gas_limit,
-                None,
-                input_data,
-                DebugInfo::Skip,
-                CollectEvents::Skip,
-                Determinism::Enforced,
+            // 2.质押检查
+            // 创建者需先通过 pallet-collaterals 以 MarketOperator 角色质押，名下每个市场（含本次注册）各需最小金额
+            let owned = OperatorMarketCount::<T>::get(&creator);
+            let required = <T as pallet_collaterals::Config>::MinMarketOperatorCollateral::get()
+                .saturating_mul(owned.saturating_add(1).into());
+            ensure!(
+                T::CollateralManager::pledged_amount(&creator, CollateralRole::MarketOperator) >= required,
+                Error::<T>::InsufficientOperatorBond
             );
-
-            let verified = match result.result {
-                Ok(retval) => {
-                    if retval.flags.contains(ReturnFlags::REVERT) {
-                        false
-                    } else {
-                        let decoded_result: Result<Result<bool, u8>, _> = Decode::decode(&mut &retval.data[..]);
-                        match decoded_result {
-                            Ok(Ok(true)) => true,
-                            _ => false,
-                        }
-                    }
-                },
-                Err(_) => false,
-            };
-
-            ensure!(verified, Error::<T>::MarketVerificationFailed);
+            
+            // 3. 验证合约逻辑，按实际消耗的 gas 收费，未使用的部分退还
+            let mut gas_used = Weight::zero();
+            let verification = Self::verify_market(&creator, &contract_address, &mut gas_used);
+            let actual_weight = T::MarketWeightInfo::register_market().saturating_add(gas_used);
+            let market_type = verification.map_err(|error| DispatchErrorWithPostInfo {
+                post_info: PostDispatchInfo { actual_weight: Some(actual_weight), pays_fee: Pays::Yes },
+                error: error.into(),
+            })?;
 
             // 4. 存储市场信息
             let info = MarketRegistryInfo {
@@ -177,9 +270,13 @@ pub mod pallet {
             };
 
             RegisteredMarkets::<T>::insert(&contract_address, info);
+            MarketCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            OperatorMarketCount::<T>::insert(&creator, owned.saturating_add(1));
+            ActiveMarkets::<T>::try_mutate(|markets| markets.try_push(contract_address.clone()))
+                .map_err(|_| Error::<T>::TooManyMarkets)?;
 
-            Self::deposit_event(Event::MarketRegistered { creator, contract_address, asset_type: asset_type_for_event });
-            Ok(())
+            Self::deposit_event(Event::MarketRegistered { creator, contract_address, asset_type: asset_type_for_event, market_type });
+            Ok(Some(actual_weight).into())
         }
 
         /// 注销市场
@@ -196,19 +293,214 @@ pub mod pallet {
             ensure!(market.creator == who, Error::<T>::NotOwner);
 
             // 2. 解除质押
+            // 质押按角色整体记录，名下还有其他市场时继续锁定，注销最后一个市场时才解押
             // 调用 pallet-collaterals 的内部解押函数
             // 如果未满 2 年，这里会返回CollateralNotReadyForRelease错误
             // 这意味着市场在锁定期内无法被完全注销
-            pallet_collaterals::Pallet::<T>::internal_unbond(
-                &who,
-                CollateralRole::MarketOperator
-            )?;
+            let remaining = OperatorMarketCount::<T>::get(&who).saturating_sub(1);
+            if remaining == 0 {
+                T::CollateralManager::internal_unbond(
+                    &who,
+                    CollateralRole::MarketOperator
+                )?;
+                OperatorMarketCount::<T>::remove(&who);
+            } else {
+                OperatorMarketCount::<T>::insert(&who, remaining);
+            }
 
             // 3. 移除市场信息
             RegisteredMarkets::<T>::remove(&contract_address);
+            MarketInspections::<T>::remove(&contract_address);
+            MarketCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            ActiveMarkets::<T>::mutate(|markets| markets.retain(|market| market != &contract_address));
             
             Self::deposit_event(Event::MarketUnregistered { contract_address });
             Ok(())
         }
+
+        /// 设置或移除（spec 为 None）市场类型规范，仅限治理
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::MarketWeightInfo::set_market_type())]
+        pub fn set_market_type(
+            origin: OriginFor<T>,
+            market_type: u8,
+            spec: Option<MarketTypeSpec>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match spec {
+                Some(spec) => {
+                    MarketTypeRegistry::<T>::insert(market_type, spec.clone());
+                    Self::deposit_event(Event::MarketTypeSet { market_type, spec });
+                },
+                None => {
+                    MarketTypeRegistry::<T>::remove(market_type);
+                    Self::deposit_event(Event::MarketTypeRemoved { market_type });
+                },
+            }
+            Ok(())
+        }
+
+        /// 设置市场状态，仅限治理；同步维护 ActiveMarkets 索引
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::MarketWeightInfo::set_market_status())]
+        pub fn set_market_status(
+            origin: OriginFor<T>,
+            contract_address: T::AccountId,
+            status: MarketStatus,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RegisteredMarkets::<T>::try_mutate(&contract_address, |market| -> DispatchResult {
+                let market = market.as_mut().ok_or(Error::<T>::MarketNotFound)?;
+                market.status = status.clone();
+                Ok(())
+            })?;
+
+            ActiveMarkets::<T>::try_mutate(|markets| -> DispatchResult {
+                markets.retain(|market| market != &contract_address);
+                if status == MarketStatus::Active {
+                    markets.try_push(contract_address.clone()).map_err(|_| Error::<T>::TooManyMarkets)?;
+                }
+                Ok(())
+            })?;
+
+            Self::deposit_event(Event::MarketStatusChanged { contract_address, status });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 返回所有 Active 市场及其注册信息（资产类型、状态等），数量不超过 MaxMarkets
+        /// 手续费比例等合约数据通过 inspect_market 查询
+        pub fn list_markets() -> Vec<(T::AccountId, MarketRegistryInfo<T::AccountId>)> {
+            ActiveMarkets::<T>::get()
+                .into_iter()
+                .filter_map(|market| RegisteredMarkets::<T>::get(&market).map(|info| (market, info)))
+                .collect()
+        }
+
+        /// 依次调用合约的 is_assetx_market、get_market_type、get_fee_ratio 并返回解码结果
+        /// 以合约自身为调用者，三次调用共用 MarketVerificationGasLimit，调用产生的状态修改全部回滚
+        /// 结果写入 MarketInspections 缓存；通过运行时 API 只读调用时缓存写入不会持久化
+        pub fn inspect_market(
+            contract_address: &T::AccountId,
+        ) -> Result<MarketInspection<BlockNumberFor<T>>, Error<T>> {
+            let mut gas_used = Weight::zero();
+
+            let is_market = Self::query_market::<bool>(contract_address, SELECTOR_IS_MARKET, &mut gas_used)?
+                .unwrap_or(false);
+            let market_type = Self::query_market::<u8>(contract_address, SELECTOR_GET_MARKET_TYPE, &mut gas_used)?;
+            let fee_ratio = Self::query_market::<u32>(contract_address, SELECTOR_GET_FEE_RATIO, &mut gas_used)?;
+
+            let inspection = MarketInspection {
+                is_market,
+                market_type,
+                fee_ratio,
+                verified_at: frame_system::Pallet::<T>::block_number(),
+            };
+            MarketInspections::<T>::insert(contract_address, inspection.clone());
+            Ok(inspection)
+        }
+
+        /// 调用合约的无参数查询方法并解码 ink! 返回值，未实现或解码失败时为 None
+        fn query_market<V: Decode>(
+            contract_address: &T::AccountId,
+            selector: [u8; 4],
+            gas_used: &mut Weight,
+        ) -> Result<Option<V>, Error<T>> {
+            Ok(Self::probe_market(contract_address, contract_address, selector.to_vec(), gas_used)?
+                .and_then(|data| {
+                    let decoded_result: Result<Result<V, u8>, _> = Decode::decode(&mut &data[..]);
+                    decoded_result.ok().and_then(|r| r.ok())
+                }))
+        }
+
+        /// 验证合约是否为符合标准的市场，返回通过验证的市场类型
+        /// 先检查 is_assetx_market 与 get_fee_ratio（不超过 MaxMarketFeeRatio），
+        /// 注册表为空时不检查市场类型，返回 None
+        fn verify_market(
+            caller: &T::AccountId,
+            contract_address: &T::AccountId,
+            gas_used: &mut Weight,
+        ) -> Result<Option<u8>, Error<T>> {
+            // 返回值必须恰好是 ink! 编码的 Result<bool, LangError>：空返回、多余字节或 LangError 都按验证失败处理，
+            // 只有明确返回 false 才是合约声明自己不符合市场标准
+            let data = Self::probe_market(caller, contract_address, SELECTOR_IS_MARKET.to_vec(), gas_used)?
+                .ok_or(Error::<T>::MarketVerificationFailed)?;
+            let is_market = match <Result<bool, u8>>::decode_all(&mut &data[..]) {
+                Ok(Ok(is_market)) => is_market,
+                _ => return Err(Error::<T>::MarketVerificationFailed),
+            };
+            ensure!(is_market, Error::<T>::MarketRejectedStandard);
+
+            let fee_ratio = Self::probe_market(caller, contract_address, SELECTOR_GET_FEE_RATIO.to_vec(), gas_used)?
+                .and_then(|data| {
+                    let decoded_result: Result<Result<u32, u8>, _> = Decode::decode(&mut &data[..]);
+                    decoded_result.ok().and_then(|r| r.ok())
+                })
+                .ok_or(Error::<T>::MarketVerificationFailed)?;
+            ensure!(fee_ratio <= T::MaxMarketFeeRatio::get(), Error::<T>::MarketFeeTooHigh);
+
+            if MarketTypeRegistry::<T>::iter_keys().next().is_none() {
+                return Ok(None);
+            }
+
+            let market_type = Self::probe_market(caller, contract_address, SELECTOR_GET_MARKET_TYPE.to_vec(), gas_used)?
+                .and_then(|data| {
+                    let decoded_result: Result<Result<u8, u8>, _> = Decode::decode(&mut &data[..]);
+                    decoded_result.ok().and_then(|r| r.ok())
+                })
+                .ok_or(Error::<T>::MarketVerificationFailed)?;
+            let spec = MarketTypeRegistry::<T>::get(market_type).ok_or(Error::<T>::UnknownMarketType)?;
+
+            for selector in spec.required_selectors.iter() {
+                let implemented = Self::probe_market(caller, contract_address, selector.to_vec(), gas_used)?.is_some();
+                ensure!(implemented, Error::<T>::MissingRequiredMethod);
+            }
+
+            Ok(Some(market_type))
+        }
+
+        /// 调用市场合约并回滚调用产生的所有状态修改，累计消耗的 gas（总量不超过 MarketVerificationGasLimit）
+        /// 合约正常返回时为 Some(返回数据)，回退或调用失败时为 None
+        fn probe_market(
+            caller: &T::AccountId,
+            contract_address: &T::AccountId,
+            input_data: Vec<u8>,
+            gas_used: &mut Weight,
+        ) -> Result<Option<Vec<u8>>, Error<T>> {
+            let gas_limit = T::MarketVerificationGasLimit::get().saturating_sub(*gas_used);
+
+            let result = with_transaction(|| {
+                let result = pallet_contracts::Pallet::<T>::bare_call(
+                    caller.clone(),
+                    contract_address.clone(),
+                    0u32.into(),
+                    gas_limit,
+                    None,
+                    input_data,
+                    DebugInfo::Skip,
+                    CollectEvents::Skip,
+                    Determinism::Enforced,
+                );
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(result))
+            })
+            .map_err(|_| Error::<T>::MarketVerificationFailed)?;
+            *gas_used = gas_used.saturating_add(result.gas_consumed);
+
+            match result.result {
+                Ok(retval) if !retval.flags.contains(ReturnFlags::REVERT) => Ok(Some(retval.data)),
+                Ok(_) => Ok(None),
+                Err(e) if e == pallet_contracts::Error::<T>::OutOfGas.into() => Err(Error::<T>::VerificationOutOfGas),
+                Err(_) => Ok(None),
+            }
+        }
+    }
+}
+
+impl<T: Config> MarketProvider<T::AccountId> for Pallet<T> {
+    fn is_active_market(market: &T::AccountId) -> bool {
+        RegisteredMarkets::<T>::get(market).map_or(false, |info| info.status == MarketStatus::Active)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,49 @@
+/// 市场模块的存储迁移
+///
+/// 每个版本一个子模块，内部迁移不检查版本，由 VersionedMigration 包装后
+/// 只在链上版本匹配时执行并更新版本号。新增的迁移需要加入 runtime 的 Migrations 元组。
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+    BoundedVec,
+};
+use sp_std::vec::Vec;
+
+pub mod v1 {
+    use super::*;
+
+    /// 按已注册的市场回填 MarketCount、ActiveMarkets 和各运营者的 OperatorMarketCount
+    pub struct InnerInitMarketIndexes<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerInitMarketIndexes<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut entries: u64 = 0;
+            let mut active: Vec<T::AccountId> = Vec::new();
+            let _ = OperatorMarketCount::<T>::clear(u32::MAX, None);
+            for (contract_address, info) in RegisteredMarkets::<T>::iter() {
+                entries += 1;
+                OperatorMarketCount::<T>::mutate(&info.creator, |count| *count = count.saturating_add(1));
+                if info.status == MarketStatus::Active {
+                    active.push(contract_address);
+                }
+            }
+
+            MarketCount::<T>::put(entries.min(u32::MAX as u64) as u32);
+            // 活跃市场超过 MaxMarkets 时只保留上限内的部分，与 register_market 的上限一致
+            ActiveMarkets::<T>::put(BoundedVec::truncate_from(active));
+
+            <T as frame_system::Config>::DbWeight::get().reads_writes(entries, entries.saturating_add(2))
+        }
+    }
+
+    pub type InitMarketIndexes<T> = VersionedMigration<
+        0,
+        1,
+        InnerInitMarketIndexes<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
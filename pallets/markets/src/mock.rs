@@ -0,0 +1,272 @@
+use crate as pallet_markets;
+use codec::Encode;
+use frame_support::{
+    derive_impl, parameter_types,
+    traits::{ConstU128, ConstU32, ConstU64, Contains},
+    weights::Weight,
+};
+use frame_system::EnsureSigned;
+use pallet_collaterals::CollateralRole;
+use pallet_contracts::{Code, CollectEvents, DebugInfo};
+use sp_runtime::{AccountId32, BuildStorage, Perbill};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+pub type AccountId = AccountId32;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        Contracts: pallet_contracts,
+        Collaterals: pallet_collaterals,
+        Markets: pallet_markets,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountId = AccountId;
+    type Lookup = sp_runtime::traits::IdentityLookup<AccountId>;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = RuntimeHoldReason;
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+parameter_types! {
+    pub ContractsSchedule: pallet_contracts::Schedule<Test> = Default::default();
+}
+
+#[derive_impl(pallet_contracts::config_preludes::TestDefaultConfig)]
+impl pallet_contracts::Config for Test {
+    type Time = Timestamp;
+    type Currency = Balances;
+    type Schedule = ContractsSchedule;
+    type CallStack = [pallet_contracts::Frame<Self>; 5];
+    type UploadOrigin = EnsureSigned<AccountId>;
+    type InstantiateOrigin = EnsureSigned<AccountId>;
+}
+
+parameter_types! {
+    pub const MinMarketOperatorCollateral: u128 = 1_000;
+    pub const MinIpfsProviderCollateral: u128 = 500;
+    pub const MinGovernancePledge: u128 = 2_000;
+    pub const MaxMarketOperatorCollateral: Option<u128> = None;
+    pub const MaxIpfsProviderCollateral: Option<u128> = None;
+    pub const MaxGovernancePledge: Option<u128> = None;
+    pub const PledgeActivationDelay: u64 = 10;
+    pub const EarlyUnbondPenalty: Perbill = Perbill::from_percent(10);
+    pub const EarlyUnbondSlashType: pallet_collaterals::SlashType = pallet_collaterals::SlashType::LightViolation;
+    pub const DestructionAccount: AccountId = AccountId32::new([0u8; 32]);
+    pub const IncentivePoolAccount: AccountId = AccountId32::new([10u8; 32]);
+    pub const IpfsPoolAccount: AccountId = AccountId32::new([11u8; 32]);
+    pub const CompensationPoolAccount: AccountId = AccountId32::new([12u8; 32]);
+}
+
+/// 与运行时一致：市场运营者不允许提前解押
+pub struct EarlyUnbondAllowedRoles;
+impl Contains<CollateralRole> for EarlyUnbondAllowedRoles {
+    fn contains(role: &CollateralRole) -> bool {
+        matches!(role, CollateralRole::IpfsProvider | CollateralRole::GovernancePledge)
+    }
+}
+
+impl pallet_collaterals::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MinMarketOperatorCollateral = MinMarketOperatorCollateral;
+    type MinIpfsProviderCollateral = MinIpfsProviderCollateral;
+    type MinGovernancePledge = MinGovernancePledge;
+    type MaxMarketOperatorCollateral = MaxMarketOperatorCollateral;
+    type MaxIpfsProviderCollateral = MaxIpfsProviderCollateral;
+    type MaxGovernancePledge = MaxGovernancePledge;
+    type PledgeActivationDelay = PledgeActivationDelay;
+    type EarlyUnbondPenalty = EarlyUnbondPenalty;
+    type EarlyUnbondSlashType = EarlyUnbondSlashType;
+    type EarlyUnbondAllowedRoles = EarlyUnbondAllowedRoles;
+    type IncentivePoolAccount = IncentivePoolAccount;
+    type DestructionAccount = DestructionAccount;
+    type IpfsPoolAccount = IpfsPoolAccount;
+    type CompensationPoolAccount = CompensationPoolAccount;
+    type SlashNotificationHandler = ();
+    type WeightInfo = pallet_collaterals::weights::WeightInfo<Test>;
+}
+
+parameter_types! {
+    pub MarketVerificationGasLimit: Weight = Weight::from_parts(5_000_000_000, 256 * 1024);
+    pub const MaxMarkets: u32 = 10;
+    pub const MaxMarketFeeRatio: u32 = 1_000;
+}
+
+impl pallet_markets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type MarketWeightInfo = crate::weights::WeightInfo<Test>;
+    type CollateralManager = Collaterals;
+    type MarketVerificationGasLimit = MarketVerificationGasLimit;
+    type MaxMarkets = MaxMarkets;
+    type MaxMarketFeeRatio = MaxMarketFeeRatio;
+}
+
+pub const ALICE: AccountId = AccountId32::new([1u8; 32]);
+pub const BOB: AccountId = AccountId32::new([2u8; 32]);
+
+/// 测试账户初始余额
+pub const INITIAL_BALANCE: u128 = 1_000_000_000_000;
+
+/// MarketOperator 质押满 2 年后才能解押（与 pallet-collaterals 一致）
+pub const OPERATOR_LOCK_BLOCKS: u64 = 365 * 2 * 24 * 60;
+
+/// 市场合约查询方法的返回：Some(原始返回数据) 时正常返回，None 时回退
+#[derive(Clone)]
+pub struct MarketBehaviour {
+    pub is_market: Option<Vec<u8>>,
+    pub fee_ratio: Option<Vec<u8>>,
+    pub market_type: Option<Vec<u8>>,
+}
+
+impl Default for MarketBehaviour {
+    /// is_assetx_market 返回 Ok(true)，get_fee_ratio 返回 Ok(100)，get_market_type 回退
+    fn default() -> Self {
+        Self {
+            is_market: Some(Ok::<bool, u8>(true).encode()),
+            fee_ratio: Some(Ok::<u32, u8>(100).encode()),
+            market_type: None,
+        }
+    }
+}
+
+/// 按 `behaviour` 生成最小的市场合约，未列出的方法回退
+fn market_wat(behaviour: &MarketBehaviour) -> String {
+    let methods = [
+        // is_assetx_market: 0x263e5334
+        ([0x26, 0x3e, 0x53, 0x34], &behaviour.is_market),
+        // get_fee_ratio: 0xdc93dab1
+        ([0xdc, 0x93, 0xda, 0xb1], &behaviour.fee_ratio),
+        // get_market_type: 0xee7187ef
+        ([0xee, 0x71, 0x87, 0xef], &behaviour.market_type),
+    ];
+
+    let mut data = String::new();
+    let mut branches = String::new();
+    for (index, (selector, output)) in methods.iter().enumerate() {
+        let Some(output) = output else { continue };
+        // 每个方法的返回数据占 [64 + 32 * index, 96 + 32 * index)
+        let offset = 64 + 32 * index;
+        let bytes: String = output.iter().map(|byte| format!("\\{:02x}", byte)).collect();
+        data.push_str(&format!("    (data (i32.const {offset}) \"{bytes}\")\n"));
+        branches.push_str(&format!(
+            "        (if (i32.eq (i32.load (i32.const 32)) (i32.const 0x{:08x}))\n            (then (call $seal_return (i32.const 0) (i32.const {offset}) (i32.const {}))))\n",
+            u32::from_le_bytes(*selector),
+            output.len(),
+        ));
+    }
+
+    format!(
+        r#"
+(module
+    (import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+    (import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+    (import "env" "memory" (memory 1 1))
+
+    ;; [0, 4) 输入缓冲区长度
+    (data (i32.const 0) "\20")
+{data}
+    (func (export "deploy"))
+
+    (func (export "call")
+        ;; [32, 64) 调用输入，前 4 字节为选择器
+        (call $seal_input (i32.const 32) (i32.const 0))
+{branches}        (call $seal_return (i32.const 1) (i32.const 0) (i32.const 0))
+    )
+)
+"#
+    )
+}
+
+/// 以 `owner` 部署 WAT 合约，`salt` 不同时得到不同的合约地址
+fn instantiate(owner: &AccountId, salt: u8, wat: &str) -> AccountId {
+    let wasm = wat::parse_str(wat).expect("市场合约 WAT 无效");
+    Contracts::bare_instantiate(
+        owner.clone(),
+        0,
+        Weight::MAX,
+        None,
+        Code::Upload(wasm),
+        vec![],
+        vec![salt],
+        DebugInfo::Skip,
+        CollectEvents::Skip,
+    )
+    .result
+    .expect("部署市场合约失败")
+    .account_id
+}
+
+/// 任何调用都陷入死循环、耗尽 gas 的合约
+const LOOPING_MARKET_WAT: &str = r#"
+(module
+    (import "env" "memory" (memory 1 1))
+
+    (func (export "deploy"))
+
+    (func (export "call")
+        (loop $forever
+            (br $forever)
+        )
+    )
+)
+"#;
+
+/// 以 `owner` 部署一个耗尽 gas 的合约
+pub fn deploy_looping_market(owner: &AccountId, salt: u8) -> AccountId {
+    instantiate(owner, salt, LOOPING_MARKET_WAT)
+}
+
+/// 以 `owner` 部署一个默认行为的市场合约
+pub fn deploy_market(owner: &AccountId, salt: u8) -> AccountId {
+    deploy_market_with(owner, salt, MarketBehaviour::default())
+}
+
+/// 以 `owner` 部署一个按 `behaviour` 返回的市场合约
+pub fn deploy_market_with(owner: &AccountId, salt: u8, behaviour: MarketBehaviour) -> AccountId {
+    instantiate(owner, salt, &market_wat(&behaviour))
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(ALICE, INITIAL_BALANCE), (BOB, INITIAL_BALANCE)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
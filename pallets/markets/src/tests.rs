@@ -0,0 +1,305 @@
+use crate::{mock::*, Error, Event, MarketTypeRegistry, OperatorMarketCount};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+use pallet_collaterals::CollateralRole;
+use pallet_shared_traits::MarketProvider;
+
+fn pledge(who: &AccountId, amount: u128) {
+    assert_ok!(Collaterals::internal_pledge(who, CollateralRole::MarketOperator, amount));
+}
+
+fn register(who: &AccountId, market: &AccountId) -> frame_support::dispatch::DispatchResultWithPostInfo {
+    Markets::register_market(RuntimeOrigin::signed(who.clone()), market.clone(), crate::MarketAssetType::DataAsset)
+}
+
+/// 注册市场并丢弃 post info，便于和 Error 比较
+fn try_register(who: &AccountId, market: &AccountId) -> frame_support::dispatch::DispatchResult {
+    register(who, market).map(|_| ()).map_err(|e| e.error)
+}
+
+#[test]
+fn register_market_requires_operator_bond() {
+    new_test_ext().execute_with(|| {
+        let market = deploy_market(&ALICE, 1);
+
+        assert_noop!(register(&ALICE, &market), Error::<Test>::InsufficientOperatorBond);
+
+        pledge(&ALICE, MinMarketOperatorCollateral::get());
+        assert_ok!(register(&ALICE, &market));
+        assert!(Markets::is_active_market(&market));
+        assert_eq!(OperatorMarketCount::<Test>::get(&ALICE), 1);
+        System::assert_last_event(
+            Event::MarketRegistered {
+                creator: ALICE,
+                contract_address: market,
+                asset_type: crate::MarketAssetType::DataAsset,
+                market_type: None,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn each_market_needs_its_own_operator_bond() {
+    new_test_ext().execute_with(|| {
+        let min = MinMarketOperatorCollateral::get();
+        let first = deploy_market(&ALICE, 1);
+        let second = deploy_market(&ALICE, 2);
+        pledge(&ALICE, min);
+        assert_ok!(register(&ALICE, &first));
+
+        // 同一份质押不能再担保第二个市场
+        assert_noop!(register(&ALICE, &second), Error::<Test>::InsufficientOperatorBond);
+
+        // 追加质押到 2 × MinMarketOperatorCollateral 后可以注册
+        pledge(&ALICE, min);
+        assert_ok!(register(&ALICE, &second));
+        assert_eq!(OperatorMarketCount::<Test>::get(&ALICE), 2);
+
+        // 其他运营者的质押单独计算
+        let other = deploy_market(&BOB, 3);
+        pledge(&BOB, min);
+        assert_ok!(register(&BOB, &other));
+        assert_eq!(OperatorMarketCount::<Test>::get(&BOB), 1);
+    });
+}
+
+#[test]
+fn operator_bond_is_released_with_the_last_market() {
+    new_test_ext().execute_with(|| {
+        let min = MinMarketOperatorCollateral::get();
+        let first = deploy_market(&ALICE, 1);
+        let second = deploy_market(&ALICE, 2);
+        pledge(&ALICE, 2 * min);
+        assert_ok!(register(&ALICE, &first));
+        assert_ok!(register(&ALICE, &second));
+        let reserved = Balances::reserved_balance(&ALICE);
+
+        // 锁定期内仍可注销非最后一个市场，质押继续担保剩下的市场
+        assert_ok!(Markets::unregister_market(RuntimeOrigin::signed(ALICE), first.clone()));
+        assert!(!Markets::is_active_market(&first));
+        assert_eq!(OperatorMarketCount::<Test>::get(&ALICE), 1);
+        assert_eq!(Balances::reserved_balance(&ALICE), reserved);
+
+        // 最后一个市场需要等质押满足释放条件
+        assert_noop!(
+            Markets::unregister_market(RuntimeOrigin::signed(ALICE), second.clone()),
+            pallet_collaterals::Error::<Test>::CollateralNotReadyForRelease
+        );
+        System::set_block_number(OPERATOR_LOCK_BLOCKS + 2);
+        assert_ok!(Markets::unregister_market(RuntimeOrigin::signed(ALICE), second));
+        assert_eq!(OperatorMarketCount::<Test>::get(&ALICE), 0);
+        assert_eq!(Balances::reserved_balance(&ALICE), reserved - 2 * min);
+    });
+}
+
+#[test]
+fn v1_migration_backfills_market_indexes() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        let min = MinMarketOperatorCollateral::get();
+        let first = deploy_market(&ALICE, 1);
+        let second = deploy_market(&ALICE, 2);
+        let other = deploy_market(&BOB, 3);
+        pledge(&ALICE, 2 * min);
+        pledge(&BOB, min);
+        assert_ok!(register(&ALICE, &first));
+        assert_ok!(register(&ALICE, &second));
+        assert_ok!(register(&BOB, &other));
+        assert_ok!(Markets::set_market_status(
+            RuntimeOrigin::root(),
+            second.clone(),
+            crate::MarketStatus::Inactive
+        ));
+
+        // 模拟升级前的链上状态：索引均未写入
+        crate::MarketCount::<Test>::kill();
+        crate::ActiveMarkets::<Test>::kill();
+        let _ = OperatorMarketCount::<Test>::clear(u32::MAX, None);
+        StorageVersion::new(0).put::<Markets>();
+
+        crate::migrations::v1::InitMarketIndexes::<Test>::on_runtime_upgrade();
+
+        assert_eq!(crate::MarketCount::<Test>::get(), 3);
+        let active = crate::ActiveMarkets::<Test>::get();
+        assert_eq!(active.len(), 2);
+        assert!(active.contains(&first));
+        assert!(active.contains(&other));
+        assert!(!active.contains(&second));
+        assert_eq!(OperatorMarketCount::<Test>::get(&ALICE), 2);
+        assert_eq!(OperatorMarketCount::<Test>::get(&BOB), 1);
+        assert_eq!(Markets::on_chain_storage_version(), StorageVersion::new(1));
+    });
+}
+
+#[test]
+fn verification_accepts_only_an_explicit_true() {
+    new_test_ext().execute_with(|| {
+        pledge(&ALICE, 4 * MinMarketOperatorCollateral::get());
+        let with_output = |salt, output: Vec<u8>| {
+            deploy_market_with(&ALICE, salt, MarketBehaviour { is_market: Some(output), ..Default::default() })
+        };
+
+        // 合约明确声明不符合市场标准
+        let rejects = with_output(1, Ok::<bool, u8>(false).encode());
+        assert_noop!(try_register(&ALICE, &rejects), Error::<Test>::MarketRejectedStandard);
+
+        // 空返回
+        let empty = with_output(2, vec![]);
+        assert_noop!(try_register(&ALICE, &empty), Error::<Test>::MarketVerificationFailed);
+
+        // 多余字节、无效的 bool 和 LangError 都不是合法的返回值
+        let trailing = with_output(3, vec![0x00, 0x01, 0x00]);
+        assert_noop!(try_register(&ALICE, &trailing), Error::<Test>::MarketVerificationFailed);
+        let invalid_bool = with_output(4, vec![0x00, 0x02]);
+        assert_noop!(try_register(&ALICE, &invalid_bool), Error::<Test>::MarketVerificationFailed);
+        let lang_error = with_output(5, Err::<bool, u8>(1).encode());
+        assert_noop!(try_register(&ALICE, &lang_error), Error::<Test>::MarketVerificationFailed);
+
+        // 方法回退
+        let reverts = deploy_market_with(&ALICE, 6, MarketBehaviour { is_market: None, ..Default::default() });
+        assert_noop!(try_register(&ALICE, &reverts), Error::<Test>::MarketVerificationFailed);
+
+        let accepts = with_output(7, Ok::<bool, u8>(true).encode());
+        assert_ok!(try_register(&ALICE, &accepts));
+        assert!(Markets::is_active_market(&accepts));
+    });
+}
+
+/// 构造市场类型规范
+fn market_type_spec(name: &[u8], required_selectors: Vec<[u8; 4]>) -> crate::MarketTypeSpec {
+    crate::MarketTypeSpec {
+        name: frame_support::BoundedVec::truncate_from(name.to_vec()),
+        required_selectors: frame_support::BoundedVec::truncate_from(required_selectors),
+    }
+}
+
+#[test]
+fn fee_ratio_above_the_cap_is_rejected() {
+    new_test_ext().execute_with(|| {
+        pledge(&ALICE, 2 * MinMarketOperatorCollateral::get());
+        let with_fee = |salt, fee_ratio: u32| {
+            deploy_market_with(
+                &ALICE,
+                salt,
+                MarketBehaviour { fee_ratio: Some(Ok::<u32, u8>(fee_ratio).encode()), ..Default::default() },
+            )
+        };
+
+        let too_high = with_fee(1, MaxMarketFeeRatio::get() + 1);
+        assert_noop!(try_register(&ALICE, &too_high), Error::<Test>::MarketFeeTooHigh);
+
+        // 恰好等于上限可以注册
+        let at_cap = with_fee(2, MaxMarketFeeRatio::get());
+        assert_ok!(try_register(&ALICE, &at_cap));
+    });
+}
+
+#[test]
+fn unknown_market_type_is_rejected() {
+    new_test_ext().execute_with(|| {
+        pledge(&ALICE, MinMarketOperatorCollateral::get());
+        assert_ok!(Markets::set_market_type(RuntimeOrigin::root(), 1, Some(market_type_spec(b"orderbook", vec![]))));
+
+        let market = deploy_market_with(
+            &ALICE,
+            1,
+            MarketBehaviour { market_type: Some(Ok::<u8, u8>(2).encode()), ..Default::default() },
+        );
+        assert_noop!(try_register(&ALICE, &market), Error::<Test>::UnknownMarketType);
+    });
+}
+
+#[test]
+fn verification_that_exhausts_the_gas_limit_fails() {
+    new_test_ext().execute_with(|| {
+        pledge(&ALICE, MinMarketOperatorCollateral::get());
+        let market = deploy_looping_market(&ALICE, 1);
+
+        let error = register(&ALICE, &market).unwrap_err();
+        assert_eq!(error.error, Error::<Test>::VerificationOutOfGas.into());
+        // 按耗尽的 gas 上限收费
+        let actual_weight = error.post_info.actual_weight.expect("验证失败时应返回实际权重");
+        assert!(actual_weight.ref_time() >= MarketVerificationGasLimit::get().ref_time());
+        assert!(!Markets::is_active_market(&market));
+    });
+}
+
+#[test]
+fn market_type_registry_is_set_looked_up_and_removed() {
+    new_test_ext().execute_with(|| {
+        // get_fee_ratio: 0xdc93dab1，默认合约已实现
+        let spec = market_type_spec(b"orderbook", vec![[0xdc, 0x93, 0xda, 0xb1]]);
+        assert_noop!(
+            Markets::set_market_type(RuntimeOrigin::signed(ALICE), 1, Some(spec.clone())),
+            sp_runtime::DispatchError::BadOrigin
+        );
+        assert_ok!(Markets::set_market_type(RuntimeOrigin::root(), 1, Some(spec.clone())));
+        assert_eq!(Markets::market_type_registry(1), Some(spec.clone()));
+        assert!(MarketTypeRegistry::<Test>::get(2).is_none());
+        System::assert_last_event(Event::MarketTypeSet { market_type: 1, spec }.into());
+
+        // 注册时按合约返回的类型查找规范并检查必需方法
+        pledge(&ALICE, 2 * MinMarketOperatorCollateral::get());
+        let orderbook = MarketBehaviour { market_type: Some(Ok::<u8, u8>(1).encode()), ..Default::default() };
+        let market = deploy_market_with(&ALICE, 1, orderbook.clone());
+        assert_ok!(try_register(&ALICE, &market));
+        System::assert_last_event(
+            Event::MarketRegistered {
+                creator: ALICE,
+                contract_address: market,
+                asset_type: crate::MarketAssetType::DataAsset,
+                market_type: Some(1),
+            }
+            .into(),
+        );
+
+        // 规范更新后，缺少要求的方法的合约不能注册
+        assert_ok!(Markets::set_market_type(
+            RuntimeOrigin::root(),
+            1,
+            Some(market_type_spec(b"orderbook", vec![[0xde, 0xad, 0xbe, 0xef]]))
+        ));
+        let missing = deploy_market_with(&ALICE, 2, orderbook);
+        assert_noop!(try_register(&ALICE, &missing), Error::<Test>::MissingRequiredMethod);
+
+        assert_ok!(Markets::set_market_type(RuntimeOrigin::root(), 1, None));
+        assert!(Markets::market_type_registry(1).is_none());
+        System::assert_last_event(Event::MarketTypeRemoved { market_type: 1 }.into());
+    });
+}
+
+#[test]
+fn pledge_topped_up_to_the_minimum_restarts_activation_delay() {
+    new_test_ext().execute_with(|| {
+        let min = MinGovernancePledge::get();
+        let delay = PledgeActivationDelay::get();
+        assert_ok!(Collaterals::internal_pledge(&ALICE, CollateralRole::GovernancePledge, min));
+        assert!(!Collaterals::is_pledge_active(&ALICE, CollateralRole::GovernancePledge));
+        System::set_block_number(1 + delay);
+        assert!(Collaterals::is_pledge_active(&ALICE, CollateralRole::GovernancePledge));
+
+        // 轻度违规罚没后低于最小金额，质押不再生效
+        assert_ok!(Collaterals::slash_for_violation(
+            &ALICE,
+            CollateralRole::GovernancePledge,
+            pallet_collaterals::ViolationSeverity::Light
+        ));
+        assert!(!Collaterals::is_pledge_active(&ALICE, CollateralRole::GovernancePledge));
+
+        // 补足后从补足的区块重新计算生效期
+        let topped_up_at = 5 * delay;
+        System::set_block_number(topped_up_at);
+        assert_ok!(Collaterals::internal_pledge(&ALICE, CollateralRole::GovernancePledge, min));
+        assert_eq!(Collaterals::collateral_data(&ALICE, CollateralRole::GovernancePledge).start_block, topped_up_at);
+        assert!(!Collaterals::is_pledge_active(&ALICE, CollateralRole::GovernancePledge));
+        System::set_block_number(topped_up_at + delay);
+        assert!(Collaterals::is_pledge_active(&ALICE, CollateralRole::GovernancePledge));
+
+        // 仍不低于最小金额的追加质押不影响起始区块
+        assert_ok!(Collaterals::internal_pledge(&ALICE, CollateralRole::GovernancePledge, min));
+        assert_eq!(Collaterals::collateral_data(&ALICE, CollateralRole::GovernancePledge).start_block, topped_up_at);
+    });
+}
@@ -49,6 +49,10 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 	/// Proof: `Timestamp::Now` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x11d2df4e979aa105cf552e9544ebd2b500000000` (r:1 w:0)
 	/// Proof: UNKNOWN KEY `0x11d2df4e979aa105cf552e9544ebd2b500000000` (r:1 w:0)
+	/// Storage: `Markets::MarketCount` (r:1 w:1)
+	/// Proof: `Markets::MarketCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Markets::ActiveMarkets` (r:1 w:1)
+	/// Proof: `Markets::ActiveMarkets` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	fn register_market() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1584`
@@ -56,11 +60,15 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 167_674_000 picoseconds.
 		Weight::from_parts(188_638_000, 0)
 			.saturating_add(Weight::from_parts(0, 134573))
-			.saturating_add(T::DbWeight::get().reads(7))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(9))
+			.saturating_add(T::DbWeight::get().writes(4))
 	}
 	/// Storage: `Markets::RegisteredMarkets` (r:1 w:1)
 	/// Proof: `Markets::RegisteredMarkets` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Markets::MarketCount` (r:1 w:1)
+	/// Proof: `Markets::MarketCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Markets::ActiveMarkets` (r:1 w:1)
+	/// Proof: `Markets::ActiveMarkets` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
 	fn unregister_market() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `147`
@@ -68,7 +76,32 @@ impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
 		// Minimum execution time: 9_294_000 picoseconds.
 		Weight::from_parts(9_673_000, 0)
 			.saturating_add(Weight::from_parts(0, 3579))
-			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `Markets::MarketTypeRegistry` (r:0 w:1)
+	/// Proof: `Markets::MarketTypeRegistry` (`max_values`: None, `max_size`: Some(117), added: 2592, mode: `MaxEncodedLen`)
+	fn set_market_type() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_803_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
+	/// Storage: `Markets::RegisteredMarkets` (r:1 w:1)
+	/// Proof: `Markets::RegisteredMarkets` (`max_values`: None, `max_size`: Some(114), added: 2589, mode: `MaxEncodedLen`)
+	/// Storage: `Markets::ActiveMarkets` (r:1 w:1)
+	/// Proof: `Markets::ActiveMarkets` (`max_values`: Some(1), `max_size`: Some(32002), added: 32497, mode: `MaxEncodedLen`)
+	fn set_market_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `32181`
+		//  Estimated: `33487`
+		// Minimum execution time: 21_936_000 picoseconds.
+		Weight::from_parts(23_410_000, 0)
+			.saturating_add(Weight::from_parts(0, 33487))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
 }
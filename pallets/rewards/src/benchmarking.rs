@@ -2,8 +2,7 @@
 
 use super::*;
 use crate::Pallet as Rewards;
-use frame_benchmarking::{benchmarks, whitelisted_caller, account};
-use frame_system::RawOrigin;
+use frame_benchmarking::benchmarks;
 
 use frame_support::{
 	traits::{Currency, Get, Hooks},
@@ -12,24 +11,17 @@ use frame_support::{
 use frame_system::pallet_prelude::BlockNumberFor;
 
 benchmarks! {
-	get_current_reward {
-		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller))
-	verify {
-		assert!(!frame_system::Pallet::<T>::events().is_empty());
-	}
-
-	on_finalize_initial {
+	on_initialize_initial {
 		let block_number: BlockNumberFor<T> = 1u32.into();
 		TotalTokensMined::<T>::put(BalanceOf::<T>::zero());
 	}: {
-		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_finalize(block_number);
+		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_initialize(block_number);
 	}
 	verify {
 		assert_eq!(TotalTokensMined::<T>::get(), T::InitialReward::get());
 	}
 
-	on_finalize_adjustment {
+	on_initialize_adjustment {
 		let block_number: BlockNumberFor<T> = 100u32.into();
 		let threshold = T::RewardAdjustmentThreshold::get();
 		let initial_reward = T::InitialReward::get();
@@ -37,18 +29,18 @@ benchmarks! {
 		let near_threshold = threshold.saturating_sub(initial_reward);
 		TotalTokensMined::<T>::put(near_threshold);
 	}: {
-		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_finalize(block_number);
+		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_initialize(block_number);
 	}
 	verify {
 		assert!(TotalTokensMined::<T>::get() >= threshold);
 	}
 
-	on_finalize_max_supply {
+	on_initialize_max_supply {
 		let block_number: BlockNumberFor<T> = 999u32.into();
 		let max_supply = T::MaxSupply::get();
 		TotalTokensMined::<T>::put(max_supply);
 	}: {
-		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_finalize(block_number);
+		<Rewards<T> as Hooks<BlockNumberFor<T>>>::on_initialize(block_number);
 	}
 	verify {
 		assert_eq!(TotalTokensMined::<T>::get(), max_supply);
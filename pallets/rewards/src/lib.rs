@@ -25,16 +25,17 @@ pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use frame_support::traits::Currency;
+	use frame_support::traits::{Currency, Imbalance};
 	use frame_support::sp_runtime::Saturating;
+	#[cfg(any(feature = "try-runtime", test))]
+	use frame_support::sp_runtime::TryRuntimeError;
 
 	pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
 
 	pub trait WeightInfo {
-		fn get_current_reward() -> Weight;
-		fn on_finalize_initial() -> Weight;
-		fn on_finalize_adjustment() -> Weight;
-		fn on_finalize_max_supply() -> Weight;
+		fn on_initialize_initial() -> Weight;
+		fn on_initialize_adjustment() -> Weight;
+		fn on_initialize_max_supply() -> Weight;
 	}
 
 	#[pallet::pallet]
@@ -59,6 +60,13 @@ pub mod pallet {
         type AdjustedReward: Get<BalanceOf<Self>>;
 		#[pallet::constant]
     	type MaxSupply: Get<BalanceOf<Self>>;
+		/// 阶梯奖励的判断依据：
+		/// - false：按 TotalTokensMined，即本模块累计铸造的区块奖励，不受罚没、销毁影响；
+		/// - true：按 Currency::total_issuance()，即链上实际流通总量，包含创世分配，
+		///   罚没销毁后会减少，可能让奖励回到调整前的档位。
+		/// 两种口径下 MaxSupply 始终按 TotalTokensMined 计算。
+		#[pallet::constant]
+		type UseTotalIssuance: Get<bool>;
 
 		type WeightInfo: WeightInfo;
 	}
@@ -66,6 +74,11 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type TotalTokensMined<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
 
+	/// 每个区块生产者累计获得的区块奖励（不受转账影响）
+	#[pallet::storage]
+	#[pallet::getter(fn validator_reward_earned)]
+	pub type ValidatorRewardEarned<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
 	// /// 初始区块奖励：5 DAT
 	// #[pallet::type_value]
 	// pub fn InitialReward<T: Config>() -> BalanceOf<T> {
@@ -91,8 +104,8 @@ pub mod pallet {
 		RewardPaid{who: T::AccountId, amount: BalanceOf<T>, block_number: BlockNumberFor<T>},
 		// 新奖励金额，调整发生的区块号
 		RewardAdjusted{new_amount: BalanceOf<T>, block_number: BlockNumberFor<T>},
-		// 当前奖励查询结果
-        CurrentRewardQueried{who: T::AccountId, amount: BalanceOf<T>},
+		// 奖励未发放：接收者余额加奖励仍低于ED（会产生粉尘账户）或累计总量溢出
+		RewardSkipped{who: T::AccountId, amount: BalanceOf<T>, block_number: BlockNumberFor<T>},
 	}
 
 	#[pallet::error]
@@ -103,19 +116,20 @@ pub mod pallet {
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-		/// 在每个区块结束时发放奖励（每次都判断当前奖励金额）
-		fn on_finalize(block_number: BlockNumberFor<T>) {
+		/// 在每个区块开始时发放奖励（每次都判断当前奖励金额），返回实际消耗的权重
+		fn on_initialize(block_number: BlockNumberFor<T>) -> Weight {
 			// 获取当前已挖出的代币总量
 			let current_total = TotalTokensMined::<T>::get();
 			let max_supply = T::MaxSupply::get();
 			
 			// 检查是否已超最大供应量
 			if current_total >= max_supply {
-				return;
+				return T::WeightInfo::on_initialize_max_supply();
 			}
 
 			// 计算当前区块应发放的奖励（每次都判断：未达阈值发5，已达阈值发1）
-			let mut reward_amount = Self::calculate_current_reward(current_total);
+			let basis = Self::reward_basis();
+			let mut reward_amount = Self::calculate_current_reward(basis);
 
 			// 防止最后一笔奖励超出5亿上限
 			if current_total.saturating_add(reward_amount) > max_supply {
@@ -124,66 +138,105 @@ pub mod pallet {
 
 			// 发放奖励给接收者
 			let receiver = T::RewardReceiver::get();
-			// 发放奖励给接收者，忽略返回的Imbalance
-			let _ = T::Currency::deposit_creating(&receiver, reward_amount);
-
-			// 更新总量
-			let new_total = current_total.checked_add(&reward_amount)
-				.expect("奖励金额不会导致溢出"); // 实际场景可根据需求处理溢出
-			// 更新已挖出的代币总量
+			let weight = T::WeightInfo::on_initialize_initial().max(T::WeightInfo::on_initialize_adjustment());
+
+			// 累计总量溢出，或接收者余额加奖励仍低于ED（deposit_creating 不会创建粉尘账户），跳过本次发放
+			let new_total = current_total.checked_add(&reward_amount);
+			let would_be_dust = !reward_amount.is_zero()
+				&& T::Currency::total_balance(&receiver).saturating_add(reward_amount) < T::Currency::minimum_balance();
+			let Some(new_total) = new_total.filter(|_| !would_be_dust) else {
+				Self::deposit_event(Event::RewardSkipped { who: receiver, amount: reward_amount, block_number });
+				return weight;
+			};
+
+			// 按实际铸造的金额计入总量
+			let minted = T::Currency::deposit_creating(&receiver, reward_amount).peek();
+			let new_total = new_total.saturating_sub(reward_amount.saturating_sub(minted));
+
+			// 更新已挖出的代币总量及接收者的累计收益
 			TotalTokensMined::<T>::put(new_total);
+			ValidatorRewardEarned::<T>::mutate(&receiver, |earned| *earned = earned.saturating_add(minted));
 
 			// 真给了区块奖励才发事件
-			if reward_amount > Zero::zero() {
+			if minted > Zero::zero() {
 				Self::deposit_event(Event::RewardPaid {
 					who: receiver.clone(),
-					amount: reward_amount,
+					amount: minted,
 					block_number,
 				});
 			}
 
 			// 若本次发放后首次达到阈值，触发奖励调整事件
-			if current_total < T::RewardAdjustmentThreshold::get() 
-				&& new_total >= T::RewardAdjustmentThreshold::get() 
+			if basis < T::RewardAdjustmentThreshold::get()
+				&& Self::reward_basis() >= T::RewardAdjustmentThreshold::get()
 			{
 				Self::deposit_event(Event::RewardAdjusted {
 					new_amount: T::AdjustedReward::get(),
 					block_number,
 				});
 			}
+
+			weight
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn try_state(_n: BlockNumberFor<T>) -> Result<(), TryRuntimeError> {
+			Self::do_try_state()
 		}
 	}
 
+	// 当前区块奖励通过 RewardsApi::current_reward 查询，不再作为收费的交易
 	#[pallet::call]
+	impl<T: Config> Pallet<T> {}
+
 	impl<T: Config> Pallet<T> {
-		/// 获取当前区块奖励的金额
-		#[pallet::call_index(0)]
-		#[pallet::weight(T::WeightInfo::get_current_reward())]
-		// #[pallet::weight(T::WeightInfo::get_current_reward())]
-		pub fn get_current_reward(origin: OriginFor<T>) -> DispatchResult {
-			let who = ensure_signed(origin)?;
-			
-			let current_total = TotalTokensMined::<T>::get();
-			let current_reward = Self::calculate_current_reward(current_total);
-			
-			// 返回当前区块的奖励
-			Self::deposit_event(Event::CurrentRewardQueried {
-                who,
-                amount: current_reward,
-            });
-			Ok(())
+		/// 当前区块奖励的金额（只读查询）
+		pub fn current_reward() -> BalanceOf<T> {
+			Self::calculate_current_reward(Self::reward_basis())
+		}
+
+		/// 阶梯奖励的判断依据，口径由 UseTotalIssuance 决定
+		pub fn reward_basis() -> BalanceOf<T> {
+			if T::UseTotalIssuance::get() {
+				T::Currency::total_issuance()
+			} else {
+				TotalTokensMined::<T>::get()
+			}
 		}
-	}
 
-	impl<T: Config> Pallet<T> {
 		/// 每次发放奖励前计算当前应发金额
-		/// 若累计已挖出的代币 < 2.5亿，发5个；否则发1个
-		fn calculate_current_reward(current_total: BalanceOf<T>) -> BalanceOf<T> {
-			if current_total < T::RewardAdjustmentThreshold::get() {
+		/// 若判断依据（累计已挖出或总发行量）< 2.5亿，发5个；否则发1个
+		fn calculate_current_reward(basis: BalanceOf<T>) -> BalanceOf<T> {
+			if basis < T::RewardAdjustmentThreshold::get() {
 				T::InitialReward::get()
 			} else {
 				T::AdjustedReward::get()
 			}
 		}
+
+		/// 核对 TotalTokensMined 与链上总发行量
+		/// 累计铸造量不超过 MaxSupply，各区块生产者累计收益之和不超过累计铸造量
+		/// （ValidatorRewardEarned 引入之前挖出的奖励没有按生产者记录，两者不一定相等）；
+		/// 总发行量低于累计铸造量说明已有奖励被罚没销毁，两种口径出现分歧，只记录警告
+		#[cfg(any(feature = "try-runtime", test))]
+		pub(crate) fn do_try_state() -> Result<(), TryRuntimeError> {
+			let mined = TotalTokensMined::<T>::get();
+			ensure!(mined <= T::MaxSupply::get(), "TotalTokensMined exceeds MaxSupply");
+
+			let earned = ValidatorRewardEarned::<T>::iter_values()
+				.fold(BalanceOf::<T>::zero(), |acc, amount| acc.saturating_add(amount));
+			ensure!(earned <= mined, "ValidatorRewardEarned exceeds TotalTokensMined");
+
+			let issuance = T::Currency::total_issuance();
+			if issuance < mined {
+				log::warn!(
+					target: "runtime::rewards",
+					"total issuance {:?} is below TotalTokensMined {:?}, reward basis depends on UseTotalIssuance",
+					issuance,
+					mined,
+				);
+			}
+			Ok(())
+		}
 	}
 }
@@ -2,7 +2,7 @@ use crate as pallet_rewards;
 use frame_support::{
     parameter_types,
     derive_impl,
-    traits::{ConstU128, ConstU32},
+    traits::ConstU32,
 };
 use sp_runtime::{
     BuildStorage,
@@ -31,7 +31,7 @@ impl pallet_balances::Config for Test {
     type Balance = u128;
     type RuntimeEvent = RuntimeEvent;
     type DustRemoval = ();
-    type ExistentialDeposit = ConstU128<1>;
+    type ExistentialDeposit = ExistentialDeposit;
     type AccountStore = System;
     type WeightInfo = ();
     type FreezeIdentifier = ();
@@ -42,11 +42,15 @@ impl pallet_balances::Config for Test {
 }
 
 parameter_types! {
+    // 测试中可调高，模拟奖励低于ED的情况
+    pub static ExistentialDeposit: u128 = 1;
     pub const RewardReceiverAccount: u64 = 123;
     pub const InitialReward: u128 = 5;
     pub const RewardAdjustmentThreshold: u128 = 250_000_000;
     pub const AdjustedReward: u128 = 1;
     pub const MaxSupply: u128 = 500_000_000;
+    // 默认按累计铸造量判断奖励档位，测试中可切换为总发行量
+    pub static UseTotalIssuance: bool = false;
 }
 
 impl pallet_rewards::Config for Test {
@@ -57,6 +61,7 @@ impl pallet_rewards::Config for Test {
     type RewardAdjustmentThreshold = RewardAdjustmentThreshold;
     type AdjustedReward = AdjustedReward;
     type MaxSupply = MaxSupply;
+    type UseTotalIssuance = UseTotalIssuance;
     // 使用 lib.rs 中为 () 提供的默认 WeightInfo 实现
     type WeightInfo = crate::weights::WeightInfo<Test>;
 }
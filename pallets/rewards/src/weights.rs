@@ -35,48 +35,41 @@ use core::marker::PhantomData;
 /// Weight functions for `pallet_rewards`.
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> crate::WeightInfo for WeightInfo<T> {
-	/// Storage: `Rewards::TotalTokensMined` (r:1 w:0)
-	/// Proof: `Rewards::TotalTokensMined` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	fn get_current_reward() -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `76`
-		//  Estimated: `1501`
-		// Minimum execution time: 5_137_000 picoseconds.
-		Weight::from_parts(6_646_000, 0)
-			.saturating_add(Weight::from_parts(0, 1501))
-			.saturating_add(T::DbWeight::get().reads(1))
-	}
 	/// Storage: `Rewards::TotalTokensMined` (r:1 w:1)
 	/// Proof: `Rewards::TotalTokensMined` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn on_finalize_initial() -> Weight {
+	/// Storage: `Rewards::ValidatorRewardEarned` (r:1 w:1)
+	/// Proof: `Rewards::ValidatorRewardEarned` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn on_initialize_initial() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `115`
 		//  Estimated: `3593`
 		// Minimum execution time: 17_542_000 picoseconds.
 		Weight::from_parts(19_089_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Rewards::TotalTokensMined` (r:1 w:1)
 	/// Proof: `Rewards::TotalTokensMined` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
 	/// Storage: `System::Account` (r:1 w:1)
 	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
-	fn on_finalize_adjustment() -> Weight {
+	/// Storage: `Rewards::ValidatorRewardEarned` (r:1 w:1)
+	/// Proof: `Rewards::ValidatorRewardEarned` (`max_values`: None, `max_size`: Some(80), added: 2555, mode: `MaxEncodedLen`)
+	fn on_initialize_adjustment() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `115`
 		//  Estimated: `3593`
 		// Minimum execution time: 18_514_000 picoseconds.
 		Weight::from_parts(20_025_000, 0)
 			.saturating_add(Weight::from_parts(0, 3593))
-			.saturating_add(T::DbWeight::get().reads(2))
-			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
 	}
 	/// Storage: `Rewards::TotalTokensMined` (r:1 w:0)
 	/// Proof: `Rewards::TotalTokensMined` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
-	fn on_finalize_max_supply() -> Weight {
+	fn on_initialize_max_supply() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `115`
 		//  Estimated: `1501`
@@ -0,0 +1,66 @@
+//! AccountId (32 字节) 与 H160 地址之间的转换
+//!
+//! 映射规则与以太坊兼容链的常见做法一致：
+//! - 由 H160 派生的账户：前 20 字节为 H160，后 12 字节固定为 0xEE。
+//!   这类账户与 H160 可以无损互转。
+//! - 其他原生账户：H160 取 keccak_256(AccountId) 的后 20 字节。
+//!   这个方向不可逆，h160_to_account 得到的是该 H160 的派生账户，而不是原账户。
+//!
+//! 链上（pallet-dataassets）和链下（节点侧的资产管理）都必须使用这里的函数，
+//! 保证同一个所有者在两侧得到相同的地址。
+
+use sp_core::{hashing::keccak_256, H160};
+
+/// H160 派生账户的后缀填充字节
+pub const ETH_DERIVED_SUFFIX: [u8; 12] = [0xEE; 12];
+
+/// 账户是否由 H160 派生（后 12 字节为 ETH_DERIVED_SUFFIX）
+pub fn is_h160_derived(account: &[u8; 32]) -> bool {
+    account[20..] == ETH_DERIVED_SUFFIX
+}
+
+/// 将 32 字节账户映射为 H160
+pub fn account_to_h160<AccountId: AsRef<[u8; 32]>>(account: &AccountId) -> H160 {
+    let bytes = account.as_ref();
+    if is_h160_derived(bytes) {
+        H160::from_slice(&bytes[..20])
+    } else {
+        H160::from_slice(&keccak_256(bytes)[12..])
+    }
+}
+
+/// 将 H160 映射为其派生账户（H160 ++ 12 个 0xEE）
+pub fn h160_to_account<AccountId: From<[u8; 32]>>(address: &H160) -> AccountId {
+    let mut bytes = [0u8; 32];
+    bytes[..20].copy_from_slice(address.as_bytes());
+    bytes[20..].copy_from_slice(&ETH_DERIVED_SUFFIX);
+    AccountId::from(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sp_runtime::AccountId32;
+
+    #[test]
+    fn h160_round_trips_through_derived_account() {
+        let address = H160::repeat_byte(0x42);
+        let account: AccountId32 = h160_to_account(&address);
+
+        assert!(is_h160_derived(account.as_ref()));
+        assert_eq!(account_to_h160(&account), address);
+        assert_eq!(h160_to_account::<AccountId32>(&account_to_h160(&account)), account);
+    }
+
+    #[test]
+    fn native_account_maps_to_hashed_address() {
+        let account = AccountId32::new([7u8; 32]);
+        let address = account_to_h160(&account);
+
+        assert!(!is_h160_derived(account.as_ref()));
+        assert_eq!(address, H160::from_slice(&keccak_256(account.as_ref())[12..]));
+        // 映射结果稳定，原生账户不会得到派生账户的地址
+        assert_eq!(account_to_h160(&account), address);
+        assert_ne!(h160_to_account::<AccountId32>(&address), account);
+    }
+}
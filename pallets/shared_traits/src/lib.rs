@@ -1,7 +1,11 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod address;
+pub use address::{account_to_h160, h160_to_account};
+
 use scale_info::TypeInfo;
 use sp_core::H256;
+use sp_runtime::Perbill;
 
 #[derive(Debug, PartialEq, Eq, codec::Encode, codec::Decode)]
 pub enum AssetQueryError {
@@ -20,25 +24,132 @@ pub struct EncryptionInfo {
 
 use sp_std::prelude::*;
 
+/// 激励发放失败的原因，由激励模块从内部错误映射而来
+#[derive(Debug, Clone, Copy, PartialEq, Eq, codec::Encode, codec::Decode, TypeInfo)]
+pub enum IncentiveError {
+    /// 激励池可用余额不足
+    InsufficientPool,
+    /// 该奖励已经领取过
+    AlreadyClaimed,
+    /// 资产不存在
+    AssetNotFound,
+    /// 未满足奖励条件
+    ConditionNotMet,
+    /// 奖励转账失败（如接收账户无法开户）
+    TransferFailed,
+    /// 订单所在的市场未注册或未启用
+    UnregisteredMarket,
+    /// 可用余额不足以锁定提案保证金
+    InsufficientBond,
+}
+
 /// 激励处理器Trait - dataassets模块调用
 pub trait IncentiveHandler<AccountId, AssetId, Balance> {
     /// 分发首次创建奖励
-    fn distribute_first_create_reward(recipient: &AccountId, asset_id: &AssetId) -> Result<(), &'static str>;
+    fn distribute_first_create_reward(recipient: &AccountId, asset_id: &AssetId) -> Result<(), IncentiveError>;
     
     /// 登记资产交易（用于优质数据判定）
     fn register_asset_trade(asset_id: &AssetId);
     
-    /// 分发流动性奖励
-    fn distribute_liquidity_reward(recipient: &AccountId, order_amount: Balance) -> Result<(), &'static str>;
+    /// 分发流动性奖励，订单所在的市场必须已注册且处于 Active 状态
+    fn distribute_market_liquidity_reward(
+        recipient: &AccountId,
+        market_contract: &AccountId,
+        order_amount: Balance,
+    ) -> Result<(), IncentiveError>;
     
-    /// 分发提案通过奖励
-    fn distribute_proposal_reward(recipient: &AccountId) -> Result<(), &'static str>;
+    /// 提交提案时锁定提案保证金
+    fn reserve_proposal_bond(proposer: &AccountId) -> Result<(), IncentiveError>;
+
+    /// 结算提案：通过时退还保证金并发放提案奖励；
+    /// 否决且支持率 `support` 低于阈值时罚没保证金，否则只退还保证金
+    fn distribute_proposal_outcome(proposer: &AccountId, passed: bool, support: Perbill) -> Result<(), IncentiveError>;
+
+    /// 奖励代理发行权证的市场运营者（资产所有者自己发行时不调用）
+    fn reward_issuance_operator(market: &AccountId) -> Result<(), IncentiveError>;
 }
 
 /// 数据资产提供者Trait - incentive模块调用
 pub trait DataAssetProvider<AccountId, AssetId> {
     /// 获取资产信息，主要向incentive模块提供查询资产是否存在的功能
     fn get_asset_owner(asset_id: &AssetId) -> Result<AccountId, AssetQueryError>;
+
+    /// 资产记录中的所有者，不检查账户是否存在（用于账户被回收时托管奖励）
+    fn get_recorded_owner(asset_id: &AssetId) -> Option<AccountId>;
+
+    /// 获取资产原始数据的哈希（加密数据分片的 Merkle 根），用于校验存储证明
+    fn get_raw_data_hash(asset_id: &AssetId) -> Result<H256, AssetQueryError>;
+
+    /// 获取资产数据的大小（字节），用于计算存储证明的分片数
+    fn get_data_size(asset_id: &AssetId) -> Result<u64, AssetQueryError>;
+}
+
+/// 市场查询Trait - 由 markets 模块实现，供incentive模块校验订单所在的市场
+pub trait MarketProvider<AccountId> {
+    /// 市场合约是否已注册且处于 Active 状态
+    fn is_active_market(market: &AccountId) -> bool;
+}
+
+/// 质押生效查询Trait - 由 collaterals 模块实现，供incentive模块判断治理质押是否已过生效期
+pub trait PledgeStatusProvider<AccountId> {
+    /// `who` 的治理质押是否已生效（质押满 PledgeActivationDelay 个区块）
+    fn is_governance_pledge_active(who: &AccountId) -> bool;
+}
+
+/// 未接入质押模块时使用，所有投票权重都计入
+impl<AccountId> PledgeStatusProvider<AccountId> for () {
+    fn is_governance_pledge_active(_who: &AccountId) -> bool {
+        true
+    }
+}
+
+/// 质押角色的分类，供不依赖 collaterals 的模块判断被罚没的是哪类质押
+pub trait CollateralRoleKind {
+    /// 是否为数据创建者的质押
+    fn is_data_creator(&self) -> bool;
+}
+
+/// 质押罚没通知Trait - collaterals 模块罚没后调用，由质押所属的业务模块实现
+pub trait SlashNotificationHandler<AccountId, Role, Balance> {
+    /// `who` 在 `role` 角色下的质押被罚没了 `amount`（实际罚没金额）
+    fn on_collateral_slashed(who: &AccountId, role: Role, amount: Balance);
+}
+
+/// 无需通知时使用
+impl<AccountId, Role, Balance> SlashNotificationHandler<AccountId, Role, Balance> for () {
+    fn on_collateral_slashed(_who: &AccountId, _role: Role, _amount: Balance) {}
+}
+
+/// 存储证明查询Trait - 由 storage_ipfs 模块实现，供资产健康度查询使用
+pub trait StorageProofProvider<AssetId, BlockNumber> {
+    /// 为资产提交过存储证明的服务商数量
+    fn replica_count(asset_id: &AssetId) -> u32;
+
+    /// 资产最近一次存储证明所在区块
+    fn last_proof_block(asset_id: &AssetId) -> Option<BlockNumber>;
+}
+
+/// 未接入存储模块时使用
+impl<AssetId, BlockNumber> StorageProofProvider<AssetId, BlockNumber> for () {
+    fn replica_count(_asset_id: &AssetId) -> u32 {
+        0
+    }
+
+    fn last_proof_block(_asset_id: &AssetId) -> Option<BlockNumber> {
+        None
+    }
+}
+
+/// 质押管理Trait - 由 collaterals 模块实现，供其他模块代用户质押/解押
+pub trait CollateralManager<AccountId, Role, Balance> {
+    /// 为 `who` 以 `role` 角色质押 `amount`
+    fn internal_pledge(who: &AccountId, role: Role, amount: Balance) -> frame_support::dispatch::DispatchResult;
+
+    /// 解除 `who` 在 `role` 角色下满足释放条件的质押
+    fn internal_unbond(who: &AccountId, role: Role) -> frame_support::dispatch::DispatchResult;
+
+    /// `who` 当前以 `role` 角色质押的金额
+    fn pledged_amount(who: &AccountId, role: Role) -> Balance;
 }
 
 pub trait DataAssetInternal<AccountId, Balance> {
@@ -5,34 +5,70 @@
 
 pub use pallet::*;
 
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, traits::Randomness};
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
     use sp_core::H256;
+    use sp_runtime::traits::{Saturating, Zero};
     
     // 引入依赖模块的类型
-    use pallet_collaterals::{CollateralRole, Pallet as CollateralPallet};
-    use pallet_shared_traits::{DataAssetInternal, EncryptionInfo};
+    use pallet_collaterals::CollateralRole;
+    use pallet_shared_traits::{CollateralManager, DataAssetInternal, DataAssetProvider, EncryptionInfo};
 
     #[pallet::pallet]
     pub struct Pallet<T>(_);
 
     #[pallet::config]
-    pub trait Config: frame_system::Config + pallet_collaterals::Config + pallet_dataassets::Config {
+    pub trait Config: frame_system::Config + pallet_dataassets::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
         
         /// 资产处理接口，用于调用 pallet-dataassets
         type AssetHandler: DataAssetInternal<Self::AccountId, BalanceOf<Self>>;
 
+        /// 资产查询接口，用于获取资产的 raw_data_hash（分片 Merkle 根）和数据大小以校验存储证明
+        type AssetProvider: DataAssetProvider<Self::AccountId, [u8; 32]>;
+
+        /// 链上随机数来源，每个区块记录一次种子，用于抽取存储证明要提交的分片
+        type Randomness: Randomness<H256, BlockNumberFor<Self>>;
+
+        /// 存储证明的分片大小（字节），须与链下计算 raw_data_hash 时的分片大小一致
+        #[pallet::constant]
+        type ChunkSize: Get<u32>;
+
+        /// 质押接口，用于代服务商质押/解押（通常为 pallet-collaterals）
+        type CollateralManager: CollateralManager<Self::AccountId, CollateralRole, BalanceOf<Self>>;
+
         /// 存储证明的有效周期（以区块数为单位）
         #[pallet::constant]
         type ProofPeriod: Get<BlockNumberFor<Self>>;
+
+        /// 单个资产自定义证明周期的下限（以区块数为单位）
+        #[pallet::constant]
+        type MinProofPeriod: Get<BlockNumberFor<Self>>;
+
+        /// 单个资产自定义证明周期的上限（以区块数为单位）
+        #[pallet::constant]
+        type MaxProofPeriod: Get<BlockNumberFor<Self>>;
+
+        /// 服务商申请退出后的冷却期（以区块数为单位），期间仍需为已存储的资产提交证明
+        #[pallet::constant]
+        type ProviderExitCooldown: Get<BlockNumberFor<Self>>;
+
+        /// 每个资产至少需要的存储副本数，服务商退出后不能低于该值
+        #[pallet::constant]
+        type MinReplicationFactor: Get<u32>;
     }
 
-    type BalanceOf<T> = <<T as pallet_collaterals::Config>::Currency as frame_support::traits::Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    type BalanceOf<T> = pallet_dataassets::BalanceOf<T>;
 
     /// 存储提供者信息
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -53,6 +89,16 @@ pub mod pallet {
         pub is_weak: bool,                           // 是否处于余额不足的虚弱状态
     }
 
+    /// 服务商状态汇总，用于退出、惩罚前的检查
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub struct ProviderStatus<BlockNumber, Balance> {
+        pub info: ProviderInfo<BlockNumber, Balance>,
+        /// 当前存储（提交过证明）的资产数量
+        pub asset_count: u32,
+        /// 正在退出时，可以完成退出的区块
+        pub exit_at: Option<BlockNumber>,
+    }
+
     /// 存储证明记录
     #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct StorageProof<BlockNumber> {
@@ -91,11 +137,71 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// 每个服务商存储的资产数量，在首次为某资产提交证明、移除证明时更新
+    #[pallet::storage]
+    #[pallet::getter(fn provider_asset_count)]
+    pub type ProviderAssetCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
+        ValueQuery,
+    >;
+
+    /// 服务商存储（提交过证明）的资产索引：(provider, asset_id)，退出时只遍历该服务商自己的资产
+    #[pallet::storage]
+    pub type ProviderAssets<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat, T::AccountId, // provider
+        Blake2_128Concat, [u8; 32], // asset_id
+        (),
+        OptionQuery,
+    >;
+
+    /// 正在退出的服务商：provider -> 可以完成退出的区块
+    #[pallet::storage]
+    #[pallet::getter(fn provider_exits)]
+    pub type ProviderExits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// 资产自定义的证明周期，未设置时使用全局的 ProofPeriod
+    #[pallet::storage]
+    #[pallet::getter(fn asset_proof_period)]
+    pub type AssetProofPeriod<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        [u8; 32], // asset_id
+        BlockNumberFor<T>,
+        OptionQuery,
+    >;
+
+    /// 区块 -> 该区块开始时记录的挑战随机种子，只保留最近一个最长证明周期内的种子
+    #[pallet::storage]
+    #[pallet::getter(fn challenge_seed)]
+    pub type ChallengeSeeds<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        H256,
+        OptionQuery,
+    >;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ProviderRegistered { who: T::AccountId, endpoint: Vec<u8> },
-        ProofSubmitted { asset_id: [u8; 32], provider: T::AccountId },
+        ProofSubmitted { asset_id: [u8; 32], provider: T::AccountId, chunk_index: u32 },
+        /// 服务商申请退出，冷却期结束后才能取回质押
+        ProviderExitRequested { who: T::AccountId, exit_at: BlockNumberFor<T> },
+        /// 服务商完成退出并取回质押
+        ProviderExited { who: T::AccountId },
+        /// 资产的证明周期已更新，None 表示恢复使用全局的 ProofPeriod
+        AssetProofPeriodSet { asset_id: [u8; 32], period: Option<BlockNumberFor<T>> },
     }
 
     #[pallet::error]
@@ -104,6 +210,38 @@ pub mod pallet {
         ProviderAlreadyExists,
         InvalidEndpoint,
         AssetNotRegistered,
+        /// 服务商已在退出流程中
+        ProviderExiting,
+        /// 服务商没有申请退出
+        ExitNotRequested,
+        /// 退出冷却期尚未结束
+        ExitCooldownNotOver,
+        /// 服务商退出后资产副本数将低于最小副本数
+        InsufficientReplicas,
+        /// 提交的分片不是当前挑战抽中的分片、Merkle 路径与 raw_data_hash 不符，或在同一挑战周期内重复提交
+        InvalidProof,
+        /// 当前证明周期起始区块的挑战种子不存在，暂时无法生成挑战
+        ChallengeUnavailable,
+        /// 调用者不是资产所有者
+        NotAssetOwner,
+        /// 证明周期超出 MinProofPeriod..=MaxProofPeriod
+        InvalidProofPeriod,
+        /// 传入的 asset_count 小于服务商实际存储的资产数量
+        AssetCountWitnessTooLow,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        /// 记录本区块的挑战种子，并删除已不可能再被任何证明周期用到的旧种子
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let (seed, _) = T::Randomness::random(b"storage_ipfs/challenge");
+            ChallengeSeeds::<T>::insert(n, seed);
+            let horizon = T::MaxProofPeriod::get().max(T::ProofPeriod::get());
+            if n > horizon {
+                ChallengeSeeds::<T>::remove(n - horizon);
+            }
+            T::DbWeight::get().writes(2)
+        }
     }
 
     #[pallet::call]
@@ -137,9 +275,8 @@ pub mod pallet {
             let bounded_endpoint: BoundedVec<u8, ConstU32<128>> = 
                 endpoint.clone().try_into().map_err(|_| Error::<T>::InvalidEndpoint)?;
 
-            // 调用 pallet-collaterals 执行质押逻辑
-            // 注意：需要 pallet-collaterals 的 internal_pledge 是 pub 的
-            CollateralPallet::<T>::internal_pledge(
+            // 通过质押接口执行质押逻辑
+            T::CollateralManager::internal_pledge(
                 &who, 
                 CollateralRole::IpfsProvider, 
                 pledge_amount
@@ -187,17 +324,50 @@ pub mod pallet {
             Ok(())
         }
 
-        /// 存储提供者提交存储证明
+        /// 存储提供者提交存储证明：当前挑战抽中的分片原文及其到 raw_data_hash 的 Merkle 路径
+        ///
+        /// 分片序号由链上随机种子决定，只有真正保存了数据的服务商才能给出该分片；
+        /// 记录的 proof_hash 为 hash(chunk || challenge)，同一挑战周期内不能重复提交
         #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
         pub fn submit_storage_proof(
             origin: OriginFor<T>,
             asset_id: [u8; 32],
-            proof_hash: H256,
+            chunk: Vec<u8>,
+            merkle_proof: Vec<H256>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
             
             ensure!(Providers::<T>::contains_key(&who), Error::<T>::NotAProvider);
+            // 退出冷却期内只能继续为已存储的资产提交证明，不能接新的资产
+            let is_new_asset = !StorageProofs::<T>::contains_key(asset_id, &who);
+            ensure!(
+                !ProviderExits::<T>::contains_key(&who) || !is_new_asset,
+                Error::<T>::ProviderExiting
+            );
+
+            // 必须给出当前挑战抽中的分片，并能通过 Merkle 路径校验到资产的 raw_data_hash
+            let raw_data_hash = T::AssetProvider::get_raw_data_hash(&asset_id)
+                .map_err(|_| Error::<T>::AssetNotRegistered)?;
+            let chunk_count = Self::chunk_count_of(&asset_id).ok_or(Error::<T>::AssetNotRegistered)?;
+            let challenge = Self::proof_challenge(&asset_id).ok_or(Error::<T>::ChallengeUnavailable)?;
+            let chunk_index = Self::challenged_chunk(&challenge, chunk_count);
+            ensure!(chunk.len() as u32 <= T::ChunkSize::get(), Error::<T>::InvalidProof);
+            ensure!(
+                Self::verify_chunk(&raw_data_hash, chunk_index, chunk_count, &chunk, &merkle_proof),
+                Error::<T>::InvalidProof
+            );
+
+            let proof_hash = Self::chunk_proof_hash(&chunk, &challenge);
+            ensure!(
+                StorageProofs::<T>::get(asset_id, &who).map_or(true, |proof| proof.proof_hash != proof_hash),
+                Error::<T>::InvalidProof
+            );
+
+            if is_new_asset {
+                ProviderAssetCount::<T>::mutate(&who, |count| *count = count.saturating_add(1));
+                ProviderAssets::<T>::insert(&who, asset_id, ());
+            }
             
             // 记录证明
             StorageProofs::<T>::insert(asset_id, &who, StorageProof {
@@ -205,8 +375,243 @@ pub mod pallet {
                 proof_hash,
             });
 
-            Self::deposit_event(Event::ProofSubmitted { asset_id, provider: who });
+            Self::deposit_event(Event::ProofSubmitted { asset_id, provider: who, chunk_index });
             Ok(())
         }
+
+        /// 服务商申请退出：标记为不活跃并进入冷却期
+        ///
+        /// `asset_count` 为服务商存储的资产数量（见 provider_status），用于按资产数量计算权重，不能小于实际数量
+        #[pallet::call_index(3)]
+        #[pallet::weight(Pallet::<T>::request_exit_weight(*asset_count))]
+        pub fn request_provider_exit(origin: OriginFor<T>, asset_count: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(!ProviderExits::<T>::contains_key(&who), Error::<T>::ProviderExiting);
+            let stored = ProviderAssetCount::<T>::get(&who);
+            ensure!(stored <= asset_count, Error::<T>::AssetCountWitnessTooLow);
+            Providers::<T>::try_mutate(&who, |maybe_info| -> DispatchResult {
+                let info = maybe_info.as_mut().ok_or(Error::<T>::NotAProvider)?;
+                Self::ensure_replicas_after_exit(&who)?;
+                info.is_active = false;
+                Ok(())
+            })?;
+
+            let exit_at = frame_system::Pallet::<T>::block_number().saturating_add(T::ProviderExitCooldown::get());
+            ProviderExits::<T>::insert(&who, exit_at);
+
+            Self::deposit_event(Event::ProviderExitRequested { who, exit_at });
+            Ok(Some(Self::request_exit_weight(stored)).into())
+        }
+
+        /// 冷却期结束后完成退出，解除 IpfsProvider 质押并移除服务商的证明记录
+        ///
+        /// `asset_count` 与 request_provider_exit 相同，不能小于服务商实际存储的资产数量
+        #[pallet::call_index(4)]
+        #[pallet::weight(Pallet::<T>::finalize_exit_weight(*asset_count))]
+        pub fn finalize_provider_exit(origin: OriginFor<T>, asset_count: u32) -> DispatchResultWithPostInfo {
+            let who = ensure_signed(origin)?;
+
+            ensure!(Providers::<T>::contains_key(&who), Error::<T>::NotAProvider);
+            let stored = ProviderAssetCount::<T>::get(&who);
+            ensure!(stored <= asset_count, Error::<T>::AssetCountWitnessTooLow);
+            let exit_at = ProviderExits::<T>::get(&who).ok_or(Error::<T>::ExitNotRequested)?;
+            ensure!(frame_system::Pallet::<T>::block_number() >= exit_at, Error::<T>::ExitCooldownNotOver);
+            // 冷却期内其他服务商可能已退出，需要再次检查
+            Self::ensure_replicas_after_exit(&who)?;
+
+            T::CollateralManager::internal_unbond(&who, CollateralRole::IpfsProvider)?;
+
+            for asset_id in ProviderAssets::<T>::drain_prefix(&who).map(|(asset_id, _)| asset_id) {
+                StorageProofs::<T>::remove(asset_id, &who);
+            }
+            ProviderAssetCount::<T>::remove(&who);
+            Providers::<T>::remove(&who);
+            ProviderExits::<T>::remove(&who);
+
+            Self::deposit_event(Event::ProviderExited { who });
+            Ok(Some(Self::finalize_exit_weight(stored)).into())
+        }
+
+        /// 设置资产的证明周期（资产所有者或治理权限），period 为 None 时恢复使用全局的 ProofPeriod
+        #[pallet::call_index(5)]
+        #[pallet::weight(10_000)]
+        pub fn set_asset_proof_period(
+            origin: OriginFor<T>,
+            asset_id: [u8; 32],
+            period: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            let maybe_who = ensure_signed_or_root(origin)?;
+
+            if let Some(who) = maybe_who {
+                let owner = T::AssetProvider::get_asset_owner(&asset_id)
+                    .map_err(|_| Error::<T>::AssetNotRegistered)?;
+                ensure!(owner == who, Error::<T>::NotAssetOwner);
+            } else {
+                ensure!(T::AssetProvider::get_recorded_owner(&asset_id).is_some(), Error::<T>::AssetNotRegistered);
+            }
+
+            match period {
+                Some(period) => {
+                    ensure!(
+                        period >= T::MinProofPeriod::get() && period <= T::MaxProofPeriod::get(),
+                        Error::<T>::InvalidProofPeriod
+                    );
+                    AssetProofPeriod::<T>::insert(asset_id, period);
+                }
+                None => AssetProofPeriod::<T>::remove(asset_id),
+            }
+
+            Self::deposit_event(Event::AssetProofPeriodSet { asset_id, period });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 资产实际使用的证明周期：优先使用 AssetProofPeriod，否则为全局的 ProofPeriod
+        pub fn proof_period_of(asset_id: &[u8; 32]) -> BlockNumberFor<T> {
+            AssetProofPeriod::<T>::get(asset_id).unwrap_or_else(T::ProofPeriod::get)
+        }
+
+        /// 当前挑战：由 (asset_id, 当前证明周期的起始区块, 该区块记录的随机种子) 派生，每个证明周期更换一次
+        ///
+        /// 种子在周期开始前无法预知，种子已被清理或尚未记录时返回 None
+        pub fn proof_challenge(asset_id: &[u8; 32]) -> Option<H256> {
+            let now = frame_system::Pallet::<T>::block_number();
+            let period = Self::proof_period_of(asset_id);
+            let window_start = if period.is_zero() { now } else { now - now % period };
+            let seed = ChallengeSeeds::<T>::get(window_start)?;
+            Some(H256(sp_io::hashing::blake2_256(&(asset_id, window_start, seed).encode())))
+        }
+
+        /// 当前挑战及其抽中的分片序号，供服务商查询本周期应提交哪个分片
+        pub fn current_challenge(asset_id: &[u8; 32]) -> Option<(H256, u32)> {
+            let chunk_count = Self::chunk_count_of(asset_id)?;
+            let challenge = Self::proof_challenge(asset_id)?;
+            Some((challenge, Self::challenged_chunk(&challenge, chunk_count)))
+        }
+
+        /// 资产数据按 ChunkSize 切分后的分片数，空数据视为一个空分片
+        pub fn chunk_count_of(asset_id: &[u8; 32]) -> Option<u32> {
+            let size = T::AssetProvider::get_data_size(asset_id).ok()?;
+            let chunk_size = u64::from(T::ChunkSize::get().max(1));
+            let count = size.div_ceil(chunk_size).max(1);
+            Some(u32::try_from(count).unwrap_or(u32::MAX))
+        }
+
+        /// 由挑战抽取的分片序号：challenge 前 4 字节（小端）对分片数取模
+        pub fn challenged_chunk(challenge: &H256, chunk_count: u32) -> u32 {
+            let mut bytes = [0u8; 4];
+            bytes.copy_from_slice(&challenge.as_bytes()[..4]);
+            u32::from_le_bytes(bytes) % chunk_count.max(1)
+        }
+
+        /// 分片的叶子哈希：hash(0x00 || chunk)，与内部节点使用不同前缀，防止用子节点冒充分片
+        pub fn chunk_leaf_hash(chunk: &[u8]) -> H256 {
+            let mut input = Vec::with_capacity(chunk.len() + 1);
+            input.push(0u8);
+            input.extend_from_slice(chunk);
+            H256(sp_io::hashing::blake2_256(&input))
+        }
+
+        /// 内部节点哈希：hash(0x01 || left || right)
+        pub fn merkle_node_hash(left: &H256, right: &H256) -> H256 {
+            let mut input = Vec::with_capacity(65);
+            input.push(1u8);
+            input.extend_from_slice(left.as_bytes());
+            input.extend_from_slice(right.as_bytes());
+            H256(sp_io::hashing::blake2_256(&input))
+        }
+
+        /// 校验分片 `index` 经 Merkle 路径得到 `root`
+        ///
+        /// 树按层两两合并，某层节点数为奇数时最后一个节点直接进入上一层且不需要兄弟节点；
+        /// `merkle_proof` 自底向上列出各层的兄弟节点，数量必须与树高严格一致
+        pub fn verify_chunk(root: &H256, index: u32, chunk_count: u32, chunk: &[u8], merkle_proof: &[H256]) -> bool {
+            if index >= chunk_count {
+                return false;
+            }
+            let mut node = Self::chunk_leaf_hash(chunk);
+            let mut index = index;
+            let mut width = chunk_count;
+            let mut siblings = merkle_proof.iter();
+            while width > 1 {
+                if index % 2 == 1 {
+                    let Some(sibling) = siblings.next() else { return false };
+                    node = Self::merkle_node_hash(sibling, &node);
+                } else if index + 1 < width {
+                    let Some(sibling) = siblings.next() else { return false };
+                    node = Self::merkle_node_hash(&node, sibling);
+                }
+                index /= 2;
+                width = width.div_ceil(2);
+            }
+            siblings.next().is_none() && node == *root
+        }
+
+        /// 记录在链上的证明：hash(chunk || challenge)
+        pub fn chunk_proof_hash(chunk: &[u8], challenge: &H256) -> H256 {
+            let mut input = chunk.to_vec();
+            input.extend_from_slice(challenge.as_bytes());
+            H256(sp_io::hashing::blake2_256(&input))
+        }
+
+        /// 查询服务商状态，不是服务商时返回 None
+        pub fn provider_status(who: &T::AccountId) -> Option<ProviderStatus<BlockNumberFor<T>, BalanceOf<T>>> {
+            Providers::<T>::get(who).map(|info| ProviderStatus {
+                info,
+                asset_count: ProviderAssetCount::<T>::get(who),
+                exit_at: ProviderExits::<T>::get(who),
+            })
+        }
+
+        /// 检查 `who` 退出后，其存储的每个资产仍有至少 MinReplicationFactor 个活跃副本
+        ///
+        /// 只遍历 ProviderAssets 中该服务商的资产，每个资产找到足够的活跃副本后即停止
+        fn ensure_replicas_after_exit(who: &T::AccountId) -> DispatchResult {
+            let min_replicas = T::MinReplicationFactor::get();
+            for asset_id in ProviderAssets::<T>::iter_key_prefix(who) {
+                let remaining = StorageProofs::<T>::iter_key_prefix(asset_id)
+                    .filter(|other| other != who)
+                    .filter(|other| Providers::<T>::get(other).map_or(false, |info| info.is_active))
+                    .take(min_replicas as usize)
+                    .count() as u32;
+                ensure!(remaining >= min_replicas, Error::<T>::InsufficientReplicas);
+            }
+            Ok(())
+        }
+
+        /// 每个资产的副本检查：读取索引和至少 MinReplicationFactor 个副本的证明与服务商信息
+        fn replica_check_weight(asset_count: u32) -> Weight {
+            let per_asset = 1u64.saturating_add(2u64.saturating_mul(T::MinReplicationFactor::get().into()));
+            T::DbWeight::get().reads(per_asset).saturating_mul(asset_count.into())
+        }
+
+        /// request_provider_exit 的权重，随服务商存储的资产数量线性增长
+        pub fn request_exit_weight(asset_count: u32) -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(3, 2))
+                .saturating_add(Self::replica_check_weight(asset_count))
+        }
+
+        /// finalize_provider_exit 的权重：副本检查之外，每个资产还要删除证明记录和索引
+        pub fn finalize_exit_weight(asset_count: u32) -> Weight {
+            Weight::from_parts(10_000, 0)
+                .saturating_add(T::DbWeight::get().reads_writes(4, 5))
+                .saturating_add(Self::replica_check_weight(asset_count))
+                .saturating_add(T::DbWeight::get().writes(2).saturating_mul(asset_count.into()))
+        }
+    }
+
+    impl<T: Config> pallet_shared_traits::StorageProofProvider<[u8; 32], BlockNumberFor<T>> for Pallet<T> {
+        fn replica_count(asset_id: &[u8; 32]) -> u32 {
+            StorageProofs::<T>::iter_prefix(asset_id).count() as u32
+        }
+
+        fn last_proof_block(asset_id: &[u8; 32]) -> Option<BlockNumberFor<T>> {
+            StorageProofs::<T>::iter_prefix_values(asset_id)
+                .map(|proof| proof.last_proof_block)
+                .max()
+        }
     }
-}
\ No newline at end of file
+}
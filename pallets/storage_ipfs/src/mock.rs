@@ -0,0 +1,399 @@
+use crate as storage_ipfs;
+use codec::Encode;
+use core::cell::RefCell;
+use frame_support::{
+    assert_ok,
+    derive_impl,
+    parameter_types,
+    dispatch::DispatchResult,
+    traits::{ConstBool, ConstU128, ConstU32, ConstU64, Hooks, Randomness, ReservableCurrency},
+};
+use pallet_collaterals::CollateralRole;
+use pallet_shared_traits::{
+    AssetQueryError, CollateralManager, DataAssetInternal, DataAssetProvider, EncryptionInfo, IncentiveError,
+    IncentiveHandler,
+};
+use frame_system::offchain::AppCrypto;
+use sp_core::H256;
+use sp_runtime::{
+    testing::{TestSignature, TestXt, UintAuthorityId},
+    BuildStorage, Perbill,
+};
+use sp_std::collections::btree_map::BTreeMap;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
+        DataAssets: pallet_dataassets,
+        StorageIpfs: storage_ipfs,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+impl pallet_timestamp::Config for Test {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = ConstU64<1>;
+    type WeightInfo = ();
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Extrinsic = TestXt<RuntimeCall, ()>;
+
+impl frame_system::offchain::SigningTypes for Test {
+    type Public = UintAuthorityId;
+    type Signature = TestSignature;
+}
+
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    type RuntimeCall = RuntimeCall;
+    type Extrinsic = Extrinsic;
+}
+
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Test
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<C: AppCrypto<Self::Public, Self::Signature>>(
+        call: RuntimeCall,
+        _public: UintAuthorityId,
+        account: u64,
+        _nonce: u64,
+    ) -> Option<Extrinsic> {
+        Some(Extrinsic::new_signed(call, account, (), ()))
+    }
+}
+
+/// 链下工作机的测试签名密钥
+pub struct MockAttesterId;
+
+impl AppCrypto<UintAuthorityId, TestSignature> for MockAttesterId {
+    type RuntimeAppPublic = UintAuthorityId;
+    type GenericPublic = UintAuthorityId;
+    type GenericSignature = TestSignature;
+}
+
+/// 不发放任何奖励的激励处理器
+pub struct MockIncentiveHandler;
+
+impl IncentiveHandler<u64, [u8; 32], u128> for MockIncentiveHandler {
+    fn distribute_first_create_reward(_recipient: &u64, _asset_id: &[u8; 32]) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn register_asset_trade(_asset_id: &[u8; 32]) {}
+
+    fn distribute_market_liquidity_reward(
+        _recipient: &u64,
+        _market_contract: &u64,
+        _order_amount: u128,
+    ) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn reserve_proposal_bond(_proposer: &u64) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn distribute_proposal_outcome(_proposer: &u64, _passed: bool, _support: Perbill) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+
+    fn reward_issuance_operator(_market: &u64) -> Result<(), IncentiveError> {
+        Ok(())
+    }
+}
+
+parameter_types! {
+    pub const BaseCollateral: u128 = 2_000;
+    pub const CollateralPerMB: u128 = 100;
+    pub const MaxCollateral: u128 = 75_000;
+    pub const MinBillableSize: u64 = 1024;
+    pub const TransferFeeRatio: Perbill = Perbill::from_percent(0);
+    pub const LongTermShareRatio: Perbill = Perbill::from_percent(0);
+    pub const IncentivePoolAccount: u64 = 99;
+    pub const ExerciseFeePoolAccount: u64 = 98;
+    pub const IpfsGatewayUrl: &'static str = "http://127.0.0.1:8080/ipfs/";
+}
+
+impl pallet_dataassets::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type BaseCollateral = BaseCollateral;
+    type CollateralPerMB = CollateralPerMB;
+    type MaxCollateral = MaxCollateral;
+    type MinBillableSize = MinBillableSize;
+    type MaxNameLength = ConstU32<256>;
+    type MaxDescriptionLength = ConstU32<1024>;
+    type MaxReleasesPerBlock = ConstU32<4>;
+    type MaxOperatorsPerAsset = ConstU32<3>;
+    type MaxBatchSize = ConstU32<5>;
+    type MaxAssetsPerDepositor = ConstU32<8>;
+    type MaxCertificatesPerIssuer = ConstU32<8>;
+    type MaxCertificatesPerAsset = ConstU32<6>;
+    type MinCertificateValidity = ConstU64<1>;
+    type MaxCertificateValidity = ConstU64<1_000_000>;
+    type AllowPerpetualCertificates = ConstBool<true>;
+    type MaxExpirationsPerBlock = ConstU32<2>;
+    type MaxExpirationsPerBucket = ConstU32<8>;
+    type RequireCertificatesRevokedOnTransfer = ConstBool<false>;
+    type TransferFee = ConstU128<0>;
+    type TransferFeeRatio = TransferFeeRatio;
+    type IncentivePoolAccount = IncentivePoolAccount;
+    type CertificateIssuanceFee = ConstU128<0>;
+    type ExerciseFeePoolAccount = ExerciseFeePoolAccount;
+    type MinBlocksBetweenRegistrations = ConstU64<0>;
+    type MaxLineageDepth = ConstU32<8>;
+    type LongTermShareRatio = LongTermShareRatio;
+    type MaxRevenueShareRecipients = ConstU32<4>;
+    type MaxAssetsPerContentHash = ConstU32<4>;
+    type PublishAssetRootDigest = ConstBool<false>;
+    type AuthorityId = MockAttesterId;
+    type IpfsGatewayUrl = IpfsGatewayUrl;
+    type GatewayTimeoutMillis = ConstU64<2_000>;
+    type AvailabilityCheckLead = ConstU64<10>;
+    type AvailabilityAttestationTtl = ConstU64<100>;
+    type IncentiveHandler = MockIncentiveHandler;
+    type StorageProofProvider = StorageIpfs;
+    type WeightInfo = pallet_dataassets::weights::WeightInfo<Test>;
+}
+
+thread_local! {
+    // 模拟 dataassets 中资产的原始数据哈希：asset_id -> raw_data_hash
+    static RAW_DATA_HASHES: RefCell<BTreeMap<[u8; 32], H256>> = RefCell::new(BTreeMap::new());
+    // 模拟 dataassets 中资产的所有者：asset_id -> owner
+    static ASSET_OWNERS: RefCell<BTreeMap<[u8; 32], u64>> = RefCell::new(BTreeMap::new());
+    // 模拟 dataassets 中资产的数据大小：asset_id -> data_size_bytes
+    static DATA_SIZES: RefCell<BTreeMap<[u8; 32], u64>> = RefCell::new(BTreeMap::new());
+}
+
+/// 模拟的资产提供者，只维护资产ID到原始数据哈希、所有者及数据大小的映射
+pub struct MockAssetProvider;
+
+impl MockAssetProvider {
+    pub fn set_raw_data_hash(asset_id: [u8; 32], raw_data_hash: H256) {
+        RAW_DATA_HASHES.with(|hashes| hashes.borrow_mut().insert(asset_id, raw_data_hash));
+    }
+
+    pub fn set_owner(asset_id: [u8; 32], owner: u64) {
+        ASSET_OWNERS.with(|owners| owners.borrow_mut().insert(asset_id, owner));
+    }
+
+    pub fn set_data_size(asset_id: [u8; 32], data_size: u64) {
+        DATA_SIZES.with(|sizes| sizes.borrow_mut().insert(asset_id, data_size));
+    }
+}
+
+impl DataAssetProvider<u64, [u8; 32]> for MockAssetProvider {
+    fn get_asset_owner(asset_id: &[u8; 32]) -> Result<u64, AssetQueryError> {
+        Self::get_recorded_owner(asset_id).ok_or(AssetQueryError::AssetNotFound)
+    }
+
+    fn get_recorded_owner(asset_id: &[u8; 32]) -> Option<u64> {
+        ASSET_OWNERS.with(|owners| owners.borrow().get(asset_id).copied())
+    }
+
+    fn get_raw_data_hash(asset_id: &[u8; 32]) -> Result<H256, AssetQueryError> {
+        RAW_DATA_HASHES.with(|hashes| hashes.borrow().get(asset_id).copied())
+            .ok_or(AssetQueryError::AssetNotFound)
+    }
+
+    fn get_data_size(asset_id: &[u8; 32]) -> Result<u64, AssetQueryError> {
+        DATA_SIZES.with(|sizes| sizes.borrow().get(asset_id).copied())
+            .ok_or(AssetQueryError::AssetNotFound)
+    }
+}
+
+/// 测试用随机数：由 subject 和当前区块号派生，每个区块不同
+pub struct TestRandomness;
+
+impl Randomness<H256, u64> for TestRandomness {
+    fn random(subject: &[u8]) -> (H256, u64) {
+        let block = System::block_number();
+        (H256(sp_io::hashing::blake2_256(&(subject, block).encode())), block)
+    }
+}
+
+/// 不执行任何注册的资产处理器
+pub struct MockAssetHandler;
+
+impl DataAssetInternal<u64, u128> for MockAssetHandler {
+    fn register_asset(
+        _owner: u64,
+        _name: Vec<u8>,
+        _description: Vec<u8>,
+        _raw_data_hash: H256,
+        _data_size: u64,
+        _metadata_cid: Vec<u8>,
+        _encryption_info: EncryptionInfo,
+    ) -> DispatchResult {
+        Ok(())
+    }
+}
+
+/// 模拟的质押管理，直接 reserve/unreserve 余额，解押不设锁定期
+pub struct MockCollateralManager;
+
+impl CollateralManager<u64, CollateralRole, u128> for MockCollateralManager {
+    fn internal_pledge(who: &u64, _role: CollateralRole, amount: u128) -> DispatchResult {
+        Balances::reserve(who, amount)
+    }
+
+    fn internal_unbond(who: &u64, _role: CollateralRole) -> DispatchResult {
+        Balances::unreserve(who, Balances::reserved_balance(who));
+        Ok(())
+    }
+
+    fn pledged_amount(who: &u64, _role: CollateralRole) -> u128 {
+        Balances::reserved_balance(who)
+    }
+}
+
+/// 证明周期（区块数）
+pub const PROOF_PERIOD: u64 = 10;
+/// 存储证明的分片大小（字节）
+pub const CHUNK_SIZE: u32 = 4;
+
+impl storage_ipfs::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type AssetHandler = MockAssetHandler;
+    type AssetProvider = MockAssetProvider;
+    type Randomness = TestRandomness;
+    type ChunkSize = ConstU32<CHUNK_SIZE>;
+    type CollateralManager = MockCollateralManager;
+    type ProofPeriod = ConstU64<PROOF_PERIOD>;
+    type MinProofPeriod = ConstU64<2>;
+    type MaxProofPeriod = ConstU64<100>;
+    type ProviderExitCooldown = ConstU64<20>;
+    type MinReplicationFactor = ConstU32<1>;
+}
+
+/// 测试资产ID、所有者与数据（按 CHUNK_SIZE 切分为 6 个分片，最后一个不满）
+pub const ASSET: [u8; 32] = [7u8; 32];
+pub const ASSET_OWNER: u64 = 3;
+pub const DATA: &[u8] = b"storage-ipfs-test-data";
+
+/// 测试账户初始余额
+pub const INITIAL_BALANCE: u128 = 1_000_000;
+/// 服务商注册质押金额
+pub const PROVIDER_PLEDGE: u128 = 10_000;
+
+/// 以 `who` 身份注册为服务商
+pub fn register_provider(who: u64) {
+    assert_ok!(StorageIpfs::register_provider(
+        RuntimeOrigin::signed(who),
+        b"/ip4/127.0.0.1/tcp/4001".to_vec(),
+        PROVIDER_PLEDGE,
+    ));
+}
+
+/// 推进到第 n 块，逐块执行 on_initialize 以记录挑战种子
+pub fn run_to_block(n: u64) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        StorageIpfs::on_initialize(System::block_number());
+    }
+}
+
+/// DATA 的 Merkle 树各层，第 0 层为叶子；奇数个节点时最后一个直接进入上一层
+fn merkle_levels() -> Vec<Vec<H256>> {
+    let mut level: Vec<H256> = DATA.chunks(CHUNK_SIZE as usize).map(StorageIpfs::chunk_leaf_hash).collect();
+    let mut levels = vec![level.clone()];
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => StorageIpfs::merkle_node_hash(left, right),
+                _ => pair[0],
+            })
+            .collect();
+        levels.push(level.clone());
+    }
+    levels
+}
+
+/// DATA 的分片 Merkle 根，即 ASSET 的 raw_data_hash
+pub fn data_root() -> H256 {
+    merkle_levels().last().unwrap()[0]
+}
+
+/// 第 index 个分片及其自底向上的 Merkle 路径
+pub fn chunk_proof(index: u32) -> (Vec<u8>, Vec<H256>) {
+    let chunk = DATA.chunks(CHUNK_SIZE as usize).nth(index as usize).unwrap().to_vec();
+    let levels = merkle_levels();
+    let mut siblings = Vec::new();
+    let mut position = index as usize;
+    for level in &levels[..levels.len() - 1] {
+        if let Some(sibling) = level.get(position ^ 1) {
+            siblings.push(*sibling);
+        }
+        position /= 2;
+    }
+    (chunk, siblings)
+}
+
+/// 当前区块 ASSET 被挑战的分片序号
+pub fn challenged_index() -> u32 {
+    StorageIpfs::current_challenge(&ASSET).unwrap().1
+}
+
+/// 当前区块 ASSET 的有效存储证明：被挑战的分片及其 Merkle 路径
+pub fn valid_proof() -> (Vec<u8>, Vec<H256>) {
+    chunk_proof(challenged_index())
+}
+
+/// 以 `who` 身份提交给定的分片证明
+pub fn submit(who: u64, (chunk, merkle_proof): (Vec<u8>, Vec<H256>)) -> DispatchResult {
+    StorageIpfs::submit_storage_proof(RuntimeOrigin::signed(who), ASSET, chunk, merkle_proof)
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: vec![(1, INITIAL_BALANCE), (2, INITIAL_BALANCE), (3, INITIAL_BALANCE)],
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| {
+        // 从第二个证明周期开始，使当前周期起始区块的挑战种子已被记录
+        System::set_block_number(PROOF_PERIOD);
+        StorageIpfs::on_initialize(PROOF_PERIOD);
+        Timestamp::set_timestamp(6_000);
+        MockAssetProvider::set_raw_data_hash(ASSET, data_root());
+        MockAssetProvider::set_owner(ASSET, ASSET_OWNER);
+        MockAssetProvider::set_data_size(ASSET, DATA.len() as u64);
+    });
+    ext
+}
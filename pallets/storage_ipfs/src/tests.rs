@@ -0,0 +1,222 @@
+use crate::{mock::*, ChallengeSeeds, Error, ProviderAssets, StorageProofs};
+use frame_support::{assert_noop, assert_ok};
+use frame_system::RawOrigin;
+use pallet_shared_traits::StorageProofProvider;
+use sp_core::H256;
+
+/// 与当前被挑战分片不同的另一个分片序号
+fn other_index() -> u32 {
+    let count = StorageIpfs::chunk_count_of(&ASSET).unwrap();
+    (challenged_index() + 1) % count
+}
+
+#[test]
+fn submit_storage_proof_accepts_current_challenge() {
+    new_test_ext().execute_with(|| {
+        register_provider(1);
+        let (chunk, merkle_proof) = valid_proof();
+        let (challenge, chunk_index) = StorageIpfs::current_challenge(&ASSET).unwrap();
+
+        assert_ok!(submit(1, (chunk.clone(), merkle_proof)));
+
+        let proof = StorageProofs::<Test>::get(ASSET, 1).unwrap();
+        assert_eq!(proof.proof_hash, StorageIpfs::chunk_proof_hash(&chunk, &challenge));
+        assert_eq!(proof.last_proof_block, PROOF_PERIOD);
+        assert_eq!(StorageIpfs::provider_asset_count(1), 1);
+        System::assert_last_event(crate::Event::ProofSubmitted { asset_id: ASSET, provider: 1, chunk_index }.into());
+    });
+}
+
+#[test]
+fn chunk_merkle_tree_covers_every_chunk() {
+    new_test_ext().execute_with(|| {
+        let count = StorageIpfs::chunk_count_of(&ASSET).unwrap();
+        assert_eq!(count, 6);
+        for index in 0..count {
+            let (chunk, merkle_proof) = chunk_proof(index);
+            assert!(StorageIpfs::verify_chunk(&data_root(), index, count, &chunk, &merkle_proof));
+            // 路径多一个或少一个节点都不能通过
+            assert!(!StorageIpfs::verify_chunk(&data_root(), index, count, &chunk, &merkle_proof[1..]));
+            let mut longer = merkle_proof.clone();
+            longer.push(H256::zero());
+            assert!(!StorageIpfs::verify_chunk(&data_root(), index, count, &chunk, &longer));
+        }
+        assert!(!StorageIpfs::verify_chunk(&data_root(), count, count, b"", &[]));
+    });
+}
+
+#[test]
+fn submit_storage_proof_requires_the_challenged_chunk() {
+    new_test_ext().execute_with(|| {
+        register_provider(1);
+
+        // 只知道链上公开的 raw_data_hash 时无法构造证明
+        assert_noop!(submit(1, (data_root().as_bytes().to_vec(), vec![])), Error::<Test>::InvalidProof);
+        // 其他分片即使 Merkle 路径正确也不能通过
+        assert_noop!(submit(1, chunk_proof(other_index())), Error::<Test>::InvalidProof);
+        // 篡改分片内容
+        let (mut chunk, merkle_proof) = valid_proof();
+        chunk[0] ^= 0xff;
+        assert_noop!(submit(1, (chunk, merkle_proof.clone())), Error::<Test>::InvalidProof);
+        // 用下一层的两个子节点冒充分片
+        let levels_chunk = [merkle_proof[0].as_bytes(), merkle_proof[0].as_bytes()].concat();
+        assert_noop!(submit(1, (levels_chunk, merkle_proof[1..].to_vec())), Error::<Test>::InvalidProof);
+        // 超过 ChunkSize 的分片直接拒绝
+        assert_noop!(submit(1, (vec![0u8; CHUNK_SIZE as usize + 1], vec![])), Error::<Test>::InvalidProof);
+
+        assert_noop!(
+            StorageIpfs::submit_storage_proof(RuntimeOrigin::signed(1), [9u8; 32], valid_proof().0, valid_proof().1),
+            Error::<Test>::AssetNotRegistered
+        );
+    });
+}
+
+#[test]
+fn submit_storage_proof_rejects_replayed_proof() {
+    new_test_ext().execute_with(|| {
+        register_provider(1);
+        let proof = valid_proof();
+        assert_ok!(submit(1, proof.clone()));
+        let first_hash = StorageProofs::<Test>::get(ASSET, 1).unwrap().proof_hash;
+
+        // 同一挑战周期内重复提交
+        run_to_block(PROOF_PERIOD + 1);
+        assert_eq!(valid_proof(), proof);
+        assert_noop!(submit(1, proof.clone()), Error::<Test>::InvalidProof);
+
+        // 进入下一个周期后挑战更换，只有新挑战抽中的分片有效
+        let challenge = StorageIpfs::current_challenge(&ASSET).unwrap().0;
+        run_to_block(2 * PROOF_PERIOD);
+        assert_ne!(StorageIpfs::current_challenge(&ASSET).unwrap().0, challenge);
+        assert_noop!(submit(1, chunk_proof(other_index())), Error::<Test>::InvalidProof);
+        assert_ok!(submit(1, valid_proof()));
+        assert_ne!(StorageProofs::<Test>::get(ASSET, 1).unwrap().proof_hash, first_hash);
+        // 同一资产不重复计数
+        assert_eq!(StorageIpfs::provider_asset_count(1), 1);
+    });
+}
+
+#[test]
+fn challenge_seeds_are_pruned_after_longest_period() {
+    new_test_ext().execute_with(|| {
+        // MaxProofPeriod (100) 大于 ProofPeriod，只保留最近 100 个区块的种子
+        run_to_block(PROOF_PERIOD + 100);
+        assert!(ChallengeSeeds::<Test>::get(PROOF_PERIOD).is_none());
+        assert!(ChallengeSeeds::<Test>::get(PROOF_PERIOD + 1).is_some());
+        assert_eq!(ChallengeSeeds::<Test>::iter().count(), 100);
+    });
+}
+
+#[test]
+fn submit_storage_proof_requires_provider() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(submit(1, valid_proof()), Error::<Test>::NotAProvider);
+    });
+}
+
+#[test]
+fn storage_proof_provider_reports_replicas_and_latest_proof() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(StorageIpfs::replica_count(&ASSET), 0);
+        assert_eq!(StorageIpfs::last_proof_block(&ASSET), None);
+
+        register_provider(1);
+        register_provider(2);
+        assert_ok!(submit(1, valid_proof()));
+        run_to_block(PROOF_PERIOD + 3);
+        assert_ok!(submit(2, valid_proof()));
+
+        assert_eq!(StorageIpfs::replica_count(&ASSET), 2);
+        assert_eq!(StorageIpfs::last_proof_block(&ASSET), Some(PROOF_PERIOD + 3));
+    });
+}
+
+#[test]
+fn asset_proof_period_override_rotates_challenge_earlier() {
+    new_test_ext().execute_with(|| {
+        register_provider(1);
+        let first = StorageIpfs::current_challenge(&ASSET).unwrap().0;
+        assert_ok!(submit(1, valid_proof()));
+
+        // 只有资产所有者或治理可以设置，且必须在 MinProofPeriod..=MaxProofPeriod 之内
+        assert_noop!(
+            StorageIpfs::set_asset_proof_period(RuntimeOrigin::signed(1), ASSET, Some(4)),
+            Error::<Test>::NotAssetOwner
+        );
+        assert_noop!(
+            StorageIpfs::set_asset_proof_period(RuntimeOrigin::signed(ASSET_OWNER), ASSET, Some(1)),
+            Error::<Test>::InvalidProofPeriod
+        );
+        assert_noop!(
+            StorageIpfs::set_asset_proof_period(RawOrigin::Root.into(), ASSET, Some(101)),
+            Error::<Test>::InvalidProofPeriod
+        );
+        assert_ok!(StorageIpfs::set_asset_proof_period(RuntimeOrigin::signed(ASSET_OWNER), ASSET, Some(4)));
+        assert_eq!(StorageIpfs::asset_proof_period(ASSET), Some(4));
+        assert_eq!(StorageIpfs::proof_period_of(&ASSET), 4);
+
+        // 新周期起点为第 8 块，测试从第 10 块开始，没有记录该区块的种子
+        assert_eq!(StorageIpfs::proof_challenge(&ASSET), None);
+        assert_noop!(submit(1, chunk_proof(0)), Error::<Test>::ChallengeUnavailable);
+
+        // 全局周期为 10，第 12 块仍在同一全局周期内，但资产的挑战已经更换
+        run_to_block(PROOF_PERIOD + 2);
+        assert_ne!(StorageIpfs::current_challenge(&ASSET).unwrap().0, first);
+        assert_noop!(submit(1, chunk_proof(other_index())), Error::<Test>::InvalidProof);
+        assert_ok!(submit(1, valid_proof()));
+
+        // 治理清除自定义周期后恢复使用全局的 ProofPeriod
+        assert_ok!(StorageIpfs::set_asset_proof_period(RawOrigin::Root.into(), ASSET, None));
+        assert_eq!(StorageIpfs::asset_proof_period(ASSET), None);
+        assert_eq!(StorageIpfs::proof_period_of(&ASSET), PROOF_PERIOD);
+    });
+}
+
+#[test]
+fn provider_exit_only_touches_the_providers_own_assets() {
+    new_test_ext().execute_with(|| {
+        const OTHER: [u8; 32] = [8u8; 32];
+        MockAssetProvider::set_raw_data_hash(OTHER, data_root());
+        MockAssetProvider::set_owner(OTHER, ASSET_OWNER);
+        MockAssetProvider::set_data_size(OTHER, DATA.len() as u64);
+        register_provider(1);
+        register_provider(2);
+        assert_ok!(submit(1, valid_proof()));
+        assert_ok!(submit(2, valid_proof()));
+        let (chunk, merkle_proof) = chunk_proof(StorageIpfs::current_challenge(&OTHER).unwrap().1);
+        assert_ok!(StorageIpfs::submit_storage_proof(RuntimeOrigin::signed(2), OTHER, chunk, merkle_proof));
+        assert_eq!(ProviderAssets::<Test>::iter_key_prefix(2).count(), 2);
+
+        // asset_count 不能小于服务商实际存储的资产数量
+        assert_noop!(
+            StorageIpfs::request_provider_exit(RuntimeOrigin::signed(2), 1),
+            Error::<Test>::AssetCountWitnessTooLow
+        );
+        // OTHER 只有服务商 2 一个副本
+        assert_noop!(
+            StorageIpfs::request_provider_exit(RuntimeOrigin::signed(2), 2),
+            Error::<Test>::InsufficientReplicas
+        );
+
+        // 传入更大的 asset_count 时按实际数量退还权重
+        let post_info = StorageIpfs::request_provider_exit(RuntimeOrigin::signed(1), 5).unwrap();
+        assert_eq!(post_info.actual_weight, Some(StorageIpfs::request_exit_weight(1)));
+        assert!(StorageIpfs::request_exit_weight(1) < StorageIpfs::request_exit_weight(5));
+
+        run_to_block(PROOF_PERIOD + 20);
+        assert_noop!(
+            StorageIpfs::finalize_provider_exit(RuntimeOrigin::signed(1), 0),
+            Error::<Test>::AssetCountWitnessTooLow
+        );
+        assert_ok!(StorageIpfs::finalize_provider_exit(RuntimeOrigin::signed(1), 1));
+        assert!(StorageProofs::<Test>::get(ASSET, 1).is_none());
+        assert_eq!(ProviderAssets::<Test>::iter_key_prefix(1).count(), 0);
+        assert_eq!(StorageIpfs::provider_asset_count(1), 0);
+        assert_eq!(Balances::reserved_balance(1), 0);
+
+        // 服务商 2 的证明和索引不受影响
+        assert!(StorageProofs::<Test>::get(ASSET, 2).is_some());
+        assert!(StorageProofs::<Test>::get(OTHER, 2).is_some());
+        assert_eq!(ProviderAssets::<Test>::iter_key_prefix(2).count(), 2);
+    });
+}
@@ -0,0 +1,82 @@
+//! Benchmarking setup for pallet-validator
+
+use super::*;
+use crate::Pallet as Validator;
+use codec::Decode;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::{Currency, Get, ReservableCurrency}, BoundedVec};
+use frame_system::RawOrigin;
+use sp_runtime::traits::TrailingZeroInput;
+use sp_std::{vec, vec::Vec};
+
+// 为基准测试创建账户并提供足够锁定质押的资金
+fn create_funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+    let account: T::AccountId = frame_benchmarking::account(name, index, 0);
+    let balance = T::MinValidatorBond::get() * 10u32.into() + T::Currency::minimum_balance();
+    T::Currency::make_free_balance_be(&account, balance);
+    account
+}
+
+// 最坏情况：验证人名单只剩一个空位
+fn fill_validators<T: Config>() {
+    let validators: Vec<T::AccountId> = (0..T::MaxValidators::get().saturating_sub(1))
+        .map(|i| frame_benchmarking::account("validator", i, 0))
+        .collect();
+    Validators::<T>::put(BoundedVec::truncate_from(validators));
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_validator() {
+        fill_validators::<T>();
+        let who = create_funded_account::<T>("candidate", 0);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(Validator::<T>::validators().contains(&who));
+        assert_eq!(ValidatorBond::<T>::get(&who), T::MinValidatorBond::get());
+    }
+
+    #[benchmark]
+    fn remove_validator() {
+        fill_validators::<T>();
+        let who = create_funded_account::<T>("candidate", 0);
+        Validator::<T>::add_validator(RawOrigin::Root.into(), who.clone()).expect("添加验证人失败");
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, who.clone());
+
+        assert!(!Validator::<T>::validators().contains(&who));
+        assert_eq!(T::Currency::reserved_balance(&who), 0u32.into());
+    }
+
+    #[benchmark]
+    fn join_as_validator() {
+        fill_validators::<T>();
+        OpenEnrollment::<T>::put(true);
+        let caller = create_funded_account::<T>("caller", 0);
+        let keys = <T as pallet_session::Config>::Keys::decode(&mut TrailingZeroInput::zeroes())
+            .expect("session keys 解码失败");
+        let bond = T::MinValidatorBond::get();
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(caller.clone()), keys, vec![], bond);
+
+        assert!(Validator::<T>::validators().contains(&caller));
+        assert_eq!(T::Currency::reserved_balance(&caller), bond);
+    }
+
+    #[benchmark]
+    fn set_open_enrollment() {
+        #[extrinsic_call]
+        _(RawOrigin::Root, true);
+
+        assert!(Validator::<T>::open_enrollment());
+    }
+
+    impl_benchmark_test_suite!(Validator, crate::mock::new_test_ext(), crate::mock::Test);
+}
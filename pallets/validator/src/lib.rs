@@ -7,22 +7,101 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 pub use pallet::*;
 
+pub mod weights;
+pub mod migrations;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+// 存储版本，存储结构变更时递增并在 migrations 中添加对应的迁移
+const STORAGE_VERSION: frame_support::traits::StorageVersion = frame_support::traits::StorageVersion::new(1);
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
     use frame_support::pallet_prelude::*;
+    use frame_support::transactional;
     use frame_system::pallet_prelude::*;
     use frame_support::traits::{
-        Currency, ReservableCurrency, BuildGenesisConfig, ValidatorSet, ValidatorSetWithIdentification
+        Currency, Imbalance, ReservableCurrency, BuildGenesisConfig, OnUnbalanced, ValidatorSet,
+        ValidatorSetWithIdentification
     };
-    use sp_runtime::traits::Convert;
+    use sp_runtime::traits::{Convert, Saturating};
     use sp_std::prelude::*;
     use sp_staking::offence::{Offence, ReportOffence, OffenceDetails, OnOffenceHandler, OffenceError};
     use pallet_im_online::UnresponsivenessOffence;
 
     pub type BalanceOf<T> = <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+    pub type NegativeImbalanceOf<T> =
+        <<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::NegativeImbalance;
+
+    pub trait WeightInfo {
+        fn add_validator() -> Weight;
+        fn remove_validator() -> Weight;
+        fn join_as_validator() -> Weight;
+        fn set_open_enrollment() -> Weight;
+    }
+
+    /// 罚没资金的去向：在 OnUnbalanced 的基础上给出去向账户，用于事件展示
+    pub trait SlashDestination<AccountId, Imbalance>: OnUnbalanced<Imbalance> {
+        /// 去向账户，None 表示直接销毁
+        fn destination() -> Option<AccountId>;
+    }
+
+    /// `()` 直接销毁罚没资金（默认行为）
+    impl<AccountId, I> SlashDestination<AccountId, I> for ()
+    where
+        (): OnUnbalanced<I>,
+    {
+        fn destination() -> Option<AccountId> {
+            None
+        }
+    }
+
+    /// 将罚没资金转入固定账户（如激励池、国库）
+    pub struct SlashToAccount<AccountId, C, A>(core::marker::PhantomData<(AccountId, C, A)>);
+
+    impl<AccountId, C, A> OnUnbalanced<C::NegativeImbalance> for SlashToAccount<AccountId, C, A>
+    where
+        C: Currency<AccountId>,
+        A: Get<AccountId>,
+    {
+        fn on_nonzero_unbalanced(amount: C::NegativeImbalance) {
+            C::resolve_creating(&A::get(), amount);
+        }
+    }
+
+    impl<AccountId, C, A> SlashDestination<AccountId, C::NegativeImbalance> for SlashToAccount<AccountId, C, A>
+    where
+        C: Currency<AccountId>,
+        A: Get<AccountId>,
+    {
+        fn destination() -> Option<AccountId> {
+            Some(A::get())
+        }
+    }
+
+    /// 验证节点状态（供 dashboard 通过 ValidatorApi 查询）
+    #[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+    pub enum ValidatorStatus<Balance> {
+        /// 在当前 session 的出块集合中
+        Active { bond: Balance, offence_count: u32 },
+        /// 已加入验证人名单，等待下一个 session 生效
+        Queued { bond: Balance, offence_count: u32 },
+        /// 因违规被罚没并移出名单
+        Slashed { bond: Balance, offence_count: u32 },
+        /// 不是验证节点
+        NotValidator,
+    }
 
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     #[pallet::genesis_config]
@@ -62,6 +141,22 @@ pub mod pallet {
         type ValidatorIdOf: Convert<Self::AccountId, Option<Self::AccountId>>;
         /// 用于 ValidatorSetWithIdentification 的 Convert trait 实现
         type IdentificationOf: Convert<Self::AccountId, Option<Self::AccountId>>;
+        /// 违规罚没资金的去向，`()` 表示销毁
+        type SlashDestination: SlashDestination<Self::AccountId, NegativeImbalanceOf<Self>>;
+        /// 每个验证节点保留的违规记录条数，超出时丢弃最早的记录
+        #[pallet::constant]
+        type MaxOffenceHistory: Get<u32>;
+        /// 统计近期违规的 session 窗口
+        #[pallet::constant]
+        type OffenceReviewWindow: Get<u32>;
+        /// 近期违规超过该次数时拒绝重新加入验证人名单，None 表示不限制
+        #[pallet::constant]
+        type MaxRecentOffences: Get<Option<u32>>;
+        /// 新加入的验证节点的免罚 session 数：密钥传播、开始出块之前的违规不罚没
+        #[pallet::constant]
+        type ValidatorGracePeriod: Get<u32>;
+        /// 交易权重
+        type WeightInfo: WeightInfo;
     }
 
     #[pallet::storage]
@@ -69,12 +164,50 @@ pub mod pallet {
     /// 存储当前的验证节点对应的账户的名单
     pub(super) type Validators<T: Config> = StorageValue<_, BoundedVec<T::AccountId, T::MaxValidators>, ValueQuery>;
 
+    #[pallet::storage]
+    #[pallet::getter(fn validator_bond)]
+    /// 验证节点当前锁定的质押金额
+    pub type ValidatorBond<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn offence_count)]
+    /// 验证节点累计违规次数
+    pub type OffenceCount<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, ValueQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn validator_offences)]
+    /// 验证节点的违规记录：(违规所在 session, 罚没比例)，供治理在重新加入前审查
+    pub type ValidatorOffences<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<(u32, sp_runtime::Perbill), T::MaxOffenceHistory>,
+        ValueQuery,
+    >;
+
+    #[pallet::storage]
+    #[pallet::getter(fn grace_period_end)]
+    /// 验证节点免罚期结束的 session（加入时的 session + ValidatorGracePeriod），早于该 session 的违规不罚没
+    pub type GracePeriodEnd<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, u32, OptionQuery>;
+
+    #[pallet::storage]
+    #[pallet::getter(fn open_enrollment)]
+    /// 是否开放自助加入（join_as_validator），许可链保持关闭只使用治理的 add_validator
+    pub type OpenEnrollment<T: Config> = StorageValue<_, bool, ValueQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         ValidatorAdded(T::AccountId),
         ValidatorRemoved(T::AccountId),
-        ValidatorSlashed(T::AccountId, BalanceOf<T>),
+        /// 验证人锁定质押：(验证人, 质押金额)
+        ValidatorBonded(T::AccountId, BalanceOf<T>),
+        /// 验证人注册了 session keys
+        SessionKeysRegistered(T::AccountId),
+        /// 自助加入开关变更
+        OpenEnrollmentSet(bool),
+        /// 验证人被罚没：(验证人, 罚没金额, 资金去向账户，None 表示销毁)
+        ValidatorSlashed(T::AccountId, BalanceOf<T>, Option<T::AccountId>),
     }
 
     #[pallet::error]
@@ -84,36 +217,26 @@ pub mod pallet {
         NotValidator,
         InsufficientBond,
         TooManyValidators,
+        /// 未开放自助加入
+        EnrollmentClosed,
+        /// 近期违规次数过多，暂不允许加入验证人名单
+        TooManyRecentOffences,
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// 添加验证人（治理调用）
         #[pallet::call_index(0)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::add_validator())]
+        #[transactional]
         pub fn add_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
             T::AddRemoveOrigin::ensure_origin(origin)?;
-            
-            // 处理上限
-            Validators::<T>::try_mutate(|validators| {
-                ensure!(!validators.contains(&who), Error::<T>::AlreadyValidator);
-
-                // 尝试锁定质押
-                T::Currency::reserve(&who, T::MinValidatorBond::get())?;
-
-                // 尝试推入新成员，如果超过 MaxValidators 会返回错误
-                validators.try_push(who.clone()).map_err(|_| Error::<T>::TooManyValidators)?;
-
-                Ok::<(), DispatchError>(())
-            })?;
-
-            Self::deposit_event(Event::ValidatorAdded(who));
-            Ok(())
+            Self::do_add_validator(&who, T::MinValidatorBond::get())
         }
 
         /// 移除验证人并解锁资金（治理调用）
         #[pallet::call_index(1)]
-        #[pallet::weight({0})]
+        #[pallet::weight(T::WeightInfo::remove_validator())]
         pub fn remove_validator(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
             T::AddRemoveOrigin::ensure_origin(origin)?;
 
@@ -124,7 +247,8 @@ pub mod pallet {
                 validators.remove(pos);
                 
                 // 解锁质押
-                T::Currency::unreserve(&who, T::MinValidatorBond::get());
+                T::Currency::unreserve(&who, ValidatorBond::<T>::take(&who));
+                GracePeriodEnd::<T>::remove(&who);
                 
                 Ok::<(), DispatchError>(())
             })?;
@@ -132,6 +256,122 @@ pub mod pallet {
             Self::deposit_event(Event::ValidatorRemoved(who));
             Ok(())
         }
+
+        /// 自助加入验证人：一次交易内锁定质押、设置 session keys 并加入验证人名单
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::join_as_validator())]
+        #[transactional]
+        pub fn join_as_validator(
+            origin: OriginFor<T>,
+            keys: <T as pallet_session::Config>::Keys,
+            proof: Vec<u8>,
+            bond: BalanceOf<T>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            ensure!(Self::open_enrollment(), Error::<T>::EnrollmentClosed);
+            ensure!(bond >= T::MinValidatorBond::get(), Error::<T>::InsufficientBond);
+
+            Self::do_add_validator(&who, bond)?;
+
+            // 复用 session 模块的 set_keys，保证与单独调用时的校验一致
+            pallet_session::Pallet::<T>::set_keys(
+                frame_system::RawOrigin::Signed(who.clone()).into(),
+                keys,
+                proof,
+            )?;
+            Self::deposit_event(Event::SessionKeysRegistered(who));
+            Ok(())
+        }
+
+        /// 开启/关闭自助加入（治理调用）
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_open_enrollment())]
+        pub fn set_open_enrollment(origin: OriginFor<T>, open: bool) -> DispatchResult {
+            T::AddRemoveOrigin::ensure_origin(origin)?;
+            OpenEnrollment::<T>::put(open);
+            Self::deposit_event(Event::OpenEnrollmentSet(open));
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// 锁定质押并加入验证人名单
+        fn do_add_validator(who: &T::AccountId, bond: BalanceOf<T>) -> DispatchResult {
+            if let Some(max_recent) = T::MaxRecentOffences::get() {
+                ensure!(Self::recent_offence_count(who) <= max_recent, Error::<T>::TooManyRecentOffences);
+            }
+
+            // 处理上限：先检查名单容量，再锁定质押，避免名单已满时资金被锁定
+            Validators::<T>::try_mutate(|validators| {
+                ensure!(!validators.contains(who), Error::<T>::AlreadyValidator);
+                ensure!(
+                    (validators.len() as u32) < T::MaxValidators::get(),
+                    Error::<T>::TooManyValidators
+                );
+
+                // 尝试锁定质押
+                T::Currency::reserve(who, bond)
+                    .map_err(|_| Error::<T>::InsufficientBond)?;
+
+                // 容量已提前检查，这里不会失败
+                validators.try_push(who.clone()).map_err(|_| Error::<T>::TooManyValidators)?;
+                // 累加而不是覆盖：账户上仍有未解锁的旧质押时不会丢失记录
+                ValidatorBond::<T>::mutate(who, |current| *current = current.saturating_add(bond));
+                let grace_end = pallet_session::Pallet::<T>::current_index()
+                    .saturating_add(T::ValidatorGracePeriod::get());
+                GracePeriodEnd::<T>::insert(who, grace_end);
+
+                Ok::<(), DispatchError>(())
+            })?;
+
+            Self::deposit_event(Event::ValidatorBonded(who.clone(), bond));
+            Self::deposit_event(Event::ValidatorAdded(who.clone()));
+            Ok(())
+        }
+
+        /// 最近 OffenceReviewWindow 个 session 内（含当前 session）记录的违规次数
+        pub fn recent_offence_count(who: &T::AccountId) -> u32 {
+            let since = pallet_session::Pallet::<T>::current_index().saturating_sub(T::OffenceReviewWindow::get());
+            Self::validator_offences(who)
+                .iter()
+                .filter(|(session, _)| *session >= since)
+                .count() as u32
+        }
+
+        /// 违规所在的 session 是否仍在验证节点的免罚期内
+        pub fn in_grace_period(who: &T::AccountId, session: u32) -> bool {
+            Self::grace_period_end(who).is_some_and(|end| session < end)
+        }
+
+        /// 记录一次违规，记录已满时丢弃最早的一条
+        fn record_offence(who: &T::AccountId, session: u32, fraction: sp_runtime::Perbill) {
+            ValidatorOffences::<T>::mutate(who, |offences| {
+                if offences.is_full() && !offences.is_empty() {
+                    offences.remove(0);
+                }
+                let _ = offences.try_push((session, fraction));
+            });
+        }
+
+        /// 查询账户的验证节点状态：是否在当前 session 出块集合中、当前质押及违规次数
+        pub fn validator_status(who: &T::AccountId) -> ValidatorStatus<BalanceOf<T>> {
+            let bond = Self::validator_bond(who);
+            let offence_count = Self::offence_count(who);
+
+            if Self::validators().contains(who) {
+                let is_active = <T as pallet_session::Config>::ValidatorIdOf::convert(who.clone())
+                    .map_or(false, |id| pallet_session::Pallet::<T>::validators().contains(&id));
+                if is_active {
+                    ValidatorStatus::Active { bond, offence_count }
+                } else {
+                    ValidatorStatus::Queued { bond, offence_count }
+                }
+            } else if offence_count > 0 {
+                ValidatorStatus::Slashed { bond, offence_count }
+            } else {
+                ValidatorStatus::NotValidator
+            }
+        }
     }
 
     // 对接Session模块
@@ -201,22 +441,43 @@ pub mod pallet {
     impl<T: Config> OnOffenceHandler<(T::AccountId, T::AccountId), (T::AccountId, T::AccountId), DispatchError> for Pallet<T> {
 		fn on_offence(
             offenders: &[OffenceDetails<(T::AccountId, T::AccountId), (T::AccountId, T::AccountId)>],
-            _slash_fraction: &[sp_runtime::Perbill],
-            _slash_session: u32,
+            slash_fraction: &[sp_runtime::Perbill],
+            slash_session: u32,
         ) -> DispatchError {
-            for detail in offenders {
+            for (index, detail) in offenders.iter().enumerate() {
                 let (offender_acc, _identification) = &detail.offender; // 获取元组中的 AccountId
-                let slash_amount = T::MinValidatorBond::get();
-                // 这里是全部罚款，应该为按比例罚款
+                if Self::in_grace_period(offender_acc, slash_session) {
+                    log::info!(
+                        "验证节点 {:?} 在免罚期内（session {}），跳过罚没",
+                        offender_acc,
+                        slash_session,
+                    );
+                    continue;
+                }
+                // 这里是全部罚款，应该为按比例罚款；不超过验证节点自己的质押，避免罚没其他模块锁定的资金
+                let slash_amount = T::MinValidatorBond::get().min(Self::validator_bond(offender_acc));
+                // 预留余额可能已被其他模块扣减，以实际罚没的金额为准
                 let (imbalance, _) = T::Currency::slash_reserved(offender_acc, slash_amount);
-                drop(imbalance);
+                let slashed = imbalance.peek();
+                T::SlashDestination::on_unbalanced(imbalance);
+                // 违规节点会被移出名单，解锁未被罚没的剩余质押，重新加入时需要重新锁定
+                let remaining = ValidatorBond::<T>::take(offender_acc).saturating_sub(slashed);
+                T::Currency::unreserve(offender_acc, remaining);
+                OffenceCount::<T>::mutate(offender_acc, |count| *count = count.saturating_add(1));
+                let fraction = slash_fraction.get(index).copied().unwrap_or_default();
+                Self::record_offence(offender_acc, slash_session, fraction);
 
                 Validators::<T>::mutate(|v| {
                     if let Some(pos) = v.iter().position(|x| x == offender_acc) {
                         v.remove(pos);
                     }
                 });
-                Self::deposit_event(Event::ValidatorSlashed(offender_acc.clone(), slash_amount));
+                GracePeriodEnd::<T>::remove(offender_acc);
+                Self::deposit_event(Event::ValidatorSlashed(
+                    offender_acc.clone(),
+                    slashed,
+                    T::SlashDestination::destination(),
+                ));
             }
             DispatchError::Other("Success")
         }
@@ -0,0 +1,50 @@
+/// 验证节点模块的存储迁移
+///
+/// 每个版本一个子模块，内部迁移不检查版本，由 VersionedMigration 包装后
+/// 只在链上版本匹配时执行并更新版本号。新增的迁移需要加入 runtime 的 Migrations 元组。
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    traits::{Get, ReservableCurrency, UncheckedOnRuntimeUpgrade},
+    weights::Weight,
+};
+use sp_runtime::traits::Zero;
+
+pub mod v1 {
+    use super::*;
+
+    /// 为引入 ValidatorBond 之前加入的验证节点回填质押记录
+    ///
+    /// 旧版本按 MinValidatorBond 锁定质押但不记录金额，按该金额回填；
+    /// 以账户实际的预留余额为上限，避免移除或罚没时动到其他模块锁定的资金。
+    pub struct InnerInitValidatorBond<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerInitValidatorBond<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let validators = Validators::<T>::get();
+            let mut writes: u64 = 0;
+            for who in validators.iter() {
+                if ValidatorBond::<T>::contains_key(who) {
+                    continue;
+                }
+                let bond = T::MinValidatorBond::get().min(T::Currency::reserved_balance(who));
+                if !bond.is_zero() {
+                    ValidatorBond::<T>::insert(who, bond);
+                    writes += 1;
+                }
+            }
+            let entries = validators.len() as u64;
+            <T as frame_system::Config>::DbWeight::get()
+                .reads_writes(entries.saturating_mul(2).saturating_add(1), writes)
+        }
+    }
+
+    pub type InitValidatorBond<T> = VersionedMigration<
+        0,
+        1,
+        InnerInitValidatorBond<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,159 @@
+use crate as pallet_validator;
+use frame_support::{
+    parameter_types,
+    derive_impl,
+    traits::{ConstU128, ConstU32, ConstU64, Currency, OnUnbalanced},
+};
+use pallet_balances::NegativeImbalance;
+use pallet_validator::SlashDestination;
+use frame_system::EnsureRoot;
+use sp_runtime::{
+    testing::UintAuthorityId,
+    traits::ConvertInto,
+    BuildStorage, KeyTypeId,
+};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        Balances: pallet_balances,
+        Session: pallet_session,
+        Validator: pallet_validator,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+    type AccountData = pallet_balances::AccountData<u128>;
+}
+
+impl pallet_balances::Config for Test {
+    type MaxLocks = ConstU32<50>;
+    type MaxReserves = ();
+    type ReserveIdentifier = [u8; 8];
+    type Balance = u128;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+/// 测试用的 session handler，不处理任何密钥
+pub struct TestSessionHandler;
+
+impl pallet_session::SessionHandler<u64> for TestSessionHandler {
+    const KEY_TYPE_IDS: &'static [KeyTypeId] = &[UintAuthorityId::ID];
+
+    fn on_genesis_session<Ks: sp_runtime::traits::OpaqueKeys>(_validators: &[(u64, Ks)]) {}
+
+    fn on_new_session<Ks: sp_runtime::traits::OpaqueKeys>(
+        _changed: bool,
+        _validators: &[(u64, Ks)],
+        _queued_validators: &[(u64, Ks)],
+    ) {
+    }
+
+    fn on_disabled(_validator_index: u32) {}
+}
+
+impl pallet_session::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type ValidatorId = u64;
+    type ValidatorIdOf = ConvertInto;
+    type ShouldEndSession = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>>;
+    type NextSessionRotation = pallet_session::PeriodicSessions<ConstU64<1>, ConstU64<0>>;
+    type SessionManager = Validator;
+    type SessionHandler = TestSessionHandler;
+    type Keys = UintAuthorityId;
+    type WeightInfo = ();
+    type DisablingStrategy = ();
+}
+
+/// 测试账户初始余额
+pub const INITIAL_BALANCE: u128 = 1_000;
+
+parameter_types! {
+    pub const MinValidatorBond: u128 = 100;
+    pub const MaxValidators: u32 = 3;
+    pub const MaxOffenceHistory: u32 = 3;
+    pub const OffenceReviewWindow: u32 = 10;
+    // 默认不限制重新加入，测试中按需设置
+    pub static MaxRecentOffences: Option<u32> = None;
+    // 默认没有免罚期，测试中按需设置
+    pub static ValidatorGracePeriod: u32 = 0;
+}
+
+thread_local! {
+    // 为 true 时罚没资金转入 SLASH_POOL，否则销毁
+    static ROUTE_SLASH_TO_POOL: core::cell::RefCell<bool> = core::cell::RefCell::new(false);
+}
+
+/// 接收罚没资金的测试账户
+pub const SLASH_POOL: u64 = 99;
+
+/// 可切换去向的罚没处理器
+pub struct MockSlashDestination;
+
+impl MockSlashDestination {
+    pub fn route_to_pool(enabled: bool) {
+        ROUTE_SLASH_TO_POOL.with(|route| *route.borrow_mut() = enabled);
+    }
+
+    fn routed() -> bool {
+        ROUTE_SLASH_TO_POOL.with(|route| *route.borrow())
+    }
+}
+
+impl OnUnbalanced<NegativeImbalance<Test>> for MockSlashDestination {
+    fn on_nonzero_unbalanced(amount: NegativeImbalance<Test>) {
+        if Self::routed() {
+            Balances::resolve_creating(&SLASH_POOL, amount);
+        }
+    }
+}
+
+impl SlashDestination<u64, NegativeImbalance<Test>> for MockSlashDestination {
+    fn destination() -> Option<u64> {
+        Self::routed().then_some(SLASH_POOL)
+    }
+}
+
+impl pallet_validator::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type AddRemoveOrigin = EnsureRoot<u64>;
+    type MinValidatorBond = MinValidatorBond;
+    type MaxValidators = MaxValidators;
+    type ValidatorIdOf = ConvertInto;
+    type IdentificationOf = ConvertInto;
+    type SlashDestination = MockSlashDestination;
+    type MaxOffenceHistory = MaxOffenceHistory;
+    type OffenceReviewWindow = OffenceReviewWindow;
+    type MaxRecentOffences = MaxRecentOffences;
+    type ValidatorGracePeriod = ValidatorGracePeriod;
+    type WeightInfo = crate::weights::WeightInfo<Test>;
+}
+
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let mut t = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    pallet_balances::GenesisConfig::<Test> {
+        balances: (1..=10).map(|who| (who, INITIAL_BALANCE)).collect(),
+        ..Default::default()
+    }
+    .assimilate_storage(&mut t)
+    .unwrap();
+    let mut ext = sp_io::TestExternalities::new(t);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
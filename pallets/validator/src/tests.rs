@@ -0,0 +1,309 @@
+use crate::{mock::*, Error, Event, OffenceCount, ValidatorBond, ValidatorOffences, Validators};
+use sp_runtime::testing::UintAuthorityId;
+use frame_support::{assert_noop, assert_ok, traits::ReservableCurrency};
+use sp_runtime::{DispatchError, Perbill};
+use sp_staking::offence::{OffenceDetails, OnOffenceHandler};
+
+#[test]
+fn add_validator_reserves_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+
+        assert_eq!(Validator::validators().to_vec(), vec![1]);
+        assert_eq!(Balances::reserved_balance(1), MinValidatorBond::get());
+        assert_eq!(ValidatorBond::<Test>::get(1), MinValidatorBond::get());
+        System::assert_last_event(Event::ValidatorAdded(1).into());
+
+        assert_noop!(
+            Validator::add_validator(RuntimeOrigin::root(), 1),
+            Error::<Test>::AlreadyValidator
+        );
+    });
+}
+
+#[test]
+fn add_validator_beyond_max_keeps_free_balance() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=MaxValidators::get() as u64 {
+            assert_ok!(Validator::add_validator(RuntimeOrigin::root(), who));
+        }
+
+        let rejected = MaxValidators::get() as u64 + 1;
+        assert_noop!(
+            Validator::add_validator(RuntimeOrigin::root(), rejected),
+            Error::<Test>::TooManyValidators
+        );
+        // 被拒绝的账户没有被锁定任何资金
+        assert_eq!(Balances::free_balance(rejected), INITIAL_BALANCE);
+        assert_eq!(Balances::reserved_balance(rejected), 0);
+    });
+}
+
+#[test]
+fn add_validator_without_funds_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Validator::add_validator(RuntimeOrigin::root(), 42),
+            Error::<Test>::InsufficientBond
+        );
+    });
+}
+
+#[test]
+fn remove_validator_releases_bond() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        assert_ok!(Validator::remove_validator(RuntimeOrigin::root(), 1));
+
+        assert!(Validator::validators().is_empty());
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+        assert_eq!(ValidatorBond::<Test>::get(1), 0);
+        System::assert_last_event(Event::ValidatorRemoved(1).into());
+    });
+}
+
+/// 模拟一次针对 `who` 的违规上报
+fn report_offence(who: u64) {
+    report_offence_in(who, 0, Perbill::from_percent(10));
+}
+
+/// 模拟在 `session` 中针对 `who` 的违规上报，罚没比例为 `fraction`
+fn report_offence_in(who: u64, session: u32, fraction: Perbill) {
+    let _ = <Validator as OnOffenceHandler<(u64, u64), (u64, u64), DispatchError>>::on_offence(
+        &[OffenceDetails { offender: (who, who), reporters: vec![] }],
+        &[fraction],
+        session,
+    );
+}
+
+#[test]
+fn slashed_bond_is_burned_by_default() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        let issuance = Balances::total_issuance();
+
+        report_offence(1);
+
+        assert_eq!(Balances::total_issuance(), issuance - MinValidatorBond::get());
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(OffenceCount::<Test>::get(1), 1);
+        assert!(Validator::validators().is_empty());
+        System::assert_last_event(Event::ValidatorSlashed(1, MinValidatorBond::get(), None).into());
+    });
+}
+
+#[test]
+fn slashed_bond_goes_to_configured_destination() {
+    new_test_ext().execute_with(|| {
+        MockSlashDestination::route_to_pool(true);
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        let issuance = Balances::total_issuance();
+
+        report_offence(1);
+
+        // 罚没资金转入去向账户，总发行量不变
+        assert_eq!(Balances::total_issuance(), issuance);
+        assert_eq!(Balances::free_balance(SLASH_POOL), MinValidatorBond::get());
+        System::assert_last_event(
+            Event::ValidatorSlashed(1, MinValidatorBond::get(), Some(SLASH_POOL)).into(),
+        );
+    });
+}
+
+#[test]
+fn slash_reports_the_amount_actually_slashed() {
+    new_test_ext().execute_with(|| {
+        MockSlashDestination::route_to_pool(true);
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        // 预留余额在上报之前已被其他途径扣减了 60
+        let (_, remaining) = Balances::slash_reserved(&1, 60);
+        assert_eq!(remaining, 0);
+
+        report_offence(1);
+
+        // 只有剩余的 40 被罚没并转入去向账户
+        assert_eq!(Balances::free_balance(SLASH_POOL), 40);
+        assert_eq!(Balances::reserved_balance(1), 0);
+        System::assert_last_event(Event::ValidatorSlashed(1, 40, Some(SLASH_POOL)).into());
+    });
+}
+
+#[test]
+fn offence_does_not_slash_reserves_outside_the_bond() {
+    new_test_ext().execute_with(|| {
+        // 账户 2 不是验证节点，其他模块锁定的资金不受影响
+        assert_ok!(Balances::reserve(&2, 50));
+
+        report_offence(2);
+
+        assert_eq!(Balances::reserved_balance(2), 50);
+        System::assert_last_event(Event::ValidatorSlashed(2, 0, None).into());
+    });
+}
+
+#[test]
+fn join_as_validator_bonds_and_sets_keys_atomically() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::set_open_enrollment(RuntimeOrigin::root(), true));
+
+        assert_ok!(Validator::join_as_validator(RuntimeOrigin::signed(1), UintAuthorityId(1), vec![], 200));
+
+        assert_eq!(Validator::validators().to_vec(), vec![1]);
+        assert_eq!(Balances::reserved_balance(1), 200);
+        assert_eq!(ValidatorBond::<Test>::get(1), 200);
+        assert_eq!(pallet_session::NextKeys::<Test>::get(1), Some(UintAuthorityId(1)));
+        System::assert_has_event(Event::ValidatorBonded(1, 200).into());
+        System::assert_last_event(Event::SessionKeysRegistered(1).into());
+    });
+}
+
+#[test]
+fn slashed_validator_gets_the_rest_of_the_bond_back() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::set_open_enrollment(RuntimeOrigin::root(), true));
+        assert_ok!(Validator::join_as_validator(RuntimeOrigin::signed(1), UintAuthorityId(1), vec![], 200));
+
+        report_offence(1);
+
+        // 罚没 MinValidatorBond，其余 100 在移出名单时解锁
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE - MinValidatorBond::get());
+        assert_eq!(ValidatorBond::<Test>::get(1), 0);
+        assert!(Validator::validators().is_empty());
+
+        // 重新加入只锁定新的质押，记录与实际锁定的金额一致
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        assert_eq!(Balances::reserved_balance(1), MinValidatorBond::get());
+        assert_eq!(ValidatorBond::<Test>::get(1), MinValidatorBond::get());
+    });
+}
+
+#[test]
+fn rejoining_keeps_bond_that_is_still_reserved() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        // 名单被直接清空（例如治理迁移）时质押仍然锁定
+        Validators::<Test>::kill();
+
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        assert_eq!(Balances::reserved_balance(1), 2 * MinValidatorBond::get());
+        assert_eq!(ValidatorBond::<Test>::get(1), 2 * MinValidatorBond::get());
+
+        // 移除时全部解锁
+        assert_ok!(Validator::remove_validator(RuntimeOrigin::root(), 1));
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn join_as_validator_respects_enrollment_and_min_bond() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            Validator::join_as_validator(RuntimeOrigin::signed(1), UintAuthorityId(1), vec![], 200),
+            Error::<Test>::EnrollmentClosed
+        );
+
+        assert_ok!(Validator::set_open_enrollment(RuntimeOrigin::root(), true));
+        assert_noop!(
+            Validator::join_as_validator(
+                RuntimeOrigin::signed(1),
+                UintAuthorityId(1),
+                vec![],
+                MinValidatorBond::get() - 1
+            ),
+            Error::<Test>::InsufficientBond
+        );
+        // 余额不足时不会留下任何记录
+        assert_noop!(
+            Validator::join_as_validator(RuntimeOrigin::signed(1), UintAuthorityId(1), vec![], 2 * INITIAL_BALANCE),
+            Error::<Test>::InsufficientBond
+        );
+    });
+}
+
+#[test]
+fn offences_are_recorded_with_session_and_fraction() {
+    new_test_ext().execute_with(|| {
+        report_offence_in(1, 1, Perbill::from_percent(10));
+        report_offence_in(1, 2, Perbill::from_percent(20));
+        report_offence_in(1, 3, Perbill::from_percent(30));
+
+        assert_eq!(
+            Validator::validator_offences(1).to_vec(),
+            vec![(1, Perbill::from_percent(10)), (2, Perbill::from_percent(20)), (3, Perbill::from_percent(30))]
+        );
+
+        // 记录已满时丢弃最早的一条，累计次数不受影响
+        report_offence_in(1, 4, Perbill::from_percent(40));
+        assert_eq!(ValidatorOffences::<Test>::get(1).len() as u32, MaxOffenceHistory::get());
+        assert_eq!(ValidatorOffences::<Test>::get(1).first(), Some(&(2, Perbill::from_percent(20))));
+        assert_eq!(Validator::offence_count(1), 4);
+        assert!(Validator::validator_offences(2).is_empty());
+    });
+}
+
+#[test]
+fn readmission_is_rejected_after_too_many_recent_offences() {
+    new_test_ext().execute_with(|| {
+        MaxRecentOffences::set(Some(1));
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        report_offence_in(1, 0, Perbill::from_percent(10));
+        report_offence_in(1, 0, Perbill::from_percent(10));
+        assert_eq!(Validator::recent_offence_count(&1), 2);
+
+        assert_noop!(
+            Validator::add_validator(RuntimeOrigin::root(), 1),
+            Error::<Test>::TooManyRecentOffences
+        );
+
+        // 违规记录移出审查窗口后可以重新加入
+        pallet_session::CurrentIndex::<Test>::put(OffenceReviewWindow::get() + 1);
+        assert_eq!(Validator::recent_offence_count(&1), 0);
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+    });
+}
+
+#[test]
+fn offence_within_grace_period_is_not_slashed() {
+    new_test_ext().execute_with(|| {
+        ValidatorGracePeriod::set(2);
+        assert_ok!(Validator::add_validator(RuntimeOrigin::root(), 1));
+        assert_eq!(Validator::grace_period_end(1), Some(2));
+
+        // 加入后的前两个 session 内的违规只记录日志
+        report_offence_in(1, 1, Perbill::from_percent(10));
+        assert_eq!(Balances::reserved_balance(1), MinValidatorBond::get());
+        assert_eq!(OffenceCount::<Test>::get(1), 0);
+        assert_eq!(Validator::validators().to_vec(), vec![1]);
+
+        // 免罚期结束后正常罚没
+        report_offence_in(1, 2, Perbill::from_percent(10));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(OffenceCount::<Test>::get(1), 1);
+        assert!(Validator::validators().is_empty());
+        assert_eq!(Validator::grace_period_end(1), None);
+    });
+}
+
+#[test]
+fn v1_migration_backfills_bond_of_existing_validators() {
+    use frame_support::traits::{GetStorageVersion, OnRuntimeUpgrade, StorageVersion};
+
+    new_test_ext().execute_with(|| {
+        // 旧版本加入的节点 1 已锁定质押但没有 ValidatorBond 记录；节点 2 来自创世配置，未锁定质押
+        assert_ok!(Balances::reserve(&1, MinValidatorBond::get()));
+        Validators::<Test>::put(frame_support::BoundedVec::truncate_from(vec![1, 2]));
+        StorageVersion::new(0).put::<Validator>();
+
+        crate::migrations::v1::InitValidatorBond::<Test>::on_runtime_upgrade();
+
+        assert_eq!(ValidatorBond::<Test>::get(1), MinValidatorBond::get());
+        assert!(!ValidatorBond::<Test>::contains_key(2));
+        assert_eq!(Validator::on_chain_storage_version(), StorageVersion::new(1));
+
+        // 回填后移除节点会解锁旧版本锁定的质押
+        assert_ok!(Validator::remove_validator(RuntimeOrigin::root(), 1));
+        assert_eq!(Balances::reserved_balance(1), 0);
+        assert_eq!(Balances::free_balance(1), INITIAL_BALANCE);
+    });
+}
@@ -0,0 +1,61 @@
+
+//! Weights for `pallet_validator`
+//!
+//! 在生成基准测试结果之前按存储读写次数估算，重新生成时使用：
+//! ./target/release/solochain-template-node benchmark pallet --chain dev --pallet pallet_validator
+//! --extrinsic * --steps 50 --repeat 20 --output pallets/validator/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_validator`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> crate::pallet::WeightInfo for WeightInfo<T> {
+	/// Storage: `Validator::ValidatorOffences` (r:1 w:0)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Storage: `Validator::Validators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Validator::ValidatorBond` (r:1 w:1)
+	/// Storage: `Validator::GracePeriodEnd` (r:0 w:1)
+	fn add_validator() -> Weight {
+		Weight::from_parts(30_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Validator::Validators` (r:1 w:1)
+	/// Storage: `Validator::ValidatorBond` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Validator::GracePeriodEnd` (r:0 w:1)
+	fn remove_validator() -> Weight {
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5_000))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `Validator::OpenEnrollment` (r:1 w:0)
+	/// Storage: `Validator::ValidatorOffences` (r:1 w:0)
+	/// Storage: `Session::CurrentIndex` (r:1 w:0)
+	/// Storage: `Validator::Validators` (r:1 w:1)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Storage: `Validator::ValidatorBond` (r:1 w:1)
+	/// Storage: `Validator::GracePeriodEnd` (r:0 w:1)
+	/// Storage: `Session::NextKeys` (r:1 w:1)
+	/// Storage: `Session::KeyOwner` (r:3 w:3)
+	fn join_as_validator() -> Weight {
+		Weight::from_parts(60_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 12_000))
+			.saturating_add(T::DbWeight::get().reads(10))
+			.saturating_add(T::DbWeight::get().writes(8))
+	}
+	/// Storage: `Validator::OpenEnrollment` (r:0 w:1)
+	fn set_open_enrollment() -> Weight {
+		Weight::from_parts(5_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -335,7 +335,7 @@ impl_runtime_apis! {
 		}
 	}
 
-	impl crate::runtime_api::DataAssetsApi<Block, AccountId> for Runtime {
+	impl crate::runtime_api::DataAssetsApi<Block, AccountId, Balance, BlockNumber> for Runtime {
 		fn get_asset(asset_id: [u8; 32]) -> Option<pallet_data_assets::types::DataAsset<AccountId>> {
 			pallet_data_assets::Pallet::<Runtime>::get_asset(&asset_id)
 		}
@@ -347,6 +347,10 @@ impl_runtime_apis! {
 		fn get_certificate(asset_id: [u8; 32], cert_id: [u8; 32]) -> Option<pallet_data_assets::types::RightToken<AccountId>> {
 			pallet_data_assets::Pallet::<Runtime>::get_certificate(&asset_id, &cert_id)
 		}
+
+		fn certificate_validity(asset_id: [u8; 32], cert_id: [u8; 32]) -> Option<pallet_data_assets::types::CertificateValidity> {
+			pallet_data_assets::Pallet::<Runtime>::certificate_validity(&asset_id, &cert_id)
+		}
 		
 		// fn get_asset_certificates(asset_id: [u8; 32]) -> Vec<pallet_data_assets::types::RightToken<AccountId>> {
 		// 	pallet_data_assets::Pallet::<Runtime>::get_asset_certificates(&asset_id)
@@ -355,6 +359,85 @@ impl_runtime_apis! {
 		fn get_asset_root() -> H256 {
 			pallet_data_assets::Pallet::<Runtime>::compute_asset_root()
 		}
+
+		fn certificate_root(asset_id: [u8; 32]) -> H256 {
+			pallet_data_assets::Pallet::<Runtime>::certificate_root(&asset_id)
+		}
+
+		fn asset_health(asset_id: [u8; 32]) -> Option<pallet_data_assets::types::AssetHealth<AccountId, Balance, BlockNumber>> {
+			pallet_data_assets::Pallet::<Runtime>::asset_health(&asset_id)
+		}
+
+		fn total_locked_collateral(account: AccountId) -> Balance {
+			pallet_data_assets::Pallet::<Runtime>::total_locked_collateral(&account)
+		}
+
+		fn locked_collateral_page(account: AccountId, start_after: Option<[u8; 32]>, limit: u32) -> Vec<([u8; 32], Balance)> {
+			pallet_data_assets::Pallet::<Runtime>::locked_collateral_page(&account, start_after, limit)
+		}
+
+		fn find_assets_by_hash(raw_data_hash: H256) -> Vec<[u8; 32]> {
+			pallet_data_assets::Pallet::<Runtime>::find_assets_by_hash(raw_data_hash)
+		}
+	}
+
+	impl crate::runtime_api::CollateralApi<Block, Balance> for Runtime {
+		fn required_collateral(data_size_bytes: u64) -> (Balance, bool) {
+			let (required, capped, _) = pallet_data_assets::Pallet::<Runtime>::calculate_collateral(data_size_bytes);
+			(required, capped)
+		}
+	}
+
+	impl crate::runtime_api::EcosystemStatsApi<Block, Balance> for Runtime {
+		fn stats() -> pallet_data_assets::types::EcosystemStats<Balance> {
+			pallet_data_assets::types::EcosystemStats {
+				total_assets: pallet_data_assets::Pallet::<Runtime>::total_assets(),
+				total_certificates: pallet_data_assets::Pallet::<Runtime>::total_certificates(),
+				total_collateral_locked: pallet_data_assets::Pallet::<Runtime>::total_collateral_locked(),
+				markets: pallet_markets::Pallet::<Runtime>::market_count(),
+				storage_providers: pallet_collaterals::Pallet::<Runtime>::pledger_count(
+					pallet_collaterals::CollateralRole::IpfsProvider,
+				),
+			}
+		}
+	}
+
+	impl crate::runtime_api::ValidatorApi<Block, AccountId, Balance> for Runtime {
+		fn validator_status(account: AccountId) -> pallet_validator::ValidatorStatus<Balance> {
+			pallet_validator::Pallet::<Runtime>::validator_status(&account)
+		}
+	}
+
+	impl crate::runtime_api::IncentiveApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn vesting_remaining(account: AccountId) -> Balance {
+			pallet_incentive::Pallet::<Runtime>::vesting_remaining(&account)
+		}
+
+		fn next_distribution_block() -> BlockNumber {
+			pallet_incentive::Pallet::<Runtime>::next_distribution_block()
+		}
+	}
+
+	impl crate::runtime_api::RewardsApi<Block, Balance> for Runtime {
+		fn current_reward() -> Balance {
+			pallet_rewards::Pallet::<Runtime>::current_reward()
+		}
+	}
+
+	impl crate::runtime_api::ValidatorRewardApi<Block, AccountId, Balance> for Runtime {
+		fn earned(account: AccountId) -> Balance {
+			pallet_rewards::Pallet::<Runtime>::validator_reward_earned(account)
+		}
+	}
+
+	impl crate::runtime_api::MarketsApi<Block, AccountId, BlockNumber> for Runtime {
+		fn inspect_market(contract_address: AccountId) -> Option<pallet_markets::MarketInspection<BlockNumber>> {
+			pallet_markets::Pallet::<Runtime>::inspect_market(&contract_address).ok()
+		}
+
+		fn list_markets() -> Vec<(AccountId, pallet_markets::MarketRegistryInfo<AccountId>)> {
+			pallet_markets::Pallet::<Runtime>::list_markets()
+		}
 	}
 
     impl pallet_contracts::ContractsApi<
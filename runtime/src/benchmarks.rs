@@ -36,4 +36,5 @@ frame_benchmarking::define_benchmarks!(
 	[pallet_incentive, Incentive]
 	[pallet_markets, Markets]
 	[pallet_rewards, Rewards]
+	[pallet_validator, Validator]
 );
@@ -38,7 +38,8 @@ use frame_system::limits::{BlockLength, BlockWeights};
 use frame_system::EnsureSigned;
 use pallet_transaction_payment::{ConstFeeMultiplier, FungibleAdapter, Multiplier};
 use sp_runtime::traits::OpaqueKeys;
-use sp_runtime::{traits::One, Perbill};
+use sp_runtime::{generic, traits::{AccountIdConversion, One, SaturatedConversion, Verify}, Perbill};
+use codec::Encode;
 use sp_version::RuntimeVersion;
 
 use pallet_shared_traits::{IncentiveHandler, DataAssetProvider};
@@ -49,6 +50,7 @@ use super::{
 	RuntimeCall, RuntimeEvent, RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, UncheckedExtrinsic,
 	System, EXISTENTIAL_DEPOSIT, SLOT_DURATION, VERSION, DAYS, HOURS, MILLI_SECS_PER_BLOCK,
 	Babe, SessionKeys, Vesting, DataAssets, Contracts, Validator,
+	Signature, SignedPayload, TxExtension, MINUTES,
 };
 use crate::{Incentive, UNIT, asset_market_extension};
 
@@ -242,8 +244,69 @@ parameter_types! {
     /// Upper limit to prevent excessive collateral requirements
     pub const MaxCollateral: Balance = 75_000 * UNIT;
 
+    /// Minimum billable data size: 1 KB
+    /// Smaller assets are charged the per-MB rate for 1 KB
+    pub const MinBillableSize: u64 = 1024;
+
 	/// Maximum number of release phases for collateral
 	pub const MaxReleasePhases: u32 = 5;
+
+	/// Maximum number of collateral release phases processed per block
+	pub const MaxReleasesPerBlock: u32 = 100;
+
+	/// Maximum number of markets an asset can be listed on at the same time
+	pub const MaxOperatorsPerAsset: u32 = 10;
+
+	/// Maximum number of certificates issued in one batch call
+	pub const MaxBatchSize: u32 = 50;
+
+	/// Maximum number of assets with locked collateral per depositor
+	pub const MaxAssetsPerDepositor: u32 = 256;
+
+	/// Maximum number of certificates indexed per issuer or proxying market
+	pub const MaxCertificatesPerIssuer: u32 = 1_000;
+
+	/// Maximum number of live certificates per asset
+	pub const MaxCertificatesPerAsset: u32 = 10_000;
+
+	/// Certificate validity window in milliseconds: at least one hour, at most ten years
+	pub const MinCertificateValidity: u64 = 60 * 60 * 1000;
+	pub const MaxCertificateValidity: u64 = 10 * 365 * 24 * 60 * 60 * 1000;
+	/// Certificates without an expiry remain allowed
+	pub const AllowPerpetualCertificates: bool = true;
+	/// Expiration sweep: at most 50 certificates marked expired per block, 1000 queued per one-minute bucket
+	pub const MaxExpirationsPerBlock: u32 = 50;
+	pub const MaxExpirationsPerBucket: u32 = 1_000;
+	/// Outstanding certificates do not block ownership transfers
+	pub const RequireCertificatesRevokedOnTransfer: bool = false;
+
+	/// Asset transfer fee routed to the incentive pool (disabled by default)
+	pub const AssetTransferFee: Balance = 0;
+	pub const AssetTransferFeeRatio: Perbill = Perbill::from_percent(0);
+	/// The incentive pallet's pool account, derived from IncentivePalletId;
+	/// receives both dataassets fees and collateral slashes
+	pub IncentivePoolAccount: AccountId = IncentivePalletId::get().into_account_truncating();
+	/// Prepaid certificate exercise fee (disabled by default), escrowed in a keyless pool account
+	pub const CertificateIssuanceFee: Balance = 0;
+	pub ExerciseFeePoolAccount: AccountId = PalletId(*b"da/exfee").into_account_truncating();
+	/// Minimum blocks between two asset registrations from the same account (about one minute)
+	pub const MinBlocksBetweenRegistrations: BlockNumber = 10;
+	/// Ownership transfers kept per asset for provenance queries
+	pub const MaxLineageDepth: u32 = 64;
+	/// Revenue-share recipients an asset owner may list besides themselves
+	pub const MaxRevenueShareRecipients: u32 = 16;
+	/// Assets that may be registered with the same raw data hash
+	pub const MaxAssetsPerContentHash: u32 = 16;
+	/// The custom header already commits to the asset root via AssetsStateRootProvider
+	pub const PublishAssetRootDigest: bool = false;
+	/// Gateway the availability off-chain worker queries with `HEAD <url><cid>`
+	pub const IpfsGatewayUrl: &'static str = "http://127.0.0.1:8080/ipfs/";
+	/// A gateway that does not answer within this many milliseconds counts as unreachable
+	pub const GatewayTimeoutMillis: u64 = 5_000;
+	/// Check availability phases this many blocks before they become due
+	pub const AvailabilityCheckLead: BlockNumber = 10 * MINUTES;
+	/// A positive attestation older than this is ignored by the release check
+	pub const AvailabilityAttestationTtl: BlockNumber = DAYS;
 }
 
 impl pallet_dataassets::Config for Runtime {
@@ -256,12 +319,44 @@ impl pallet_dataassets::Config for Runtime {
     type BaseCollateral = BaseCollateral;
     type CollateralPerMB = CollateralPerMB;
     type MaxCollateral = MaxCollateral;
+    type MinBillableSize = MinBillableSize;
     
     /// Asset metadata constraints
     type MaxNameLength = ConstU32<256>;
     type MaxDescriptionLength = ConstU32<1024>;
+    type MaxReleasesPerBlock = MaxReleasesPerBlock;
+    type MaxOperatorsPerAsset = MaxOperatorsPerAsset;
+    type MaxBatchSize = MaxBatchSize;
+    type MaxAssetsPerDepositor = MaxAssetsPerDepositor;
+    type MaxCertificatesPerIssuer = MaxCertificatesPerIssuer;
+    type MaxCertificatesPerAsset = MaxCertificatesPerAsset;
+    type MinCertificateValidity = MinCertificateValidity;
+    type MaxCertificateValidity = MaxCertificateValidity;
+    type AllowPerpetualCertificates = AllowPerpetualCertificates;
+    type MaxExpirationsPerBlock = MaxExpirationsPerBlock;
+    type MaxExpirationsPerBucket = MaxExpirationsPerBucket;
+    type RequireCertificatesRevokedOnTransfer = RequireCertificatesRevokedOnTransfer;
+    type TransferFee = AssetTransferFee;
+    type TransferFeeRatio = AssetTransferFeeRatio;
+    type IncentivePoolAccount = IncentivePoolAccount;
+    type CertificateIssuanceFee = CertificateIssuanceFee;
+    type ExerciseFeePoolAccount = ExerciseFeePoolAccount;
+    type MinBlocksBetweenRegistrations = MinBlocksBetweenRegistrations;
+    type MaxLineageDepth = MaxLineageDepth;
+    /// Same long-term share ratio the incentive pallet is configured with
+    type LongTermShareRatio = LongTermShareRatio;
+    type MaxRevenueShareRecipients = MaxRevenueShareRecipients;
+    type MaxAssetsPerContentHash = MaxAssetsPerContentHash;
+    type PublishAssetRootDigest = PublishAssetRootDigest;
+    type AuthorityId = pallet_dataassets::availability::crypto::AttesterAuthId;
+    type IpfsGatewayUrl = IpfsGatewayUrl;
+    type GatewayTimeoutMillis = GatewayTimeoutMillis;
+    type AvailabilityCheckLead = AvailabilityCheckLead;
+    type AvailabilityAttestationTtl = AvailabilityAttestationTtl;
 
     type IncentiveHandler = Incentive;
+    // storage_ipfs 尚未接入 runtime
+    type StorageProofProvider = ();
     type WeightInfo = pallet_dataassets::weights::WeightInfo<Runtime>;
 }
 
@@ -298,6 +393,8 @@ parameter_types! {
     pub const RewardAdjustmentThreshold: Balance = 250_000_000 * UNIT;
     pub const AdjustedReward: Balance = 1 * UNIT; 
     pub const MaxSupply: Balance = 500_000_000 * UNIT;
+    // 奖励档位按累计挖出的区块奖励判断；总发行量包含创世分配的 3 亿激励池等，不适合作为依据
+    pub const RewardsUseTotalIssuance: bool = false;
 }
 
 pub struct BlockAuthor;
@@ -316,6 +413,7 @@ impl pallet_rewards::Config for Runtime {
 	type RewardAdjustmentThreshold = RewardAdjustmentThreshold;
 	type AdjustedReward = AdjustedReward;
     type MaxSupply = MaxSupply;
+    type UseTotalIssuance = RewardsUseTotalIssuance;
     type WeightInfo = pallet_rewards::weights::WeightInfo<Runtime>;
 }
 
@@ -323,8 +421,15 @@ parameter_types! {
     // 激励池初始余额：3亿 DAT (经济模型的30%)
     pub const InitialIncentivePool: Balance = 300_000_000 * UNIT;
     
-    // 动态释放比例：1%/月
+    // 动态释放比例：1%/月（没有生态活跃度数据时使用）
     pub const DynamicReleaseRatio: Perbill = Perbill::from_percent(1);
+    // 按生态活跃度调整的释放比例区间：0.5% ~ 2%/月
+    pub const MinReleaseRatio: Perbill = Perbill::from_perthousand(5);
+    pub const MaxReleaseRatio: Perbill = Perbill::from_percent(2);
+    // 剩余未释放额度不超过 1000 DAT 时一次性释放完
+    pub const FinalReleaseThreshold: Balance = 1_000 * UNIT;
+    // 目标月交易总额：1000万 DAT
+    pub const TargetMonthlyTradeVolume: Balance = 10_000_000 * UNIT;
     
     // 数据创建者奖励参数
     pub const FirstCreateReward: Balance = 1_000 * UNIT; // 1000 DAT
@@ -334,27 +439,64 @@ parameter_types! {
     
     // 市场运营者奖励参数
     pub const TopMarketMonthlyReward: Balance = 50_000 * UNIT; // 5万 DAT
+    pub const IssuanceOperatorReward: Balance = 0; // 代理发行权证奖励，默认关闭
+    pub const OperatorVolumeRewardRatio: Perbill = Perbill::zero(); // 按交易额的基础奖励，默认关闭
+    pub const MaxOperatorVolumeReward: Balance = 50_000 * UNIT; // 每个市场每月最多 5万 DAT
+    pub const MaxOperatorRewardsPerBlock: u32 = 50;
     
     // 交易者奖励参数
     pub const TraderRebateThreshold: Balance = 100_000 * UNIT; // 10万 DAT
     pub const TraderRebateRatio: Perbill = Perbill::from_percent(10); // 10%
     pub const LiquidityRewardRatio: Perbill = Perbill::from_perthousand(5); // 0.5‰
+    pub const MinOrderForLiquidityReward: Balance = 10 * UNIT; // 低于 10 DAT 的订单不发流动性奖励
     
     // 治理参与者奖励参数
     pub const GovernanceVotingRewardTotal: Balance = 5_000 * UNIT; // 5000 DAT
     pub const GovernanceProposalReward: Balance = 2_000 * UNIT; // 2000 DAT
+    pub const GovernanceProposalBond: Balance = 500 * UNIT; // 500 DAT
+    pub const ProposalSupportThreshold: Perbill = Perbill::from_percent(20);
     
     // 验证节点奖励参数
     pub const ValidatorVerificationReward: Balance = 50 * UNIT; // 50 DAT
+
+    // 最小奖励发放额：不低于ED，避免粉尘账户
+    pub const MinRewardPayout: Balance = EXISTENTIAL_DEPOSIT;
+    // 所有者账户被回收时托管优质数据奖励，账户重新创建后可领取
+    pub const EscrowRewardsForMissingOwner: bool = true;
+    // 奖励常量的精度检查单位：1 DAT
+    pub const RewardUnit: Balance = UNIT;
+
+    // 基金会储备账户，与 genesis_config_presets.rs 中的 foundation_account 一致
+    pub FoundationAccount: AccountId = AccountId::new(hex_literal::hex!("701f9c89ca77bc72933cd3c1d29b18cc9fc3a95bb4b9f137373e9ebf9239b34f"));
+    pub const FoundationVestingTotal: Balance = crate::FOUNDATION_PERCENT;
+    // 每解锁 10% 通知一次
+    pub const VestingMilestoneStep: Perbill = Perbill::from_percent(10);
+
+    // 激励池账户由该 PalletId 派生（无私钥），与 genesis_config_presets.rs 中的 incentive_pool_account 一致
+    pub const IncentivePalletId: PalletId = PalletId(*b"da/incnt");
+    // 无法送达的奖励暂存账户（无私钥，由 PalletId 派生）
+    pub UndistributedRewardAccount: AccountId = PalletId(*b"da/undst").into_account_truncating();
+    // 保留最近 5 年的月度发放记录
+    pub const MaxDistributionHistory: u32 = 60;
+    // 转账失败的奖励在之后 3 轮发放中重试
+    pub const MaxPayoutRetries: u32 = 3;
+    // 单次最多批量登记 100 个治理投票权重
+    pub const IncentiveMaxBatchSize: u32 = 100;
 }
 
 impl pallet_incentive::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Currency = Balances;
 	type DataAssetProvider = DataAssets;
+	type MarketProvider = Markets;
+	type PledgeStatusProvider = Collaterals;
 	// 激励池配置
     type InitialIncentivePool = InitialIncentivePool;
     type DynamicReleaseRatio = DynamicReleaseRatio;
+    type MinReleaseRatio = MinReleaseRatio;
+    type MaxReleaseRatio = MaxReleaseRatio;
+    type FinalReleaseThreshold = FinalReleaseThreshold;
+    type TargetMonthlyTradeVolume = TargetMonthlyTradeVolume;
     
     // 数据创建者奖励配置
     type FirstCreateReward = FirstCreateReward;
@@ -364,18 +506,39 @@ impl pallet_incentive::Config for Runtime {
     
     // 市场运营者奖励配置
     type TopMarketMonthlyReward = TopMarketMonthlyReward;
+    type IssuanceOperatorReward = IssuanceOperatorReward;
+    type OperatorVolumeRewardRatio = OperatorVolumeRewardRatio;
+    type MaxOperatorVolumeReward = MaxOperatorVolumeReward;
+    type MaxOperatorRewardsPerBlock = MaxOperatorRewardsPerBlock;
     
     // 交易者奖励配置
     type TraderRebateThreshold = TraderRebateThreshold;
     type TraderRebateRatio = TraderRebateRatio;
     type LiquidityRewardRatio = LiquidityRewardRatio;
+    type MinOrderForLiquidityReward = MinOrderForLiquidityReward;
     
     // 治理参与者奖励配置
     type GovernanceVotingRewardTotal = GovernanceVotingRewardTotal;
     type GovernanceProposalReward = GovernanceProposalReward;
+    type GovernanceProposalBond = GovernanceProposalBond;
+    type ProposalSupportThreshold = ProposalSupportThreshold;
     
     // 验证节点奖励配置
     type ValidatorVerificationReward = ValidatorVerificationReward;
+    type MinRewardPayout = MinRewardPayout;
+    type EscrowRewardsForMissingOwner = EscrowRewardsForMissingOwner;
+    type Unit = RewardUnit;
+
+    // 基金会解锁通知
+    type FoundationVesting = Vesting;
+    type FoundationAccount = FoundationAccount;
+    type FoundationVestingTotal = FoundationVestingTotal;
+    type VestingMilestoneStep = VestingMilestoneStep;
+    type UndistributedRewardAccount = UndistributedRewardAccount;
+    type MaxDistributionHistory = MaxDistributionHistory;
+    type MaxPayoutRetries = MaxPayoutRetries;
+    type MaxBatchSize = IncentiveMaxBatchSize;
+    type PalletId = IncentivePalletId;
     type WeightInfo = pallet_incentive::weights::WeightInfo<Runtime>;
 }
 
@@ -487,17 +650,33 @@ parameter_types! {
     pub const MarketsPalletId: PalletId = PalletId(*b"da/mrket");
     pub const MaxMarketId: u32 = u32::MAX;
     pub const MaxListingId: u32 = u32::MAX;
+    // 注册市场时验证合约的 gas 上限
+    pub const MarketVerificationGasLimit: Weight = Weight::from_parts(5_000_000_000, 256 * 1024);
+    // 最多注册 1000 个市场，list_markets 一次返回全部 Active 市场
+    pub const MaxMarkets: u32 = 1_000;
+    // 市场手续费比例上限 10%（Basis Points）
+    pub const MaxMarketFeeRatio: u32 = 1_000;
 }
 
 impl pallet_markets::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
     type MarketWeightInfo = pallet_markets::weights::WeightInfo<Runtime>;
+    type CollateralManager = crate::Collaterals;
+    type MarketVerificationGasLimit = MarketVerificationGasLimit;
+    type MaxMarkets = MaxMarkets;
+    type MaxMarketFeeRatio = MaxMarketFeeRatio;
 }
 
 parameter_types! {
     pub const MinValidatorBond: Balance = 1_000 * UNIT; // 先质押1000DAT
     pub const MaxValidators: u32 = 100;
+    pub const MaxOffenceHistory: u32 = 16;
+    // 审查最近 7 天（28 个 session）内的违规，超过 3 次不允许重新加入
+    pub const OffenceReviewWindow: u32 = 28;
+    pub const MaxRecentOffences: Option<u32> = Some(3);
+    // 新验证节点加入后 2 个 session 内（密钥生效前）的违规不罚没
+    pub const ValidatorGracePeriod: u32 = 2;
 }
 
 impl pallet_validator::Config for Runtime {
@@ -508,6 +687,13 @@ impl pallet_validator::Config for Runtime {
     type MaxValidators = MaxValidators;
     type ValidatorIdOf = ValidatorIdOf; 
     type IdentificationOf = ValidatorIdOf;
+    // 保持原有行为：罚没资金直接销毁
+    type SlashDestination = ();
+    type MaxOffenceHistory = MaxOffenceHistory;
+    type OffenceReviewWindow = OffenceReviewWindow;
+    type MaxRecentOffences = MaxRecentOffences;
+    type ValidatorGracePeriod = ValidatorGracePeriod;
+    type WeightInfo = pallet_validator::weights::WeightInfo<Runtime>;
 }
 
 parameter_types! {
@@ -529,13 +715,63 @@ impl pallet_im_online::Config for Runtime {
     type MaxPeerInHeartbeats = ConstU32<0>; // Solo Chain 模式下通常设为 0,测试网5-10
 }
 
-// 告诉系统如何为im-online的调用创建基本的交易结构
-impl frame_system::offchain::CreateTransactionBase<pallet_im_online::Call<Runtime>> for Runtime {
+// 告诉系统如何为链下工作机的调用（im-online 心跳、数据可用性证明）创建基本的交易结构
+impl<LocalCall> frame_system::offchain::CreateTransactionBase<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
     type RuntimeCall = RuntimeCall;
     // 使用Block中定义的Extrinsic类型
     type Extrinsic = UncheckedExtrinsic;
 }
 
+impl frame_system::offchain::SigningTypes for Runtime {
+    type Public = <Signature as Verify>::Signer;
+    type Signature = Signature;
+}
+
+// 链下工作机提交签名交易（数据可用性证明）时构造完整的交易扩展
+impl<LocalCall> frame_system::offchain::CreateSignedTransaction<LocalCall> for Runtime
+where
+    RuntimeCall: From<LocalCall>,
+{
+    fn create_signed_transaction<
+        C: frame_system::offchain::AppCrypto<Self::Public, Self::Signature>,
+    >(
+        call: RuntimeCall,
+        public: <Signature as Verify>::Signer,
+        account: AccountId,
+        nonce: Nonce,
+    ) -> Option<UncheckedExtrinsic> {
+        let current_block = System::block_number().saturated_into::<u64>()
+            // 交易按父区块引用 era
+            .saturating_sub(1);
+        let period = BlockHashCount::get()
+            .checked_next_power_of_two()
+            .map(|c| c / 2)
+            .unwrap_or(2) as u64;
+        let tx_ext: TxExtension = (
+            frame_system::CheckNonZeroSender::<Runtime>::new(),
+            frame_system::CheckSpecVersion::<Runtime>::new(),
+            frame_system::CheckTxVersion::<Runtime>::new(),
+            frame_system::CheckGenesis::<Runtime>::new(),
+            frame_system::CheckEra::<Runtime>::from(generic::Era::mortal(period, current_block)),
+            frame_system::CheckNonce::<Runtime>::from(nonce),
+            frame_system::CheckWeight::<Runtime>::new(),
+            pallet_transaction_payment::ChargeTransactionPayment::<Runtime>::from(0),
+            frame_metadata_hash_extension::CheckMetadataHash::<Runtime>::new(false),
+            frame_system::WeightReclaim::<Runtime>::new(),
+        );
+        let raw_payload = SignedPayload::new(call, tx_ext)
+            .map_err(|e| log::warn!("Unable to create signed payload: {:?}", e))
+            .ok()?;
+        let signature = raw_payload.using_encoded(|payload| C::sign(payload, public))?;
+        let (call, tx_ext, _) = raw_payload.deconstruct();
+        let address = sp_runtime::MultiAddress::Id(account);
+        Some(UncheckedExtrinsic::new_signed(call, address, signature, tx_ext))
+    }
+}
+
 impl frame_system::offchain::CreateInherent<pallet_im_online::Call<Runtime>> for Runtime {
     
     fn create_bare(call: RuntimeCall) -> Self::Extrinsic {
@@ -555,14 +791,33 @@ parameter_types! {
     pub const MinMarketOperatorCollateral: Balance = 10_000 * UNIT;
     pub const MinIpfsProviderCollateral: Balance = 5_000 * UNIT;
     pub const MinGovernancePledge: Balance = 20_000 * UNIT;
+    // 单账户质押上限：只限制治理质押，避免单一账户主导按权重分配的奖励与投票
+    pub const MaxMarketOperatorCollateral: Option<Balance> = None;
+    pub const MaxIpfsProviderCollateral: Option<Balance> = None;
+    pub const MaxGovernancePledge: Option<Balance> = Some(1_000_000 * UNIT);
+    // 治理质押满 3 天后才计入投票权重
+    pub const PledgeActivationDelay: BlockNumber = 3 * DAYS;
+    // 锁定期内提前解押罚没 10%，按轻度违规分配
+    pub const EarlyUnbondPenalty: Perbill = Perbill::from_percent(10);
+    pub const EarlyUnbondSlashType: pallet_collaterals::SlashType = pallet_collaterals::SlashType::LightViolation;
     
     // 资金池账户
     pub const DestructionAccount: AccountId = AccountId::new([0u8; 32]); 
-    pub const IncentivePoolAccount: AccountId = AccountId::new([1u8; 32]); // pallets/incentive/src/lib.rs 中有账户定义
     pub const IpfsPoolAccount: AccountId = AccountId::new([2u8; 32]);
     pub const CompensationPoolAccount: AccountId = AccountId::new([3u8; 32]);
 }
 
+/// 允许提前解押的质押角色：市场运营者与数据创建者的质押保障用户权益，不允许提前退出
+pub struct EarlyUnbondAllowedRoles;
+impl frame_support::traits::Contains<pallet_collaterals::CollateralRole> for EarlyUnbondAllowedRoles {
+    fn contains(role: &pallet_collaterals::CollateralRole) -> bool {
+        matches!(
+            role,
+            pallet_collaterals::CollateralRole::IpfsProvider | pallet_collaterals::CollateralRole::GovernancePledge
+        )
+    }
+}
+
 impl pallet_collaterals::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances; // 使用 balances 模块进行质押
@@ -570,12 +825,22 @@ impl pallet_collaterals::Config for Runtime {
     type MinMarketOperatorCollateral = MinMarketOperatorCollateral;
     type MinIpfsProviderCollateral = MinIpfsProviderCollateral;
     type MinGovernancePledge = MinGovernancePledge;
-    
+    type MaxMarketOperatorCollateral = MaxMarketOperatorCollateral;
+    type MaxIpfsProviderCollateral = MaxIpfsProviderCollateral;
+    type MaxGovernancePledge = MaxGovernancePledge;
+    type PledgeActivationDelay = PledgeActivationDelay;
+    type EarlyUnbondPenalty = EarlyUnbondPenalty;
+    type EarlyUnbondSlashType = EarlyUnbondSlashType;
+    type EarlyUnbondAllowedRoles = EarlyUnbondAllowedRoles;
+    
+    // 罚没进入激励模块的池账户（由 IncentivePalletId 派生，与 dataassets 手续费相同）
     type IncentivePoolAccount = IncentivePoolAccount;
     type DestructionAccount = DestructionAccount;
     type IpfsPoolAccount = IpfsPoolAccount;
     type CompensationPoolAccount = CompensationPoolAccount;
     
+    // DataCreator 质押被罚没时由 dataassets 发出警告事件
+    type SlashNotificationHandler = DataAssets;
     type WeightInfo = pallet_collaterals::weights::WeightInfo<Runtime>;
 }
 
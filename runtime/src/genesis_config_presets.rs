@@ -17,7 +17,8 @@
 
 use crate::{AccountId, BalancesConfig, FOUNDATION_PERCENT, INCENTIVE_POOL_PERCENT, MINING_REWARD_PERCENT, RuntimeGenesisConfig, SessionKeys, SudoConfig, UNIT
 };
-use crate::configs::FoundationVestingPeriod;
+use crate::configs::{FoundationVestingPeriod, IncentivePalletId};
+use sp_runtime::traits::AccountIdConversion;
 use alloc::{vec, vec::Vec};
 use frame_support::build_struct_json_patch;
 use serde_json::Value;
@@ -40,8 +41,8 @@ fn foundation_account() -> AccountId {
 }
 
 fn incentive_pool_account() -> AccountId {
-    // 激励池账户
-    AccountId32::unchecked_from(sp_core::H256(hex!("1a9de66d5ca5a6a7bad9add630d85b972f351082b0422e5f64c78a4eecc4a427"))).into()
+    // 激励池账户，由 IncentivePalletId 派生，与 pallet_incentive 使用的账户一致
+    IncentivePalletId::get().into_account_truncating()
 }
 
 // Returns the genesis config presets populated with given parameters.
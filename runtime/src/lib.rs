@@ -188,7 +188,14 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, TxExtension>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = ();
+type Migrations = (
+	pallet_dataassets::migrations::v1::MigrateV0ToV1<Runtime>,
+	pallet_dataassets::migrations::v2::MigrateV1ToV2<Runtime>,
+	pallet_dataassets::migrations::v3::BackfillCertificateCounts<Runtime>,
+	pallet_collaterals::migrations::v1::InitPledgerCount<Runtime>,
+	pallet_markets::migrations::v1::InitMarketIndexes<Runtime>,
+	pallet_validator::migrations::v1::InitValidatorBond<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
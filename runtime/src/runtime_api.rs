@@ -1,15 +1,78 @@
 use codec::Codec;
 use sp_api::decl_runtime_apis;
 use sp_core::H256;
+use alloc::vec::Vec;
 
 decl_runtime_apis! {
-    pub trait DataAssetsApi<AccountId> where
+    pub trait DataAssetsApi<AccountId, Balance, BlockNumber> where
         AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
     {
         fn get_asset(asset_id: [u8; 32]) -> Option<pallet_dataassets::types::DataAsset<AccountId>>;
         fn get_asset_by_token_id(token_id: u32) -> Option<pallet_dataassets::types::DataAsset<AccountId>>;
         fn get_certificate(asset_id: [u8; 32], cert_id: [u8; 32]) -> Option<pallet_dataassets::types::RightToken<AccountId>>;
+        fn certificate_validity(asset_id: [u8; 32], cert_id: [u8; 32]) -> Option<pallet_dataassets::types::CertificateValidity>;
         // fn get_asset_certificates(asset_id: [u8; 32]) -> Vec<pallet_dataassets::types::RightToken<AccountId>>;
         fn get_asset_root() -> H256;
+        fn certificate_root(asset_id: [u8; 32]) -> H256;
+        fn asset_health(asset_id: [u8; 32]) -> Option<pallet_dataassets::types::AssetHealth<AccountId, Balance, BlockNumber>>;
+        fn total_locked_collateral(account: AccountId) -> Balance;
+        /// 按页返回账户仍有质押的资产及其锁定金额，start_after 为上一页最后一个资产ID
+        fn locked_collateral_page(account: AccountId, start_after: Option<[u8; 32]>, limit: u32) -> Vec<([u8; 32], Balance)>;
+        fn find_assets_by_hash(raw_data_hash: H256) -> Vec<[u8; 32]>;
+    }
+
+    pub trait CollateralApi<Balance> where
+        Balance: Codec,
+    {
+        /// 注册 data_size_bytes 大小的资产需要锁定的质押金，以及是否被 MaxCollateral 封顶
+        fn required_collateral(data_size_bytes: u64) -> (Balance, bool);
+    }
+
+    pub trait EcosystemStatsApi<Balance> where
+        Balance: Codec,
+    {
+        /// 资产、权证、资产质押金、市场和存储提供者的全网总量
+        fn stats() -> pallet_dataassets::types::EcosystemStats<Balance>;
+    }
+
+    pub trait ValidatorApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        fn validator_status(account: AccountId) -> pallet_validator::ValidatorStatus<Balance>;
+    }
+
+    pub trait IncentiveApi<AccountId, Balance, BlockNumber> where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        fn vesting_remaining(account: AccountId) -> Balance;
+        /// 下一次月度奖励发放的区块（已到期时为下一个区块）
+        fn next_distribution_block() -> BlockNumber;
+    }
+
+    pub trait RewardsApi<Balance> where
+        Balance: Codec,
+    {
+        fn current_reward() -> Balance;
+    }
+
+    pub trait ValidatorRewardApi<AccountId, Balance> where
+        AccountId: Codec,
+        Balance: Codec,
+    {
+        fn earned(account: AccountId) -> Balance;
+    }
+
+    pub trait MarketsApi<AccountId, BlockNumber> where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        fn inspect_market(contract_address: AccountId) -> Option<pallet_markets::MarketInspection<BlockNumber>>;
+        /// 所有 Active 市场及其注册信息，数量不超过 MaxMarkets
+        fn list_markets() -> Vec<(AccountId, pallet_markets::MarketRegistryInfo<AccountId>)>;
     }
 }
\ No newline at end of file